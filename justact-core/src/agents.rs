@@ -4,7 +4,7 @@
 //  Created:
 //    15 Apr 2024, 14:52:41
 //  Last edited:
-//    27 May 2024, 17:32:02
+//    16 Oct 2026, 22:19:27
 //  Auto updated?
 //    Yes
 //
@@ -14,23 +14,83 @@
 //
 
 use std::error::Error;
+use std::fmt::{Debug, Formatter, Result as FResult};
 
 use crate::agreements::Agreements;
 use crate::auxillary::Identifiable;
 use crate::statements::Statements;
-use crate::times::Times;
+use crate::times::{Times, Timestamp};
 
 
 /***** AUXILLARY *****/
 /// Allows an [`Agent`] to decide what happens to it after it has been polled.
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-pub enum AgentPoll {
+///
+/// # Generics
+/// - `A`: The type of agents that can be spawned.
+/// - `M`: The type of messages that can be waited for.
+pub enum AgentPoll<A, M> {
     /// The agent lives on, nothing happens.
     Alive,
     /// The agent should be terminated. Its work has been completed.
     Dead,
+    /// The agent lives on, but need not be polled until the current time is at least the given
+    /// [`Timestamp`].
+    Sleep(Timestamp),
+    /// The agent lives on, but need not be polled until a message matching the given predicate is
+    /// stated to it or agreed upon.
+    WaitFor(Box<dyn Send + Fn(&M) -> bool>),
+    /// The agent lives on, and the given agents should be added to the system.
+    Spawn(Vec<A>),
+}
+impl<A, M> AgentPoll<A, M> {
+    /// Changes the type of agents spawned by this AgentPoll.
+    ///
+    /// This is useful for agents that abstract over other agents.
+    ///
+    /// # Arguments
+    /// - `map`: Some closure converting the spawned agents, if any.
+    ///
+    /// # Returns
+    /// An equivalent AgentPoll over the new type of agents.
+    #[inline]
+    pub fn map_agents<B>(self, map: impl FnMut(A) -> B) -> AgentPoll<B, M> {
+        match self {
+            Self::Alive => AgentPoll::Alive,
+            Self::Dead => AgentPoll::Dead,
+            Self::Sleep(time) => AgentPoll::Sleep(time),
+            Self::WaitFor(pred) => AgentPoll::WaitFor(pred),
+            Self::Spawn(agents) => AgentPoll::Spawn(agents.into_iter().map(map).collect()),
+        }
+    }
+}
+impl<A: Debug, M> Debug for AgentPoll<A, M> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        match self {
+            Self::Alive => write!(f, "Alive"),
+            Self::Dead => write!(f, "Dead"),
+            Self::Sleep(time) => f.debug_tuple("Sleep").field(time).finish(),
+            Self::WaitFor(_) => f.debug_tuple("WaitFor").field(&"<predicate>").finish(),
+            Self::Spawn(agents) => f.debug_tuple("Spawn").field(agents).finish(),
+        }
+    }
+}
+impl<A: PartialEq, M> PartialEq for AgentPoll<A, M> {
+    /// Compares two AgentPolls.
+    ///
+    /// Predicates cannot be compared, so an [`AgentPoll::WaitFor`] is never equal to anything,
+    /// not even itself.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Alive, Self::Alive) | (Self::Dead, Self::Dead) => true,
+            (Self::Sleep(lhs), Self::Sleep(rhs)) => lhs == rhs,
+            (Self::Spawn(lhs), Self::Spawn(rhs)) => lhs == rhs,
+            _ => false,
+        }
+    }
 }
-impl Default for AgentPoll {
+impl<A, M> Default for AgentPoll<A, M> {
     #[inline]
     fn default() -> Self { Self::Alive }
 }
@@ -64,7 +124,8 @@ pub trait RationalAgent: Agent {
     /// - `times`: A set of globally synchronized [`Times`] for the agent to mutate (if consensus is reached) or not.
     ///
     /// # Returns
-    /// An [`AgentPoll`]-type that determines what the runtime should do with this agent.
+    /// An [`AgentPoll`]-type that determines what the runtime should do with this agent, and
+    /// possibly which agents it spawns.
     ///
     /// # Errors
     /// Only fatal errors that prevent the Agent from participating in the system should cause this function to error. Examples are failures to properly attach to some remote registry or queue.
@@ -73,5 +134,33 @@ pub trait RationalAgent: Agent {
         agrs: impl Agreements<Message = Self::Message>,
         times: impl Times,
         stmts: impl Statements<Message = Self::Message, Target = Self::Target>,
-    ) -> Result<AgentPoll, Self::Error>;
+    ) -> Result<AgentPoll<Self, Self::Message>, Self::Error>
+    where
+        Self: Sized;
+}
+
+
+
+
+
+/***** TESTS *****/
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+
+    #[test]
+    fn test_agent_poll_eq() {
+        type Poll = AgentPoll<u8, ()>;
+        assert_eq!(Poll::Alive, Poll::Alive);
+        assert_eq!(Poll::Sleep(Timestamp(1)), Poll::Sleep(Timestamp(1)));
+        assert_ne!(Poll::Sleep(Timestamp(1)), Poll::Sleep(Timestamp(2)));
+        assert_eq!(Poll::Spawn(vec![1, 2]), Poll::Spawn(vec![1, 2]));
+        assert_ne!(Poll::Alive, Poll::Dead);
+
+        // Predicates are never equal
+        let wait = || -> Poll { AgentPoll::WaitFor(Box::new(|_| true)) };
+        assert_ne!(wait(), wait());
+        assert_eq!(format!("{:?}", wait()), "WaitFor(\"<predicate>\")");
+    }
 }
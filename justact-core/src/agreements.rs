@@ -4,7 +4,7 @@
 //  Created:
 //    23 May 2024, 11:27:32
 //  Last edited:
-//    27 May 2024, 17:15:15
+//    16 Oct 2026, 22:18:49
 //  Auto updated?
 //    Yes
 //
@@ -17,28 +17,88 @@ use std::error::Error;
 
 use crate::auxillary::{Authored, Identifiable};
 use crate::set::LocalSet;
-use crate::times::Timestamp;
+use crate::times::{TimeRange, Timestamp};
+
+
+/***** TESTS *****/
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{scenario, MockMessage};
+
+
+    #[test]
+    fn test_agreements_history() {
+        let (_, mut agrs, _) = scenario();
+        agrs.agrs.add(Agreement { msg: MockMessage::new("s1", "consortium", "rules"), applies: TimeRange::starting(Timestamp(1)) });
+
+        // Supersede the rules at time 3
+        agrs.supersede("s1", Agreement { msg: MockMessage::new("s4", "consortium", "new rules"), applies: TimeRange::starting(Timestamp(3)) })
+            .unwrap();
+        assert_eq!(agrs.agreed().len(), 2);
+        assert_eq!(agrs.agreed().get("s1").map(|agr| agr.applies), Some(TimeRange::between(Timestamp(1), Timestamp(3))));
+
+        // See what is in force when
+        assert!(agrs.agreed_at(Timestamp(0)).is_empty());
+        assert!(agrs.agreed_at(Timestamp(2)).contains("s1"));
+        assert!(!agrs.agreed_at(Timestamp(2)).contains("s4"));
+        assert!(!agrs.agreed_at(Timestamp(3)).contains("s1"));
+        assert!(agrs.agreed_at(Timestamp(3)).contains("s4"));
+
+        // Revoking never extends an agreement
+        agrs.revoke("s1", Timestamp(5)).unwrap();
+        assert_eq!(agrs.agreed().get("s1").map(|agr| agr.applies), Some(TimeRange::between(Timestamp(1), Timestamp(3))));
+
+        // Unknown agreements cannot be revoked, nor superseded
+        assert!(agrs.revoke("s5", Timestamp(5)).is_err());
+        assert!(agrs.supersede("s5", Agreement { msg: MockMessage::new("s6", "consortium", "rules"), applies: TimeRange::starting(Timestamp(5)) }).is_err());
+        assert!(!agrs.agreed().contains("s6"));
+    }
+}
+
+
+
 
 
 /***** LIBRARY *****/
-/// Implements an [`Agreement`], which is like a message plus some timestamp that relates to when it was valid.
+/// Implements an [`Agreement`], which is like a message plus some range of timestamps that relates to when it was valid.
 ///
 /// # Generics
 /// - `M`: The concrete type of the [`Message`] stored in the agreement.
 /// - `T`: The concrete type of the [`Time`]stamp stored in the agreement.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Agreement<M> {
     /// The (stated!) message that was agreed upon.
     pub msg: M,
-    /// The timestamp indicating when this message is OK to be used as basis for actions.
-    pub timestamp: Timestamp,
+    /// The range of timestamps indicating when this message is OK to be used as basis for actions.
+    pub applies: TimeRange,
 }
 impl<M> Agreement<M> {
-    /// Returns when the agreement applied, i.e., for which time it may be used as basis for [`Action`](crate::statements::Action)s.
+    /// Checks whether the agreement applies at the given time, i.e., whether it may be used as basis for [`Action`](crate::statements::Action)s taken then.
+    ///
+    /// # Arguments
+    /// - `time`: The [`Timestamp`] to check.
     ///
     /// # Returns
-    /// The internal [`Timestamp`].
-    pub fn applies_at(&self) -> Timestamp { self.timestamp }
+    /// True if `time` is in the agreement's range, or false otherwise.
+    #[inline]
+    pub fn applies_at(&self, time: Timestamp) -> bool { self.applies.contains(time) }
+
+    /// Ends the range for which this agreement applies at the given time.
+    ///
+    /// Note that this never extends the range; if the agreement already stopped applying before
+    /// `at`, then nothing happens.
+    ///
+    /// # Arguments
+    /// - `at`: The first [`Timestamp`] for which the agreement no longer applies.
+    #[inline]
+    pub fn revoke(&mut self, at: Timestamp) {
+        self.applies.until = Some(match self.applies.until {
+            Some(until) => until.min(at),
+            None => at,
+        });
+    }
 }
 
 impl<M: Identifiable> Identifiable for Agreement<M> {
@@ -60,9 +120,13 @@ impl<M: Authored> Authored for Agreement<M> {
 ///
 /// This is a _globally synchronized_ set, meaning that the framework requires agents to be in
 /// agreement at all times about this set's contents.
+///
+/// Agreements are never removed from the set. Instead, they are [revoked](Agreements::revoke())
+/// by ending the range of time for which they apply. As such, the set doubles as the history of
+/// which agreements were in force when.
 pub trait Agreements {
     /// The type of [`Message`]s that are agreed upon in the form of [`Agreement`]s.
-    type Message;
+    type Message: Identifiable;
     /// The type of errors returned by this set.
     type Error: Error;
 
@@ -77,9 +141,84 @@ pub trait Agreements {
     /// because they could not be updated (synchronization) or did not agree with it (consensus).
     fn agree(&mut self, agr: Agreement<Self::Message>) -> Result<(), Self::Error>;
 
+    /// Revokes an existing agreement from some time onwards.
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the [`Agreement`] to revoke.
+    /// - `at`: The first [`Timestamp`] at which the agreement no longer applies.
+    ///
+    /// # Errors
+    /// This function errors if there is no agreement with the given identifier, or if it failed
+    /// to synchronize the revocation to all other agents (see [`Agreements::agree()`]).
+    fn revoke(&mut self, id: &<Self::Message as Identifiable>::Id, at: Timestamp) -> Result<(), Self::Error>;
+
+    /// Replaces an existing agreement with a new one.
+    ///
+    /// The old agreement is [revoked](Agreements::revoke()) from the moment the new one starts to
+    /// apply.
+    ///
+    /// Note that this is not atomic. If the revocation succeeds but agreeing on `agr` fails, then
+    /// the old agreement stays revoked; revocations cannot be undone, as they never extend the
+    /// time an agreement applies. Implementations may override this to check `agr` first.
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the [`Agreement`] to supersede.
+    /// - `agr`: The new [`Agreement<Self::Message>`] to agree on instead.
+    ///
+    /// # Errors
+    /// This function errors if either the revocation or the new agreement failed.
+    #[inline]
+    fn supersede(&mut self, id: &<Self::Message as Identifiable>::Id, agr: Agreement<Self::Message>) -> Result<(), Self::Error> {
+        self.revoke(id, agr.applies.from)?;
+        self.agree(agr)
+    }
+
+    /// Votes against an agreement that is proposed but not yet agreed upon.
+    ///
+    /// Agreeing on a proposed agreement (see [`Agreements::agree()`]) votes in favour of it
+    /// instead.
+    ///
+    /// Only implementations that reach agreements by voting can vote against them, which they
+    /// should do by overriding this. For all others, there is nothing to vote on and this does
+    /// nothing.
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the proposed [`Agreement`] to vote against.
+    ///
+    /// # Errors
+    /// This function errors if there is no proposal with the given identifier, or if it failed to
+    /// synchronize the vote to all other agents.
+    #[inline]
+    fn reject(&mut self, _id: &<Self::Message as Identifiable>::Id) -> Result<(), Self::Error> { Ok(()) }
+
     /// Returns an agreement set with all agreements in this Agreements.
     ///
     /// # Returns
     /// A [`Set`] that contains all the agreements in this Agreements.
     fn agreed<'s>(&'s self) -> LocalSet<&'s Agreement<Self::Message>>;
+
+    /// Returns the agreements that were in force at a particular time.
+    ///
+    /// # Arguments
+    /// - `time`: The [`Timestamp`] for which to find the agreements.
+    ///
+    /// # Returns
+    /// A [`Set`] that contains all the agreements in this Agreements that apply at `time`.
+    #[inline]
+    fn agreed_at<'s>(&'s self, time: Timestamp) -> LocalSet<&'s Agreement<Self::Message>> {
+        let mut agrs: LocalSet<&'s Agreement<Self::Message>> = self.agreed();
+        agrs.retain(|agr| agr.applies_at(time));
+        agrs
+    }
+
+    /// Returns the agreements that are proposed but not yet agreed upon.
+    ///
+    /// Only implementations that reach agreements by voting have proposals, which they should
+    /// return by overriding this. For all others, agreements are agreed upon immediately.
+    ///
+    /// # Returns
+    /// A [`Set`] that contains all proposed agreements in this Agreements. It is always empty for
+    /// implementations that do not reach agreements by voting.
+    #[inline]
+    fn proposed(&self) -> LocalSet<&Agreement<Self::Message>> { LocalSet::new() }
 }
@@ -0,0 +1,489 @@
+//  AUDITORS.rs
+//    by Lut99
+//
+//  Created:
+//    16 Oct 2026, 11:02:17
+//  Last edited:
+//    16 Oct 2026, 22:11:46
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Defines the [`Auditor`]-trait, which abstracts over the properties
+//!   checked when auditing [`Action`]s.
+//!
+//!   Each of the properties from the paper is provided as a separate
+//!   auditor, such that they can be combined with custom, domain-specific
+//!   ones.
+//
+
+use std::fmt::{Debug, Formatter, Result as FResult};
+use std::marker::PhantomData;
+
+use crate::agreements::Agreement;
+use crate::auxillary::{Authored, Identifiable};
+use crate::policy::{Extractor, Policy};
+use crate::set::LocalSet;
+use crate::statements::{Action, AuditExplanation, AuditReport, Justification, Message};
+use crate::times::TimeRange;
+
+
+/***** TESTS *****/
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agreements::Agreements as _;
+    use crate::mock::{scenario, MockExtractor, MockMessage};
+    use crate::times::Timestamp;
+
+
+    #[test]
+    fn test_auditor_custom() {
+        // Define some stack with custom properties
+        let auditor = Paper.and(MaxJustification(3)).and(Property::new("enacted-by-amy", |ctx: &AuditContext<MockMessage>| {
+            if ctx.act.enacts.author == "amy" { Ok(()) } else { Err((vec![&ctx.act.enacts], "Only amy may enact".into())) }
+        }));
+
+        // The default scenario passes it
+        let (stmts, agrs, act) = scenario();
+        assert!(act.audit_with::<MockExtractor, _, _, _>(&auditor, &stmts, &agrs).is_ok());
+
+        // Having too large a justification does not
+        let (mut stmts, agrs, mut act) = scenario();
+        stmts.stmts.add(MockMessage::new("s4", "anton", "authorises anton"));
+        act.just.add(MockMessage::new("s4", "anton", "authorises anton"));
+        let report = act.audit_with::<MockExtractor, _, _, _>(&auditor, &stmts, &agrs);
+        assert_eq!(report.violations.len(), 1);
+        assert!(matches!(&report.violations[0], AuditExplanation::Custom { property: "max-justification", .. }));
+
+        // Neither does anton enacting something
+        let (mut stmts, agrs, mut act) = scenario();
+        act.enacts = MockMessage::new("s4", "anton", "accesses x_rays");
+        stmts.stmts.add(act.enacts.clone());
+        let report = act.audit_with::<MockExtractor, _, _, _>(&auditor, &stmts, &agrs);
+        assert_eq!(report.violations.len(), 1);
+        assert!(matches!(&report.violations[0], AuditExplanation::Custom { property: "enacted-by-amy", stmts, .. } if stmts == &vec!["s4"]));
+
+        // A stack without the paper properties doesn't care about those
+        let (stmts, agrs, mut act) = scenario();
+        act.timestamp = crate::times::Timestamp(2);
+        assert!(act.audit_with::<MockExtractor, _, _, _>(&MaxJustification(3), &stmts, &agrs).is_ok());
+        assert!(!act.audit_with::<MockExtractor, _, _, _>(&Paper, &stmts, &agrs).is_ok());
+        assert!(!act.audit_with::<MockExtractor, _, _, _>(&Timely, &stmts, &agrs).is_ok());
+    }
+
+    #[test]
+    fn test_auditor_unequivocal() {
+        // Using another version of a stated message is caught
+        let (stmts, agrs, mut act) = scenario();
+        act.just.add(MockMessage::new("s2", "anton", "authorises anton"));
+        let report = act.audit_with::<MockExtractor, _, _, _>(&Paper, &stmts, &agrs);
+        assert_eq!(report.violations.len(), 1);
+        assert!(matches!(&report.violations[0], AuditExplanation::Equivocated { stmt: "s2", authors } if authors == &vec!["anton", "administrator"]));
+
+        // Stating conflicting versions is caught too, even if the action uses one of them
+        let (mut stmts, agrs, act) = scenario();
+        stmts.versions = vec![MockMessage::new("s2", "administrator", "authorises amy"), MockMessage::new("s2", "anton", "authorises anton")];
+        let report = act.audit_with::<MockExtractor, _, _, _>(&Unequivocal, &stmts, &agrs);
+        assert_eq!(report.violations.len(), 1);
+        assert!(matches!(&report.violations[0], AuditExplanation::Equivocated { stmt: "s2", authors } if authors == &vec!["administrator", "anton"]));
+
+        // But the same version is never a conflict
+        let (mut stmts, agrs, act) = scenario();
+        stmts.versions = vec![MockMessage::new("s2", "administrator", "authorises amy")];
+        assert!(act.audit_with::<MockExtractor, _, _, _>(&Paper, &stmts, &agrs).is_ok());
+    }
+
+    #[test]
+    fn test_auditor_timely_revoked() {
+        // Revoking the basis after the action was taken is fine
+        let (stmts, mut agrs, act) = scenario();
+        agrs.revoke("s1", Timestamp(2)).unwrap();
+        assert!(act.audit_with::<MockExtractor, _, _, _>(&Paper, &stmts, &agrs).is_ok());
+
+        // But revoking it before is not, even though the action itself claims otherwise
+        let (stmts, mut agrs, act) = scenario();
+        agrs.revoke("s1", Timestamp(1)).unwrap();
+        let report = act.audit_with::<MockExtractor, _, _, _>(&Paper, &stmts, &agrs);
+        assert_eq!(report.violations.len(), 1);
+        assert!(matches!(&report.violations[0], AuditExplanation::Timely { stmt: "s1", applies, .. } if applies.until == Some(Timestamp(1))));
+    }
+}
+
+
+
+
+
+/***** AUXILLARY *****/
+/// The type of [`AuditReport`] produced by [`Auditor`]s for an [`Action`] over `M`essages, when
+/// using [`Extractor`] `E`.
+pub type ActionAuditReport<'a, M, E> = AuditReport<
+    &'a <&'a M as Identifiable>::Id,
+    <E as Extractor<&'a M>>::SyntaxError<'a>,
+    <<E as Extractor<&'a M>>::Policy<'a> as Policy>::SemanticError,
+    &'a <&'a M as Authored>::AuthorId,
+>;
+
+/// The type of [`AuditExplanation`] produced by [`Auditor`]s for an [`Action`] over `M`essages,
+/// when using [`Extractor`] `E`.
+pub type ActionAuditExplanation<'a, M, E> = AuditExplanation<
+    &'a <&'a M as Identifiable>::Id,
+    <E as Extractor<&'a M>>::SyntaxError<'a>,
+    <<E as Extractor<&'a M>>::Policy<'a> as Policy>::SemanticError,
+    &'a <&'a M as Authored>::AuthorId,
+>;
+
+/// Collects everything an [`Auditor`] may need to know about an [`Action`] it audits.
+///
+/// The sets in here are computed once per audit, such that auditors do not need to rebuild them.
+///
+/// # Generics
+/// - `'a`: The lifetime of the audited action and the sets it is audited against.
+/// - `M`: The type of [`Message`]s in the action.
+#[derive(Debug)]
+pub struct AuditContext<'a, M> {
+    /// The action that is being audited.
+    pub act:    &'a Action<M>,
+    /// The action's full justification (see [`Action::justification()`]).
+    pub just:   Justification<'a, M>,
+    /// The messages stated at the time of the audit.
+    pub stated: LocalSet<&'a M>,
+    /// All conflicting versions of messages stated at the time of the audit (see [`Statements::equivocated()`](crate::statements::Statements::equivocated())).
+    pub equivocated: Vec<&'a M>,
+    /// The agreements at the time of the audit.
+    pub agreed: LocalSet<&'a Agreement<M>>,
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Defines something that checks one or more properties of [`Action`]s.
+///
+/// Auditors do not fail fast; instead, they write every violation they find to an [`AuditReport`].
+///
+/// # Generics
+/// - `M`: The type of [`Message`]s in the audited actions.
+pub trait Auditor<M> {
+    /// Checks the properties represented by this auditor for the given action.
+    ///
+    /// # Generics
+    /// - `E`: The [`Extractor`] of the [`Policy`] language that is used to verify the messages' payload's validity.
+    ///
+    /// # Arguments
+    /// - `ctx`: The [`AuditContext`] describing the action and the sets it is audited against.
+    /// - `report`: The [`AuditReport`] to which any violated properties are added.
+    fn audit<'a, E>(&self, ctx: &AuditContext<'a, M>, report: &mut ActionAuditReport<'a, M, E>)
+    where
+        M: 'a + Identifiable,
+        &'a M: Message<'a>,
+        E: Extractor<&'a M>;
+}
+
+// Pointer-like impls
+impl<M, A: Auditor<M>> Auditor<M> for &A {
+    #[inline]
+    fn audit<'a, E>(&self, ctx: &AuditContext<'a, M>, report: &mut ActionAuditReport<'a, M, E>)
+    where
+        M: 'a + Identifiable,
+        &'a M: Message<'a>,
+        E: Extractor<&'a M>,
+    {
+        A::audit::<E>(self, ctx, report)
+    }
+}
+
+
+
+/// Allows one to call [`AuditorExt::and()`] on [`Auditor`]s.
+///
+/// This is implemented for every [`Auditor`] automatically.
+///
+/// # Generics
+/// - `M`: The type of [`Message`]s in the audited actions.
+pub trait AuditorExt<M>: Auditor<M> {
+    /// Composes this auditor with another one, such that the properties of both are checked.
+    ///
+    /// # Arguments
+    /// - `other`: The other [`Auditor`] to check after this one.
+    ///
+    /// # Returns
+    /// An [`And`] that checks the properties of both auditors, `self`'s first.
+    #[inline]
+    fn and<A2>(self, other: A2) -> And<Self, A2, M>
+    where
+        Self: Sized,
+        A2: Auditor<M>,
+    {
+        And(self, other, PhantomData)
+    }
+}
+impl<M, A: Auditor<M>> AuditorExt<M> for A {}
+
+/// Combines two [`Auditor`]s, checking the properties of both.
+///
+/// It remembers the type of `M`essages it audits, such that it can be inferred from where the
+/// stack is used.
+pub struct And<A1, A2, M>(pub A1, pub A2, PhantomData<fn(&M)>);
+impl<A1: Clone, A2: Clone, M> Clone for And<A1, A2, M> {
+    #[inline]
+    fn clone(&self) -> Self { Self(self.0.clone(), self.1.clone(), PhantomData) }
+}
+impl<A1: Copy, A2: Copy, M> Copy for And<A1, A2, M> {}
+impl<A1: Debug, A2: Debug, M> Debug for And<A1, A2, M> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult { f.debug_tuple("And").field(&self.0).field(&self.1).finish() }
+}
+impl<A1: Default, A2: Default, M> Default for And<A1, A2, M> {
+    #[inline]
+    fn default() -> Self { Self(A1::default(), A2::default(), PhantomData) }
+}
+impl<M, A1: Auditor<M>, A2: Auditor<M>> Auditor<M> for And<A1, A2, M> {
+    #[inline]
+    fn audit<'a, E>(&self, ctx: &AuditContext<'a, M>, report: &mut ActionAuditReport<'a, M, E>)
+    where
+        M: 'a + Identifiable,
+        &'a M: Message<'a>,
+        E: Extractor<&'a M>,
+    {
+        self.0.audit::<E>(ctx, report);
+        self.1.audit::<E>(ctx, report);
+    }
+}
+
+
+
+/// Checks whether all messages in the justification are stated or agreed upon (property 3).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Stated;
+impl<M> Auditor<M> for Stated {
+    #[inline]
+    fn audit<'a, E>(&self, ctx: &AuditContext<'a, M>, report: &mut ActionAuditReport<'a, M, E>)
+    where
+        M: 'a + Identifiable,
+        &'a M: Message<'a>,
+        E: Extractor<&'a M>,
+    {
+        // Every message that is not stated must be agreed upon
+        for stmt in ctx.just.iter().filter(|stmt| !ctx.stated.contains(stmt.id_v()) && !ctx.agreed.contains((**stmt).id())) {
+            report.violations.push(AuditExplanation::Stated { stmt: stmt.id_v() });
+        }
+    }
+}
+
+/// Checks whether none of the messages in the justification are equivocated (property 3).
+///
+/// A message is equivocated if another version of it (i.e., a message with the same identifier but
+/// a different author or payload) is stated. This catches agents that use a version of a message
+/// that no-one stated, as well as conflicting versions stated by different agents.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Unequivocal;
+impl<M> Auditor<M> for Unequivocal {
+    #[inline]
+    fn audit<'a, E>(&self, ctx: &AuditContext<'a, M>, report: &mut ActionAuditReport<'a, M, E>)
+    where
+        M: 'a + Identifiable,
+        &'a M: Message<'a>,
+        E: Extractor<&'a M>,
+    {
+        let differs = |lhs: &'a M, rhs: &'a M| -> bool { lhs.author_v() != rhs.author_v() || lhs.payload() != rhs.payload() };
+        for msg in ctx.just.iter() {
+            // Collect all versions that differ from the used one, be they stated or known to be conflicting
+            let mut versions: Vec<&'a M> = Vec::new();
+            for other in ctx.stated.get(msg.id_v()).copied().into_iter().chain(ctx.equivocated.iter().copied().filter(|other| other.id_v() == msg.id_v()))
+            {
+                if differs(other, msg) && versions.iter().all(|version| differs(version, other)) {
+                    versions.push(other);
+                }
+            }
+
+            // Report them with the authors involved
+            if !versions.is_empty() {
+                let authors: Vec<&'a <&'a M as Authored>::AuthorId> =
+                    std::iter::once(msg.author_v()).chain(versions.into_iter().map(|version| version.author_v())).collect();
+                report.violations.push(AuditExplanation::Equivocated { stmt: msg.id_v(), authors });
+            }
+        }
+    }
+}
+
+/// Checks whether the basis and enactment are included in the justification (property 4).
+///
+/// [`Action::justification()`] always adds them, but they may still be shadowed by another message
+/// with the same identifier.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Included;
+impl<M> Auditor<M> for Included {
+    #[inline]
+    fn audit<'a, E>(&self, ctx: &AuditContext<'a, M>, report: &mut ActionAuditReport<'a, M, E>)
+    where
+        M: 'a + Identifiable,
+        &'a M: Message<'a>,
+        E: Extractor<&'a M>,
+    {
+        for msg in [&ctx.act.basis.msg, &ctx.act.enacts] {
+            // The justification must refer to this exact message...
+            let included: bool = ctx.just.get(msg.id()).map(|incl| std::ptr::eq(incl, msg)).unwrap_or(false);
+            // ...and the agent may not have given another version of it
+            let shadowed: bool =
+                ctx.act.just.get(msg.id()).map(|other| other.author_v() != msg.author_v() || other.payload() != msg.payload()).unwrap_or(false);
+            if !included || shadowed {
+                report.violations.push(AuditExplanation::Included { stmt: msg.id_v() });
+            }
+        }
+    }
+}
+
+/// Checks whether the policy in the justification can be extracted and is valid (property 5).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Valid;
+impl<M> Auditor<M> for Valid {
+    #[inline]
+    fn audit<'a, E>(&self, ctx: &AuditContext<'a, M>, report: &mut ActionAuditReport<'a, M, E>)
+    where
+        M: 'a + Identifiable,
+        &'a M: Message<'a>,
+        E: Extractor<&'a M>,
+    {
+        // Attempt to extract the policy, and check if it is valid if so
+        // NOTE: Extractors need a set, so only this auditor collects the justification into one
+        match ctx.just.iter().collect::<LocalSet<&'a M>>().extract::<E>() {
+            Ok(policy) => {
+                if let Err(expl) = policy.assert_validity() {
+                    report.violations.push(AuditExplanation::Valid { expl });
+                }
+            },
+            Err(err) => report.violations.push(AuditExplanation::Extract { err }),
+        }
+    }
+}
+
+/// Checks whether the basis is an agreement (property 6).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Based;
+impl<M> Auditor<M> for Based {
+    #[inline]
+    fn audit<'a, E>(&self, ctx: &AuditContext<'a, M>, report: &mut ActionAuditReport<'a, M, E>)
+    where
+        M: 'a + Identifiable,
+        &'a M: Message<'a>,
+        E: Extractor<&'a M>,
+    {
+        if !ctx.agreed.contains(ctx.act.basis.id()) {
+            report.violations.push(AuditExplanation::Based { stmt: (&ctx.act.basis.msg).id_v() });
+        }
+    }
+}
+
+/// Checks whether the basis applies at the time the action was taken (property 6).
+///
+/// If the basis is agreed upon, then the range of time for which it applies is taken from the
+/// agreements instead of from the action. This way, revocations are respected.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Timely;
+impl<M> Auditor<M> for Timely {
+    #[inline]
+    fn audit<'a, E>(&self, ctx: &AuditContext<'a, M>, report: &mut ActionAuditReport<'a, M, E>)
+    where
+        M: 'a + Identifiable,
+        &'a M: Message<'a>,
+        E: Extractor<&'a M>,
+    {
+        let act: &'a Action<M> = ctx.act;
+        let applies: TimeRange = ctx.agreed.get(act.basis.id()).map(|agr| agr.applies).unwrap_or(act.basis.applies);
+        if !applies.contains(act.timestamp) {
+            report.violations.push(AuditExplanation::Timely { stmt: (&act.basis.msg).id_v(), applies, taken_at: act.timestamp });
+        }
+    }
+}
+
+/// Checks all the properties from the paper, in order.
+///
+/// This is the [`Auditor`] used by [`Action::audit()`] and [`Action::audit_report()`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Paper;
+impl<M> Auditor<M> for Paper {
+    #[inline]
+    fn audit<'a, E>(&self, ctx: &AuditContext<'a, M>, report: &mut ActionAuditReport<'a, M, E>)
+    where
+        M: 'a + Identifiable,
+        &'a M: Message<'a>,
+        E: Extractor<&'a M>,
+    {
+        Stated.audit::<E>(ctx, report);
+        Unequivocal.audit::<E>(ctx, report);
+        Included.audit::<E>(ctx, report);
+        Valid.audit::<E>(ctx, report);
+        Based.audit::<E>(ctx, report);
+        Timely.audit::<E>(ctx, report);
+    }
+}
+
+
+
+/// Checks whether the justification of an action (including its basis and enactment) does not
+/// exceed the given number of messages.
+#[derive(Clone, Copy, Debug)]
+pub struct MaxJustification(pub usize);
+impl<M> Auditor<M> for MaxJustification {
+    #[inline]
+    fn audit<'a, E>(&self, ctx: &AuditContext<'a, M>, report: &mut ActionAuditReport<'a, M, E>)
+    where
+        M: 'a + Identifiable,
+        &'a M: Message<'a>,
+        E: Extractor<&'a M>,
+    {
+        if ctx.just.len() > self.0 {
+            report.violations.push(AuditExplanation::Custom {
+                property: "max-justification",
+                stmts:    ctx.just.iter().map(|msg| msg.id_v()).collect(),
+                reason:   format!("Justification has {} messages, but at most {} are allowed", ctx.just.len(), self.0),
+            });
+        }
+    }
+}
+
+/// Checks a custom property defined by a closure.
+///
+/// The closure returns the messages involved and a reason if the property does not hold.
+pub struct Property<F> {
+    /// The name of the property.
+    name:  &'static str,
+    /// The closure checking the property.
+    check: F,
+}
+impl<F> Property<F> {
+    /// Constructor for the Property.
+    ///
+    /// # Arguments
+    /// - `name`: Some name for the property, used to identify it in [`AuditExplanation::Custom`].
+    /// - `check`: The closure that checks the property for an action. It should return the
+    ///   messages involved and a human-readable reason if the property does not hold.
+    ///
+    /// # Returns
+    /// A new Property that can be used as an [`Auditor`].
+    #[inline]
+    pub fn new<M>(name: &'static str, check: F) -> Self
+    where
+        F: for<'a> Fn(&AuditContext<'a, M>) -> Result<(), (Vec<&'a M>, String)>,
+    {
+        Self { name, check }
+    }
+}
+impl<M, F> Auditor<M> for Property<F>
+where
+    F: for<'a> Fn(&AuditContext<'a, M>) -> Result<(), (Vec<&'a M>, String)>,
+{
+    #[inline]
+    fn audit<'a, E>(&self, ctx: &AuditContext<'a, M>, report: &mut ActionAuditReport<'a, M, E>)
+    where
+        M: 'a + Identifiable,
+        &'a M: Message<'a>,
+        E: Extractor<&'a M>,
+    {
+        if let Err((stmts, reason)) = (self.check)(ctx) {
+            report.violations.push(AuditExplanation::Custom { property: self.name, stmts: stmts.into_iter().map(|msg| msg.id_v()).collect(), reason });
+        }
+    }
+}
@@ -4,7 +4,7 @@
 //  Created:
 //    13 Mar 2024, 15:36:47
 //  Last edited:
-//    29 May 2024, 11:17:50
+//    16 Oct 2026, 19:28:41
 //  Auto updated?
 //    Yes
 //
@@ -17,9 +17,13 @@
 // Declare submodules
 pub mod agents;
 pub mod agreements;
+pub mod auditors;
 pub mod auxillary;
 pub mod iter;
+#[cfg(test)]
+mod mock;
 pub mod policy;
+pub mod search;
 pub mod set;
 pub mod statements;
 pub mod times;
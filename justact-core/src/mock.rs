@@ -0,0 +1,256 @@
+//  MOCK.rs
+//    by Lut99
+//
+//  Created:
+//    16 Oct 2026, 10:12:40
+//  Last edited:
+//    16 Oct 2026, 22:18:49
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Defines some minimal implementations of the framework's traits such
+//!   that the framework itself can be tested without depending on a
+//!   concrete policy language or runtime.
+//
+
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FResult};
+use std::str::Utf8Error;
+
+use crate::agreements::{Agreement, Agreements};
+use crate::auxillary::{Authored, Identifiable};
+use crate::policy::{Extractor, Policy, TaggedExtractor};
+use crate::set::LocalSet;
+use crate::statements::{Action, Message, Statements};
+use crate::times::{TimeRange, Timestamp};
+
+
+/***** ERRORS *****/
+/// The [`Policy::SemanticError`] of the [`MockPolicy`].
+#[derive(Debug)]
+pub struct MockInvalid;
+impl Display for MockInvalid {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult { write!(f, "\"error\" occurs in the policy, or something required does not") }
+}
+impl Error for MockInvalid {}
+
+/// The [`Agreements::Error`] of the [`MockAgreements`].
+#[derive(Debug)]
+pub struct MockUnknownAgreement {
+    /// The identifier of the agreement that does not exist.
+    pub id: String,
+}
+impl Display for MockUnknownAgreement {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult { write!(f, "Unknown agreement '{}'", self.id) }
+}
+impl Error for MockUnknownAgreement {}
+
+
+
+
+
+/***** LIBRARY *****/
+/// A simple message carrying its payload as text.
+#[derive(Clone, Debug)]
+pub struct MockMessage {
+    /// The identifier of the message.
+    pub id:      String,
+    /// The author of the message.
+    pub author:  String,
+    /// The payload of the message.
+    pub payload: String,
+    /// The language of the payload, if any.
+    pub lang:    Option<String>,
+}
+impl MockMessage {
+    /// Constructor for the MockMessage.
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the message.
+    /// - `author`: The author of the message.
+    /// - `payload`: The payload of the message.
+    ///
+    /// # Returns
+    /// A new MockMessage.
+    #[inline]
+    pub fn new(id: impl Into<String>, author: impl Into<String>, payload: impl Into<String>) -> Self {
+        Self { id: id.into(), author: author.into(), payload: payload.into(), lang: None }
+    }
+
+    /// Tags the MockMessage with a language.
+    ///
+    /// # Arguments
+    /// - `lang`: The language of the payload.
+    ///
+    /// # Returns
+    /// Self for chaining.
+    #[inline]
+    pub fn with_lang(mut self, lang: impl Into<String>) -> Self {
+        self.lang = Some(lang.into());
+        self
+    }
+}
+impl Identifiable for MockMessage {
+    type Id = str;
+
+    #[inline]
+    fn id(&self) -> &Self::Id { &self.id }
+}
+impl Authored for MockMessage {
+    type AuthorId = str;
+
+    #[inline]
+    fn author(&self) -> &Self::AuthorId { &self.author }
+}
+impl<'v> Message<'v> for &'v MockMessage {
+    #[inline]
+    fn id_v(&self) -> &'v Self::Id { &self.id }
+
+    #[inline]
+    fn author_v(&self) -> &'v Self::AuthorId { &self.author }
+
+    #[inline]
+    fn payload(&self) -> &'v [u8] { self.payload.as_bytes() }
+
+    #[inline]
+    fn lang(&self) -> Option<&'v str> { self.lang.as_deref() }
+}
+
+
+
+/// A policy that is simply the lines of all messages in a set.
+///
+/// It is valid if and only if none of these lines is `error`, and for every line `requires X`,
+/// there is also a line `X`.
+#[derive(Clone, Debug)]
+pub struct MockPolicy<'v> {
+    /// The lines in the policy.
+    pub lines: Vec<&'v str>,
+}
+impl<'v> Policy for MockPolicy<'v> {
+    type SemanticError = MockInvalid;
+
+    #[inline]
+    fn assert_validity(&self) -> Result<(), Self::SemanticError> {
+        if self.lines.contains(&"error") {
+            return Err(MockInvalid);
+        }
+        for line in &self.lines {
+            if let Some(req) = line.strip_prefix("requires ") {
+                if !self.lines.contains(&req) {
+                    return Err(MockInvalid);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The [`Extractor`] for the [`MockPolicy`].
+#[derive(Clone, Copy, Debug)]
+pub struct MockExtractor;
+impl<M> Extractor<M> for MockExtractor {
+    type Policy<'v> = MockPolicy<'v> where Self: 'v;
+    type SyntaxError<'v> = Utf8Error where Self: 'v;
+
+    #[inline]
+    fn extract<'v, R>(set: &LocalSet<M, R>) -> Result<Self::Policy<'v>, Self::SyntaxError<'v>>
+    where
+        Self: Sized,
+        M: Authored + Identifiable + Message<'v>,
+    {
+        let mut lines: Vec<&'v str> = Vec::new();
+        for msg in set {
+            lines.extend(std::str::from_utf8(msg.payload())?.lines());
+        }
+        Ok(MockPolicy { lines })
+    }
+}
+impl<M> TaggedExtractor<M> for MockExtractor {
+    #[inline]
+    fn handles(lang: &str) -> bool { lang == "mock" }
+}
+
+
+
+/// A set of statements that is simply a pair of local sets.
+#[derive(Debug, Default)]
+pub struct MockStatements {
+    /// The stated messages.
+    pub stmts: LocalSet<MockMessage>,
+    /// The enacted actions.
+    pub encts: LocalSet<Action<MockMessage>>,
+    /// Any conflicting versions of stated messages.
+    pub versions: Vec<MockMessage>,
+}
+impl Statements for MockStatements {
+    type Message = MockMessage;
+    type Target = ();
+    type Status = ();
+
+    #[inline]
+    fn state(&mut self, _target: Self::Target, msg: Self::Message) -> Self::Status { self.stmts.add(msg); }
+
+    #[inline]
+    fn stated(&self) -> LocalSet<&Self::Message> { self.stmts.iter().collect() }
+
+    #[inline]
+    fn equivocated(&self) -> Vec<&Self::Message> { self.versions.iter().collect() }
+
+    #[inline]
+    fn enact(&mut self, _target: Self::Target, act: Action<Self::Message>) -> Self::Status { self.encts.add(act); }
+
+    #[inline]
+    fn enacted(&self) -> LocalSet<&Action<Self::Message>> { self.encts.iter().collect() }
+}
+
+/// A set of agreements that is simply a local set.
+#[derive(Debug, Default)]
+pub struct MockAgreements {
+    /// The agreed upon agreements.
+    pub agrs: LocalSet<Agreement<MockMessage>>,
+}
+impl Agreements for MockAgreements {
+    type Message = MockMessage;
+    type Error = MockUnknownAgreement;
+
+    #[inline]
+    fn agree(&mut self, agr: Agreement<Self::Message>) -> Result<(), Self::Error> {
+        self.agrs.add(agr);
+        Ok(())
+    }
+
+    #[inline]
+    fn revoke(&mut self, id: &<Self::Message as Identifiable>::Id, at: Timestamp) -> Result<(), Self::Error> {
+        let mut agr: Agreement<MockMessage> = self.agrs.remove(id).ok_or_else(|| MockUnknownAgreement { id: id.into() })?;
+        agr.revoke(at);
+        self.agrs.add(agr);
+        Ok(())
+    }
+
+    #[inline]
+    fn agreed(&self) -> LocalSet<&Agreement<Self::Message>> { self.agrs.iter().collect() }
+}
+
+
+
+/// Builds the typical scenario from the paper for the mock types.
+///
+/// Specifically, it returns statements with an administrator authorising `amy` (`s2`) and her
+/// stating her access (`s3`), and agreements with the consortium's rules for timestamp 1 (`s1`).
+///
+/// # Returns
+/// A tuple of the statements, agreements and a well-behaved action based on them.
+pub fn scenario() -> (MockStatements, MockAgreements, Action<MockMessage>) {
+    let s1 = MockMessage::new("s1", "consortium", "rules");
+    let s2 = MockMessage::new("s2", "administrator", "authorises amy");
+    let s3 = MockMessage::new("s3", "amy", "accesses x_rays");
+
+    let stmts = MockStatements { stmts: LocalSet::from([s1.clone(), s2.clone(), s3.clone()]), encts: LocalSet::new(), versions: Vec::new() };
+    let agrs = MockAgreements { agrs: LocalSet::from([Agreement { msg: s1.clone(), applies: TimeRange::at(Timestamp(1)) }]) };
+    let act = Action { basis: Agreement { msg: s1, applies: TimeRange::at(Timestamp(1)) }, just: LocalSet::from([s2]), enacts: s3, timestamp: Timestamp(1) };
+    (stmts, agrs, act)
+}
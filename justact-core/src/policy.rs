@@ -4,7 +4,7 @@
 //  Created:
 //    29 May 2024, 11:17:34
 //  Last edited:
-//    29 May 2024, 11:51:27
+//    16 Oct 2026, 22:21:06
 //  Auto updated?
 //    Yes
 //
@@ -13,12 +13,144 @@
 //
 
 use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FResult};
+use std::marker::PhantomData;
 
 use crate::auxillary::{Authored, Identifiable};
 use crate::set::LocalSet;
 use crate::statements::Message;
 
 
+/***** TESTS *****/
+#[cfg(test)]
+mod tests {
+    use std::str::Utf8Error;
+
+    use super::*;
+    use crate::mock::{MockExtractor, MockMessage, MockPolicy};
+
+    /// An extractor for the same language as the [`MockExtractor`], but with a different tag.
+    struct OtherExtractor;
+    impl<M> Extractor<M> for OtherExtractor {
+        type Policy<'v> = MockPolicy<'v> where Self: 'v;
+        type SyntaxError<'v> = Utf8Error where Self: 'v;
+
+        #[inline]
+        fn extract<'v, R>(set: &LocalSet<M, R>) -> Result<Self::Policy<'v>, Self::SyntaxError<'v>>
+        where
+            Self: Sized,
+            M: Authored + Identifiable + Message<'v>,
+        {
+            <MockExtractor as Extractor<M>>::extract(set)
+        }
+    }
+    impl<M> TaggedExtractor<M> for OtherExtractor {
+        #[inline]
+        fn handles(lang: &str) -> bool { lang == "other" }
+    }
+
+    type Multi = MultiExtractor<MockExtractor, OtherExtractor>;
+
+
+    #[test]
+    fn test_multi_extractor() {
+        let s1 = MockMessage::new("s1", "amy", "requires x").with_lang("mock");
+        let s2 = MockMessage::new("s2", "bob", "x").with_lang("other");
+        let s3 = MockMessage::new("s3", "cho", "x");
+        let s4 = MockMessage::new("s4", "dan", "x").with_lang("unknown");
+
+        // Messages are given to the extractor of their language
+        let set: LocalSet<&MockMessage> = LocalSet::from([&s1, &s2]);
+        let policy: MultiPolicy<MockPolicy, MockPolicy> = Multi::extract(&set).unwrap();
+        assert_eq!(policy.first.lines, vec!["requires x"]);
+        assert_eq!(policy.second.lines, vec!["x"]);
+
+        // Untagged messages are given to the first language
+        let set: LocalSet<&MockMessage> = LocalSet::from([&s1, &s2, &s3]);
+        assert!(Multi::extract(&set).unwrap().assert_validity().is_ok());
+
+        // Unknown languages are rejected
+        let set: LocalSet<&MockMessage> = LocalSet::from([&s1, &s4]);
+        assert!(matches!(Multi::extract(&set), Err(MultiSyntaxError::UnknownLanguage { lang }) if lang == "unknown"));
+    }
+
+    #[test]
+    fn test_multi_policy_cross_language() {
+        // Within one language, `x` satisfies the requirement
+        let s1 = MockMessage::new("s1", "amy", "requires x").with_lang("mock");
+        let s2 = MockMessage::new("s2", "bob", "x").with_lang("mock");
+        let set: LocalSet<&MockMessage> = LocalSet::from([&s1, &s2]);
+        assert!(Multi::extract(&set).unwrap().assert_validity().is_ok());
+
+        // But stated in the other language, the first language never sees it
+        let s2 = MockMessage::new("s2", "bob", "x").with_lang("other");
+        let set: LocalSet<&MockMessage> = LocalSet::from([&s1, &s2]);
+        let policy: MultiPolicy<MockPolicy, MockPolicy> = Multi::extract(&set).unwrap();
+        assert!(policy.second.assert_validity().is_ok());
+        assert!(matches!(policy.assert_validity(), Err(MultiSemanticError::First { .. })));
+    }
+}
+
+
+
+
+
+/***** ERRORS *****/
+/// The [`Extractor::SyntaxError`] of the [`MultiExtractor`].
+///
+/// # Generics
+/// - `E1`: The syntax error of the first extractor.
+/// - `E2`: The syntax error of the second extractor.
+#[derive(Debug)]
+pub enum MultiSyntaxError<E1, E2> {
+    /// The first extractor failed to extract its part of the policy.
+    First { err: E1 },
+    /// The second extractor failed to extract its part of the policy.
+    Second { err: E2 },
+    /// A message was tagged with a language that none of the extractors handles.
+    UnknownLanguage { lang: String },
+}
+impl<E1: Display, E2: Display> Display for MultiSyntaxError<E1, E2> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use MultiSyntaxError::*;
+        match self {
+            First { err } => write!(f, "Failed to extract policy from messages in the first language: {err}"),
+            Second { err } => write!(f, "Failed to extract policy from messages in the second language: {err}"),
+            UnknownLanguage { lang } => write!(f, "No extractor handles messages in language '{lang}'"),
+        }
+    }
+}
+impl<E1: Error, E2: Error> Error for MultiSyntaxError<E1, E2> {}
+
+/// The [`Policy::SemanticError`] of the [`MultiPolicy`].
+///
+/// # Generics
+/// - `E1`: The semantic error of the first policy.
+/// - `E2`: The semantic error of the second policy.
+#[derive(Debug)]
+pub enum MultiSemanticError<E1, E2> {
+    /// The policy in the first language is not valid.
+    First { err: E1 },
+    /// The policy in the second language is not valid.
+    Second { err: E2 },
+}
+impl<E1: Display, E2: Display> Display for MultiSemanticError<E1, E2> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use MultiSemanticError::*;
+        match self {
+            First { err } => write!(f, "Policy in the first language is not valid: {err}"),
+            Second { err } => write!(f, "Policy in the second language is not valid: {err}"),
+        }
+    }
+}
+impl<E1: Error, E2: Error> Error for MultiSemanticError<E1, E2> {}
+
+
+
+
+
 /***** LIBRARY *****/
 /// Defines the framework's notion of policy.
 ///
@@ -37,11 +169,38 @@ pub trait Policy {
     fn assert_validity(&self) -> Result<(), Self::SemanticError>;
 }
 
+/// Extends a [`Policy`] with the ability to ask questions of it.
+///
+/// This is useful for agents that want to know what a policy (e.g., one
+/// [extracted](Extractor::extract()) from some set of messages) says before taking actions based
+/// on it.
+pub trait QueryablePolicy: Policy {
+    /// The type of questions that can be asked of the policy.
+    type Query<'q>;
+    /// The type of answers to those questions.
+    type Answer;
+    /// The type of error emitted when a question could not be answered.
+    type QueryError: Error;
+
+    /// Asks a question of this policy.
+    ///
+    /// # Arguments
+    /// - `query`: The [`Self::Query`](QueryablePolicy::Query) to answer.
+    ///
+    /// # Returns
+    /// A [`Self::Answer`](QueryablePolicy::Answer) encoding what the policy says about the `query`.
+    ///
+    /// # Errors
+    /// This function errors if the policy failed to answer the question for policy-specific reasons.
+    fn query<'q>(&self, query: Self::Query<'q>) -> Result<Self::Answer, Self::QueryError>;
+}
+
 
 
 /// Defines the `extract()`-function for some [`Policy`].
 ///
-/// This is what is used to get some policy from a set of messages.
+/// This is what is used to get some policy from a set of messages. If the extracted policy
+/// implements [`QueryablePolicy`], it can then be asked questions.
 ///
 /// # Generics
 /// - `M`: Some type of message that is contained within the given `set`.
@@ -75,3 +234,110 @@ pub trait Extractor<M> {
         Self: Sized,
         M: Authored + Identifiable + Message<'v>;
 }
+
+/// Extends an [`Extractor`] with the language(s) that it handles.
+///
+/// This is used by the [`MultiExtractor`] to decide which messages to send to which extractor,
+/// based on their [language tag](Message::lang()).
+///
+/// # Generics
+/// - `M`: Some type of message that is contained within the sets extracted from.
+pub trait TaggedExtractor<M>: Extractor<M> {
+    /// Checks whether this extractor handles messages in the given language.
+    ///
+    /// # Arguments
+    /// - `lang`: The language tag of some message.
+    ///
+    /// # Returns
+    /// True if messages tagged with `lang` should be given to this extractor, or false otherwise.
+    fn handles(lang: &str) -> bool;
+}
+
+
+
+/// A [`Policy`] that is composed of the policies of two different languages.
+///
+/// Each language's policy is checked on its own, after which the results are combined. Hence, the
+/// composed policy is valid if and only if both are.
+///
+/// Note that this means that the languages do not see each other's policy. Anything derived in
+/// one language is never visible to the other, so a rule in one language cannot be satisfied by a
+/// fact stated in another. Justifications that rely on that must be written in a single language.
+///
+/// # Generics
+/// - `P1`: The policy in the first language.
+/// - `P2`: The policy in the second language.
+#[derive(Clone, Debug)]
+pub struct MultiPolicy<P1, P2> {
+    /// The policy in the first language.
+    pub first:  P1,
+    /// The policy in the second language.
+    pub second: P2,
+}
+impl<P1: Policy, P2: Policy> Policy for MultiPolicy<P1, P2> {
+    type SemanticError = MultiSemanticError<P1::SemanticError, P2::SemanticError>;
+
+    #[inline]
+    fn assert_validity(&self) -> Result<(), Self::SemanticError> {
+        self.first.assert_validity().map_err(|err| MultiSemanticError::First { err })?;
+        self.second.assert_validity().map_err(|err| MultiSemanticError::Second { err })
+    }
+}
+
+/// An [`Extractor`] that dispatches messages to one of two extractors based on their
+/// [language tag](Message::lang()).
+///
+/// Messages without a tag are given to the first extractor. More than two languages can be
+/// supported by nesting, e.g., `MultiExtractor<E1, MultiExtractor<E2, E3>>`.
+///
+/// # Generics
+/// - `E1`: The first [`TaggedExtractor`], which also handles untagged messages.
+/// - `E2`: The second [`TaggedExtractor`].
+#[derive(Clone, Copy, Debug)]
+pub struct MultiExtractor<E1, E2> {
+    _e1: PhantomData<E1>,
+    _e2: PhantomData<E2>,
+}
+impl<M, E1, E2> Extractor<M> for MultiExtractor<E1, E2>
+where
+    M: Clone,
+    E1: TaggedExtractor<M>,
+    E2: TaggedExtractor<M>,
+{
+    type Policy<'v> = MultiPolicy<E1::Policy<'v>, E2::Policy<'v>> where Self: 'v;
+    type SyntaxError<'v> = MultiSyntaxError<E1::SyntaxError<'v>, E2::SyntaxError<'v>> where Self: 'v;
+
+    #[inline]
+    fn extract<'v, R>(set: &LocalSet<M, R>) -> Result<Self::Policy<'v>, Self::SyntaxError<'v>>
+    where
+        Self: Sized,
+        M: Authored + Identifiable + Message<'v>,
+    {
+        // Sort the messages by language
+        let mut first: LocalSet<M> = LocalSet::new();
+        let mut second: LocalSet<M> = LocalSet::new();
+        for msg in set {
+            match msg.lang() {
+                None => first.add(msg.clone()),
+                Some(lang) if E1::handles(lang) => first.add(msg.clone()),
+                Some(lang) if E2::handles(lang) => second.add(msg.clone()),
+                Some(lang) => return Err(MultiSyntaxError::UnknownLanguage { lang: lang.into() }),
+            };
+        }
+
+        // Extract them separately
+        Ok(MultiPolicy {
+            first:  E1::extract(&first).map_err(|err| MultiSyntaxError::First { err })?,
+            second: E2::extract(&second).map_err(|err| MultiSyntaxError::Second { err })?,
+        })
+    }
+}
+impl<M, E1, E2> TaggedExtractor<M> for MultiExtractor<E1, E2>
+where
+    M: Clone,
+    E1: TaggedExtractor<M>,
+    E2: TaggedExtractor<M>,
+{
+    #[inline]
+    fn handles(lang: &str) -> bool { E1::handles(lang) || E2::handles(lang) }
+}
@@ -0,0 +1,488 @@
+//  SEARCH.rs
+//    by Lut99
+//
+//  Created:
+//    16 Oct 2026, 20:05:12
+//  Last edited:
+//    16 Oct 2026, 22:22:09
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements helpers for agents to automatically find a justification
+//!   for the actions they want to enact, and for auditors to analyze
+//!   which parts of a justification are actually needed.
+//
+
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FResult};
+
+use crate::agreements::{Agreement, Agreements};
+use crate::auditors::Auditor;
+use crate::auxillary::Identifiable;
+use crate::policy::{Extractor, Policy};
+use crate::set::LocalSet;
+use crate::statements::{Action, Message, Statements};
+use crate::times::Timestamp;
+
+
+/***** TESTS *****/
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auditors::Paper;
+    use crate::mock::{scenario, MockExtractor, MockMessage};
+
+
+    #[test]
+    fn test_find_justification() {
+        let (mut stmts, agrs, act) = scenario();
+        stmts.stmts.add(MockMessage::new("s4", "anton", "authorises anton"));
+        stmts.stmts.add(MockMessage::new("s5", "anton", "error"));
+        stmts.stmts.add(MockMessage::new("s6", "amy", "requires authorises amy"));
+        stmts.stmts.add(MockMessage::new("s7", "amy", "requires nobody"));
+        let stated: LocalSet<&MockMessage> = stmts.stated();
+        let basis: &Agreement<MockMessage> = agrs.agrs.get("s1").unwrap();
+
+        // Something that is valid on its own needs no justification
+        let just: LocalSet<&MockMessage> = find_justification::<MockExtractor, _, _>(basis, &act.enacts, &stated, 16).unwrap();
+        assert!(just.is_empty());
+
+        // Something that requires something finds only that
+        let enacts: &MockMessage = stated.get("s6").unwrap();
+        let just: LocalSet<&MockMessage> = find_justification::<MockExtractor, _, _>(basis, enacts, &stated, 16).unwrap();
+        assert_eq!(just.len(), 1);
+        assert!(just.contains("s2"));
+
+        // ...unless we run out of budget
+        assert!(matches!(find_justification::<MockExtractor, _, _>(basis, enacts, &stated, 1), Err(SearchError::BudgetExhausted { budget: 1 })));
+
+        // Something that requires the impossible never finds anything
+        let enacts: &MockMessage = stated.get("s7").unwrap();
+        assert!(matches!(find_justification::<MockExtractor, _, _>(basis, enacts, &stated, 1024), Err(SearchError::NotFound { tried: 32 })));
+    }
+
+    #[test]
+    fn test_find_justification_with() {
+        let (mut stmts, agrs, act) = scenario();
+        stmts.stmts.add(MockMessage::new("s4", "anton", "authorises amy"));
+        stmts.stmts.add(MockMessage::new("s6", "amy", "requires authorises amy"));
+        stmts.versions = vec![MockMessage::new("s2", "administrator", "authorises amy"), MockMessage::new("s2", "anton", "authorises anton")];
+        let basis: &Agreement<MockMessage> = agrs.agrs.get("s1").unwrap();
+        let enacts: &MockMessage = stmts.stmts.get("s6").unwrap();
+
+        // Only checking validity happily uses the equivocated message...
+        let stated: LocalSet<&MockMessage> = stmts.stated();
+        let just: LocalSet<&MockMessage> = find_justification::<MockExtractor, _, _>(basis, enacts, &stated, 16).unwrap();
+        assert_eq!(just.len(), 1);
+        assert!(just.contains("s2"));
+
+        // ...but auditing skips it in favour of one that passes
+        let just: LocalSet<&MockMessage> =
+            find_justification_with::<MockExtractor, _, _, _, _>(&Paper, basis, enacts, act.timestamp, &stmts, &agrs, 16).unwrap();
+        assert_eq!(just.len(), 1);
+        assert!(just.contains("s4"));
+
+        // Nothing helps if the basis does not apply at the time
+        assert!(matches!(
+            find_justification_with::<MockExtractor, _, _, _, _>(&Paper, basis, enacts, Timestamp(2), &stmts, &agrs, 1024),
+            Err(SearchError::NotFound { tried: 8 })
+        ));
+    }
+
+    #[test]
+    fn test_analyze_justification() {
+        // Nothing is required in the default scenario, so the empty set is the only minimal
+        // justification and `s2` is superfluous
+        let (_, _, mut act) = scenario();
+        let analysis = analyze_justification::<MockExtractor, _>(&act, 16).unwrap();
+        assert_eq!(analysis.minimal.len(), 1);
+        assert!(analysis.minimal[0].is_empty());
+        assert_eq!(analysis.superfluous.len(), 1);
+        assert!(analysis.superfluous.contains("s2"));
+
+        // Now require things, which can be satisfied by either of two messages
+        act.enacts = MockMessage::new("s3", "amy", "requires authorises amy\nrequires accesses x_rays");
+        act.just.add(MockMessage::new("s4", "administrator", "authorises amy\naccesses x_rays"));
+        act.just.add(MockMessage::new("s5", "anton", "accesses x_rays"));
+        act.just.add(MockMessage::new("s6", "anton", "authorises anton"));
+        let analysis = analyze_justification::<MockExtractor, _>(&act, 64).unwrap();
+        let mut minimal: Vec<Vec<&str>> = analysis
+            .minimal
+            .iter()
+            .map(|just| {
+                let mut ids: Vec<&str> = just.iter().map(|msg| msg.id.as_str()).collect();
+                ids.sort();
+                ids
+            })
+            .collect();
+        minimal.sort();
+        assert_eq!(minimal, vec![vec!["s2", "s5"], vec!["s4"]]);
+        assert_eq!(analysis.superfluous.len(), 1);
+        assert!(analysis.superfluous.contains("s6"));
+
+        // The budget is respected
+        assert!(matches!(analyze_justification::<MockExtractor, _>(&act, 4), Err(SearchError::BudgetExhausted { budget: 4 })));
+
+        // An invalid justification has no minimal subsets, and thus nothing superfluous either
+        act.enacts = MockMessage::new("s3", "amy", "requires nobody");
+        let analysis = analyze_justification::<MockExtractor, _>(&act, 64).unwrap();
+        assert!(analysis.minimal.is_empty());
+        assert!(analysis.superfluous.is_empty());
+
+        // Not even if some of its subsets are valid
+        let (_, _, mut act) = scenario();
+        act.just.add(MockMessage::new("s4", "anton", "error"));
+        let analysis = analyze_justification::<MockExtractor, _>(&act, 64).unwrap();
+        assert!(analysis.minimal.is_empty());
+        assert!(analysis.superfluous.is_empty());
+    }
+}
+
+
+
+
+
+/***** ERRORS *****/
+/// Defines the reasons why [`find_justification()`] failed to find a justification.
+#[derive(Debug)]
+pub enum SearchError {
+    /// More candidate justifications had to be tried than allowed.
+    BudgetExhausted { budget: usize },
+    /// All candidate justifications were tried, but none were valid (or passed the audit).
+    NotFound { tried: usize },
+}
+impl Display for SearchError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use SearchError::*;
+        match self {
+            BudgetExhausted { budget } => write!(f, "Failed to find a valid justification within {budget} candidate(s)"),
+            NotFound { tried } => write!(f, "No valid justification exists (tried all {tried} candidate(s))"),
+        }
+    }
+}
+impl Error for SearchError {}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Tries combinations of candidates in order of increasing size, until one is accepted.
+///
+/// Within combinations of the same size, candidates are tried in order.
+///
+/// # Arguments
+/// - `n`: The number of candidates to choose from.
+/// - `budget`: The maximum number of combinations to try.
+/// - `accept`: Some closure deciding whether a combination (as indices of candidates) is the one.
+///
+/// # Returns
+/// The indices of the first combination that was accepted.
+///
+/// # Errors
+/// This function errors if the `budget` was exhausted before a combination was accepted, or if
+/// none were accepted at all.
+fn search(n: usize, budget: usize, mut accept: impl FnMut(&[usize]) -> bool) -> Result<Vec<usize>, SearchError> {
+    let mut tried: usize = 0;
+    let mut idxs: Vec<usize> = Vec::with_capacity(n);
+    for size in 0..=n {
+        idxs.clear();
+        idxs.extend(0..size);
+        loop {
+            // Respect the budget
+            if tried >= budget {
+                return Err(SearchError::BudgetExhausted { budget });
+            }
+            tried += 1;
+
+            // Return the first combination that is accepted
+            if accept(&idxs) {
+                return Ok(idxs);
+            }
+
+            // Move to the next one of this size
+            if !next_combination(&mut idxs, n) {
+                break;
+            }
+        }
+    }
+    Err(SearchError::NotFound { tried })
+}
+
+/// Checks whether some candidate messages, together with a basis and enactment, form a valid justification.
+///
+/// # Generics
+/// - `E`: The [`Extractor`] of the [`Policy`] language that is used to verify the messages' payload's validity.
+///
+/// # Arguments
+/// - `cands`: The candidate messages to choose from.
+/// - `idxs`: The indices of the chosen candidates.
+/// - `basis`: The message in the basis of the action.
+/// - `enacts`: The message that the action enacts.
+///
+/// # Returns
+/// True if the policy in the chosen candidates, `basis` and `enacts` can be extracted and is valid, or false otherwise.
+fn justifies<'v, E, M>(cands: &[&'v M], idxs: &[usize], basis: &'v M, enacts: &'v M) -> bool
+where
+    E: 'v + Extractor<&'v M>,
+    M: Identifiable,
+    &'v M: Message<'v>,
+{
+    let mut just: LocalSet<&'v M> = LocalSet::with_capacity(idxs.len() + 2);
+    just.extend(idxs.iter().map(|i| cands[*i]));
+    just.add(basis);
+    just.add(enacts);
+    just.extract::<E>().map(|policy| policy.assert_validity().is_ok()).unwrap_or(false)
+}
+
+/// Collects the candidates for a justification in a predictable order.
+///
+/// # Arguments
+/// - `msgs`: The messages to collect.
+/// - `basis`: The message in the basis of the action, which is excluded.
+/// - `enacts`: The message that the action enacts, which is excluded.
+///
+/// # Returns
+/// A list of `msgs` without `basis` or `enacts`, ordered by identifier.
+fn candidates<'v, M>(msgs: impl IntoIterator<Item = &'v M>, basis: &'v M, enacts: &'v M) -> Vec<&'v M>
+where
+    M: 'v + Identifiable,
+    M::Id: Ord,
+{
+    let mut cands: Vec<&'v M> = msgs.into_iter().filter(|msg| msg.id() != basis.id() && msg.id() != enacts.id()).collect();
+    cands.sort_by(|lhs, rhs| lhs.id().cmp(rhs.id()));
+    cands
+}
+
+/// Advances a set of indices to the next combination of the same size.
+///
+/// Combinations are generated in lexicographical order, e.g., `[0, 1]`, `[0, 2]`, `[1, 2]` for
+/// combinations of two out of three.
+///
+/// # Arguments
+/// - `idxs`: The (strictly increasing) indices to advance.
+/// - `n`: The total number of elements to choose from.
+///
+/// # Returns
+/// True if `idxs` now contains the next combination, or false if it was the last one.
+fn next_combination(idxs: &mut [usize], n: usize) -> bool {
+    // Find the rightmost index that can still be incremented
+    let k: usize = idxs.len();
+    for i in (0..k).rev() {
+        if idxs[i] < n - k + i {
+            // Increment it, then reset all indices after it to follow it directly
+            idxs[i] += 1;
+            for j in i + 1..k {
+                idxs[j] = idxs[j - 1] + 1;
+            }
+            return true;
+        }
+    }
+    false
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Describes which parts of an action's justification are needed for it to be valid.
+///
+/// Built by [`analyze_justification()`].
+#[derive(Debug)]
+pub struct JustificationAnalysis<'a, M> {
+    /// All subsets of the justification (excluding the basis and enactment) that are valid, but
+    /// of which no proper subset is.
+    ///
+    /// This is empty if the justification as a whole is not valid.
+    pub minimal:     Vec<LocalSet<&'a M>>,
+    /// The messages in the justification that do not occur in any of the `minimal` subsets, and
+    /// thus play no part in its validity.
+    pub superfluous: LocalSet<&'a M>,
+}
+
+/// Searches for a justification that makes an action with the given basis and enactment valid.
+///
+/// The search tries subsets of the stated messages in order of increasing size, and returns the
+/// first for which the extracted policy is [valid](Policy::assert_validity()). As such, the
+/// justification found is always of minimal size. Within sets of the same size, messages are
+/// considered in order of their identifiers.
+///
+/// Note that this only checks the validity of the justification (property 5). The justification
+/// only consists of stated messages and never includes the `basis` or the `enacts` themselves,
+/// but whether those are stated, unequivocal or (timely) agreed upon is up to the caller. Use
+/// [`find_justification_with()`] to find a justification that passes an audit instead.
+///
+/// # Generics
+/// - `E`: The [`Extractor`] of the [`Policy`] language that is used to verify the messages' payload's validity.
+///
+/// # Arguments
+/// - `basis`: The [`Agreement`] that the action will be based upon.
+/// - `enacts`: The message that the action will enact.
+/// - `stated`: The stated messages to search through.
+/// - `budget`: The maximum number of candidate justifications to try (i.e., policies to extract).
+///
+/// # Returns
+/// A [`LocalSet`] with the messages in `stated` that justify the action.
+///
+/// # Errors
+/// This function errors if the `budget` was exhausted before a valid justification was found, or
+/// if no valid justification exists at all.
+pub fn find_justification<'v, E, M, R>(
+    basis: &'v Agreement<M>,
+    enacts: &'v M,
+    stated: &LocalSet<&'v M, R>,
+    budget: usize,
+) -> Result<LocalSet<&'v M>, SearchError>
+where
+    E: 'v + Extractor<&'v M>,
+    M: Identifiable,
+    M::Id: Ord,
+    &'v M: Message<'v>,
+{
+    let cands: Vec<&'v M> = candidates(stated.iter().copied(), &basis.msg, enacts);
+    let idxs: Vec<usize> = search(cands.len(), budget, |idxs| justifies::<E, M>(&cands, idxs, &basis.msg, enacts))?;
+    Ok(idxs.into_iter().map(|i| cands[i]).collect())
+}
+
+/// Searches for a justification with which an action with the given basis and enactment passes
+/// an audit.
+///
+/// This works like [`find_justification()`], except that every candidate justification is
+/// turned into an [`Action`] that is [audited](Action::audit_with()) with the given `auditor`.
+/// Hence, with the [`Paper`](crate::auditors::Paper) auditor, messages that are equivocated are
+/// avoided, and nothing is found if the `basis` is not agreed upon at the `timestamp`. Note that
+/// the `enacts` must be stated for the action to pass that audit.
+///
+/// # Generics
+/// - `E`: The [`Extractor`] of the [`Policy`] language that is used to verify the messages' payload's validity.
+/// - `S`: The type of `stmts` given to find candidates and check which messages are stated.
+/// - `A`: The type of `agrs` given to check which agreements are actually agreed upon.
+/// - `U`: The type of [`Auditor`] that checks the properties.
+///
+/// # Arguments
+/// - `auditor`: The [`Auditor`] (stack) that the action must pass.
+/// - `basis`: The [`Agreement`] that the action will be based upon.
+/// - `enacts`: The message that the action will enact.
+/// - `timestamp`: The [`Timestamp`] at which the action will be taken.
+/// - `stmts`: The [`Statements`] of which the stated messages are searched through.
+/// - `agrs`: The [`Agreements`] against which the action is audited.
+/// - `budget`: The maximum number of candidate justifications to try (i.e., actions to audit).
+///
+/// # Returns
+/// A [`LocalSet`] with the stated messages that justify the action.
+///
+/// # Errors
+/// This function errors if the `budget` was exhausted before a justification was found that
+/// passes the audit, or if no such justification exists at all.
+pub fn find_justification_with<'v, E, M, S, A, U>(
+    auditor: &U,
+    basis: &'v Agreement<M>,
+    enacts: &'v M,
+    timestamp: Timestamp,
+    stmts: &'v S,
+    agrs: &'v A,
+    budget: usize,
+) -> Result<LocalSet<&'v M>, SearchError>
+where
+    E: for<'a> Extractor<&'a M>,
+    M: Clone + Identifiable,
+    M::Id: Ord,
+    for<'a> &'a M: Message<'a>,
+    S: Statements<Message = M>,
+    A: Agreements<Message = M>,
+    U: Auditor<M>,
+{
+    let stated: LocalSet<&'v M> = stmts.stated();
+    let cands: Vec<&'v M> = candidates(stated.iter().copied(), &basis.msg, enacts);
+    let idxs: Vec<usize> = search(cands.len(), budget, |idxs| {
+        let act: Action<M> = Action {
+            basis: basis.clone(),
+            just: idxs.iter().map(|i| cands[*i].clone()).collect(),
+            enacts: enacts.clone(),
+            timestamp,
+        };
+        // Bound first, since the report borrows `act` and would otherwise outlive it
+        let passes: bool = act.audit_with::<E, S, A, U>(auditor, stmts, agrs).is_ok();
+        passes
+    })?;
+    Ok(idxs.into_iter().map(|i| cands[i]).collect())
+}
+
+/// Analyzes which parts of an action's justification are needed for it to be valid.
+///
+/// This finds all minimal subsets of the action's justification that are still valid when
+/// combined with its basis and enactment. Any message in the justification that is not in one of
+/// those subsets is superfluous.
+///
+/// If the justification as a whole is not valid, then nothing is analyzed and the result is
+/// empty. This matters for non-monotonic policies, where subsets of an invalid justification may
+/// still be valid.
+///
+/// Note that this only considers the validity of the justification (property 5). Use
+/// [`Action::audit()`] to check the other properties.
+///
+/// # Generics
+/// - `E`: The [`Extractor`] of the [`Policy`] language that is used to verify the messages' payload's validity.
+///
+/// # Arguments
+/// - `act`: The [`Action`] to analyze.
+/// - `budget`: The maximum number of candidate justifications to try (i.e., policies to extract).
+///
+/// # Returns
+/// A [`JustificationAnalysis`] describing the minimal subsets and the superfluous messages.
+///
+/// # Errors
+/// This function errors if the `budget` was exhausted before all subsets were considered.
+pub fn analyze_justification<'a, E, M>(act: &'a Action<M>, budget: usize) -> Result<JustificationAnalysis<'a, M>, SearchError>
+where
+    E: 'a + Extractor<&'a M>,
+    M: Identifiable,
+    M::Id: Ord,
+    &'a M: Message<'a>,
+{
+    let cands: Vec<&'a M> = candidates(&act.just, &act.basis.msg, &act.enacts);
+
+    // There is nothing to analyze if the justification is not valid as a whole
+    if budget == 0 {
+        return Err(SearchError::BudgetExhausted { budget });
+    }
+    let all: Vec<usize> = (0..cands.len()).collect();
+    if !justifies::<E, M>(&cands, &all, &act.basis.msg, &act.enacts) {
+        return Ok(JustificationAnalysis { minimal: Vec::new(), superfluous: LocalSet::new() });
+    }
+
+    // Try all combinations in order of size, skipping those that contain a smaller valid one
+    let mut tried: usize = 1;
+    let mut minimal: Vec<Vec<usize>> = Vec::new();
+    let mut idxs: Vec<usize> = Vec::with_capacity(cands.len());
+    for size in 0..=cands.len() {
+        idxs.clear();
+        idxs.extend(0..size);
+        loop {
+            if !minimal.iter().any(|min| min.iter().all(|i| idxs.contains(i))) {
+                // Respect the budget
+                if tried >= budget {
+                    return Err(SearchError::BudgetExhausted { budget });
+                }
+                tried += 1;
+
+                // Note down the combination if it's valid
+                if justifies::<E, M>(&cands, &idxs, &act.basis.msg, &act.enacts) {
+                    minimal.push(idxs.clone());
+                }
+            }
+
+            // Move to the next one of this size
+            if !next_combination(&mut idxs, cands.len()) {
+                break;
+            }
+        }
+    }
+
+    // Find the superfluous messages based on that
+    let superfluous: LocalSet<&'a M> = (0..cands.len()).filter(|i| !minimal.iter().any(|min| min.contains(i))).map(|i| cands[i]).collect();
+    Ok(JustificationAnalysis { minimal: minimal.into_iter().map(|min| min.into_iter().map(|i| cands[i]).collect()).collect(), superfluous })
+}
@@ -4,7 +4,7 @@
 //  Created:
 //    16 Apr 2024, 10:14:23
 //  Last edited:
-//    29 May 2024, 13:35:03
+//    16 Oct 2026, 21:08:13
 //  Auto updated?
 //    Yes
 //
@@ -13,8 +13,11 @@
 //!   unordered set of messages or actions.
 //
 
-use std::collections::HashMap;
+use std::collections::hash_map::{Drain as HashMapDrain, Entry, HashMap, IntoValues, Values};
 use std::hash::{BuildHasher, Hash, RandomState};
+use std::iter::FusedIterator;
+use std::slice::Iter as SliceIter;
+use std::vec::IntoIter as VecIntoIter;
 
 use nohash_hasher::BuildNoHashHasher;
 
@@ -23,6 +26,436 @@ use crate::policy::Extractor;
 use crate::statements::Message;
 
 
+/***** TESTS *****/
+#[cfg(test)]
+mod tests {
+    use std::hash::{BuildHasher, Hasher};
+
+    use super::*;
+    use crate::mock::MockMessage;
+
+
+    /// A [`Hasher`] that hashes everything to the same value, forcing collisions.
+    struct CollidingHasher;
+    impl Hasher for CollidingHasher {
+        #[inline]
+        fn finish(&self) -> u64 { 42 }
+
+        #[inline]
+        fn write(&mut self, _bytes: &[u8]) {}
+    }
+
+    /// The [`BuildHasher`] for the [`CollidingHasher`].
+    #[derive(Clone, Copy, Debug, Default)]
+    struct CollidingState;
+    impl BuildHasher for CollidingState {
+        type Hasher = CollidingHasher;
+
+        #[inline]
+        fn build_hasher(&self) -> Self::Hasher { CollidingHasher }
+    }
+
+
+    #[test]
+    fn test_local_set_collisions() {
+        let mut set: LocalSet<MockMessage, CollidingState> = LocalSet::new();
+        assert_eq!(set.add(MockMessage::new("a", "amy", "foo")).map(|m| m.id), None);
+        assert_eq!(set.add(MockMessage::new("b", "anton", "bar")).map(|m| m.id), None);
+        assert_eq!(set.add(MockMessage::new("c", "anton", "baz")).map(|m| m.id), None);
+
+        // All three should be there, with the correct message
+        assert_eq!(set.len(), 3);
+        assert_eq!(set.iter().count(), 3);
+        assert_eq!(set.get("a").map(|m| m.payload.as_str()), Some("foo"));
+        assert_eq!(set.get("b").map(|m| m.payload.as_str()), Some("bar"));
+        assert_eq!(set.get("c").map(|m| m.payload.as_str()), Some("baz"));
+        assert!(!set.contains("d"));
+
+        // Replacing only replaces the element with the same identifier
+        assert_eq!(set.add(MockMessage::new("b", "anton", "quz")).map(|m| m.payload), Some("bar".into()));
+        assert_eq!(set.len(), 3);
+        assert_eq!(set.get("a").map(|m| m.payload.as_str()), Some("foo"));
+        assert_eq!(set.get("b").map(|m| m.payload.as_str()), Some("quz"));
+
+        // Removing only removes the element with the same identifier
+        assert_eq!(set.remove("a").map(|m| m.id), Some("a".into()));
+        assert_eq!(set.remove("a").map(|m| m.id), None);
+        assert_eq!(set.len(), 2);
+        assert!(!set.contains("a"));
+        assert!(set.contains("b"));
+        assert!(set.contains("c"));
+        assert_eq!(set.remove("c").map(|m| m.id), Some("c".into()));
+        assert_eq!(set.remove("b").map(|m| m.id), Some("b".into()));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_local_set_from_iter() {
+        // Collisions are also resolved when collecting
+        let set: LocalSet<MockMessage, CollidingState> =
+            LocalSet::from([MockMessage::new("a", "amy", "foo"), MockMessage::new("b", "anton", "bar"), MockMessage::new("a", "amy", "baz")]);
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.get("a").map(|m| m.payload.as_str()), Some("baz"));
+        let mut ids: Vec<String> = set.into_iter().map(|m| m.id).collect();
+        ids.sort();
+        assert_eq!(ids, vec!["a".to_string(), "b".to_string()]);
+
+        // And it should behave the same for non-colliding hashers
+        let set: LocalSet<MockMessage> =
+            LocalSet::from([MockMessage::new("a", "amy", "foo"), MockMessage::new("b", "anton", "bar"), MockMessage::new("a", "amy", "baz")]);
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.iter().count(), 2);
+        assert_eq!(set.get("a").map(|m| m.payload.as_str()), Some("baz"));
+    }
+
+    #[test]
+    fn test_local_set_algebra() {
+        /// Collects the identifiers of the given messages in sorted order.
+        fn ids<'a>(msgs: impl IntoIterator<Item = &'a MockMessage>) -> Vec<&'a str> {
+            let mut ids: Vec<&str> = msgs.into_iter().map(|m| m.id.as_str()).collect();
+            ids.sort();
+            ids
+        }
+
+        let left: LocalSet<MockMessage, CollidingState> =
+            LocalSet::from([MockMessage::new("a", "amy", "foo"), MockMessage::new("b", "amy", "bar"), MockMessage::new("c", "amy", "baz")]);
+        let right: LocalSet<MockMessage> = LocalSet::from([MockMessage::new("b", "anton", "quz"), MockMessage::new("d", "anton", "qux")]);
+        let refs: LocalSet<&MockMessage> = left.iter().collect();
+
+        // Views
+        assert_eq!(ids(left.union(&right)), vec!["a", "b", "c", "d"]);
+        assert_eq!(left.union(&right).find(|m| m.id == "b").map(|m| m.author.as_str()), Some("amy"));
+        assert_eq!(ids(left.intersection(&right)), vec!["b"]);
+        assert_eq!(ids(left.difference(&right)), vec!["a", "c"]);
+        assert_eq!(ids(right.difference(&left)), vec!["d"]);
+
+        // Subsets (also across element types)
+        assert!(refs.is_subset(&left));
+        assert!(left.is_subset(&refs));
+        assert!(!left.is_subset(&right));
+        assert!(LocalSet::<MockMessage>::new().is_subset(&right));
+
+        // Mutations
+        let mut set = left.clone();
+        set.retain(|m| m.id != "b");
+        assert_eq!(set.len(), 2);
+        assert_eq!(ids(&set), vec!["a", "c"]);
+        let mut drained: Vec<MockMessage> = set.drain().collect();
+        drained.sort_by(|lhs, rhs| lhs.id.cmp(&rhs.id));
+        assert_eq!(ids(&drained), vec!["a", "c"]);
+        assert!(set.is_empty());
+        assert!(!set.contains("a"));
+    }
+}
+
+
+
+
+
+/***** AUXILLARY *****/
+/// Represents the elements in a [`LocalSet`] of which the identifiers hash to the same value.
+///
+/// In the overwhelmingly common case, there is only one of those. Only when hashes collide do we
+/// fall back to a list that is searched by comparing the identifiers themselves.
+#[derive(Clone, Debug)]
+enum Bucket<V> {
+    /// There is only one element with this hash.
+    One(V),
+    /// There are multiple elements with this hash (i.e., a collision happened).
+    Many(Vec<V>),
+}
+impl<V> Bucket<V> {
+    /// Returns the elements in this bucket as a slice.
+    ///
+    /// # Returns
+    /// A slice with one or more `V`s.
+    #[inline]
+    fn as_slice(&self) -> &[V] {
+        match self {
+            Self::One(elem) => std::slice::from_ref(elem),
+            Self::Many(elems) => elems,
+        }
+    }
+}
+impl<V: Identifiable> Bucket<V> {
+    /// Retrieves the element with the given identifier from this bucket.
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the element to retrieve.
+    ///
+    /// # Returns
+    /// The referred element if it was in this bucket, or else [`None`].
+    #[inline]
+    fn get(&self, id: &V::Id) -> Option<&V> { self.as_slice().iter().find(|elem| elem.id() == id) }
+
+    /// Adds an element to this bucket, replacing any with the same identifier.
+    ///
+    /// # Arguments
+    /// - `elem`: The element to add.
+    ///
+    /// # Returns
+    /// The old element if one with the same identifier already existed, or else [`None`].
+    fn add(&mut self, elem: V) -> Option<V> {
+        match self {
+            Self::One(old) => {
+                if old.id() == elem.id() {
+                    Some(std::mem::replace(old, elem))
+                } else {
+                    // Collision; upgrade to a list
+                    let Self::One(old) = std::mem::replace(self, Self::Many(Vec::with_capacity(2))) else { unreachable!() };
+                    let Self::Many(elems) = self else { unreachable!() };
+                    elems.push(old);
+                    elems.push(elem);
+                    None
+                }
+            },
+            Self::Many(elems) => match elems.iter_mut().find(|old| old.id() == elem.id()) {
+                Some(old) => Some(std::mem::replace(old, elem)),
+                None => {
+                    elems.push(elem);
+                    None
+                },
+            },
+        }
+    }
+}
+impl<V> IntoIterator for Bucket<V> {
+    type Item = V;
+    type IntoIter = BucketIntoIter<V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        match self {
+            Self::One(elem) => BucketIntoIter::One(Some(elem)),
+            Self::Many(elems) => BucketIntoIter::Many(elems.into_iter()),
+        }
+    }
+}
+impl<'a, V> IntoIterator for &'a Bucket<V> {
+    type Item = &'a V;
+    type IntoIter = SliceIter<'a, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.as_slice().iter() }
+}
+
+/// Iterates over the elements in a [`Bucket`] by ownership.
+#[derive(Debug)]
+enum BucketIntoIter<V> {
+    /// There is only one element (left).
+    One(Option<V>),
+    /// There are multiple elements.
+    Many(VecIntoIter<V>),
+}
+impl<V> Iterator for BucketIntoIter<V> {
+    type Item = V;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::One(elem) => elem.take(),
+            Self::Many(elems) => elems.next(),
+        }
+    }
+}
+
+
+
+
+
+/***** ITERATORS *****/
+/// Iterates over the elements in a [`LocalSet`] by reference.
+#[derive(Debug)]
+pub struct Iter<'a, V> {
+    /// The buckets we're iterating over.
+    buckets: Values<'a, u64, Bucket<V>>,
+    /// The elements in the current bucket.
+    bucket:  SliceIter<'a, V>,
+    /// The number of elements left to yield.
+    left:    usize,
+}
+impl<'a, V> Clone for Iter<'a, V> {
+    #[inline]
+    fn clone(&self) -> Self { Self { buckets: self.buckets.clone(), bucket: self.bucket.clone(), left: self.left } }
+}
+impl<'a, V> Iterator for Iter<'a, V> {
+    type Item = &'a V;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(elem) = self.bucket.next() {
+                self.left -= 1;
+                return Some(elem);
+            }
+            self.bucket = self.buckets.next()?.as_slice().iter();
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { (self.left, Some(self.left)) }
+}
+impl<'a, V> ExactSizeIterator for Iter<'a, V> {}
+impl<'a, V> FusedIterator for Iter<'a, V> {}
+
+/// Iterates over the elements in a [`LocalSet`] by ownership.
+#[derive(Debug)]
+pub struct IntoIter<V> {
+    /// The buckets we're iterating over.
+    buckets: IntoValues<u64, Bucket<V>>,
+    /// The elements in the current bucket.
+    bucket:  BucketIntoIter<V>,
+    /// The number of elements left to yield.
+    left:    usize,
+}
+impl<V> Iterator for IntoIter<V> {
+    type Item = V;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(elem) = self.bucket.next() {
+                self.left -= 1;
+                return Some(elem);
+            }
+            self.bucket = self.buckets.next()?.into_iter();
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { (self.left, Some(self.left)) }
+}
+impl<V> ExactSizeIterator for IntoIter<V> {}
+impl<V> FusedIterator for IntoIter<V> {}
+
+/// Iterates over the elements in a [`LocalSet`] by ownership while emptying it.
+#[derive(Debug)]
+pub struct Drain<'a, V> {
+    /// The buckets we're iterating over.
+    buckets: HashMapDrain<'a, u64, Bucket<V>>,
+    /// The elements in the current bucket.
+    bucket:  BucketIntoIter<V>,
+    /// The number of elements left to yield.
+    left:    usize,
+}
+impl<'a, V> Iterator for Drain<'a, V> {
+    type Item = V;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(elem) = self.bucket.next() {
+                self.left -= 1;
+                return Some(elem);
+            }
+            self.bucket = self.buckets.next()?.1.into_iter();
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { (self.left, Some(self.left)) }
+}
+impl<'a, V> ExactSizeIterator for Drain<'a, V> {}
+impl<'a, V> FusedIterator for Drain<'a, V> {}
+
+
+
+/// Lazily yields the elements in the union of two [`LocalSet`]s.
+///
+/// Elements are matched by identifier. If both sets have an element with the same identifier, the
+/// one in the left set is yielded.
+#[derive(Debug)]
+pub struct Union<'a, V, S1> {
+    /// The left set, used to skip elements in the right one.
+    lhs:  &'a LocalSet<V, S1>,
+    /// The elements in the left set.
+    left: Iter<'a, V>,
+    /// The elements in the right set.
+    rght: Iter<'a, V>,
+}
+impl<'a, V, S1> Iterator for Union<'a, V, S1>
+where
+    V: Identifiable,
+    S1: BuildHasher,
+{
+    type Item = &'a V;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(elem) = self.left.next() {
+            return Some(elem);
+        }
+        let lhs: &'a LocalSet<V, S1> = self.lhs;
+        self.rght.find(|elem| !lhs.contains(elem.id()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { (self.left.len(), Some(self.left.len() + self.rght.len())) }
+}
+impl<'a, V: Identifiable, S1: BuildHasher> FusedIterator for Union<'a, V, S1> {}
+
+/// Lazily yields the elements of a [`LocalSet`] that are also in another.
+///
+/// Elements are matched by identifier, and always yielded from the left set.
+#[derive(Debug)]
+pub struct Intersection<'a, V, V2, S2> {
+    /// The elements in the left set.
+    left: Iter<'a, V>,
+    /// The right set to check for membership.
+    rhs:  &'a LocalSet<V2, S2>,
+}
+impl<'a, V, V2, S2> Iterator for Intersection<'a, V, V2, S2>
+where
+    V: Identifiable,
+    V2: Identifiable<Id = V::Id>,
+    S2: BuildHasher,
+{
+    type Item = &'a V;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let rhs: &'a LocalSet<V2, S2> = self.rhs;
+        self.left.find(|elem| rhs.contains(elem.id()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { (0, Some(self.left.len().min(self.rhs.len()))) }
+}
+impl<'a, V: Identifiable, V2: Identifiable<Id = V::Id>, S2: BuildHasher> FusedIterator for Intersection<'a, V, V2, S2> {}
+
+/// Lazily yields the elements of a [`LocalSet`] that are _not_ in another.
+///
+/// Elements are matched by identifier.
+#[derive(Debug)]
+pub struct Difference<'a, V, V2, S2> {
+    /// The elements in the left set.
+    left: Iter<'a, V>,
+    /// The right set to check for membership.
+    rhs:  &'a LocalSet<V2, S2>,
+}
+impl<'a, V, V2, S2> Iterator for Difference<'a, V, V2, S2>
+where
+    V: Identifiable,
+    V2: Identifiable<Id = V::Id>,
+    S2: BuildHasher,
+{
+    type Item = &'a V;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let rhs: &'a LocalSet<V2, S2> = self.rhs;
+        self.left.find(|elem| !rhs.contains(elem.id()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { (self.left.len().saturating_sub(self.rhs.len()), Some(self.left.len())) }
+}
+impl<'a, V: Identifiable, V2: Identifiable<Id = V::Id>, S2: BuildHasher> FusedIterator for Difference<'a, V, V2, S2> {}
+
+
+
+
+
 /***** LIBRARY *****/
 // /// Defines an abstract, unordered set of things.
 // ///
@@ -78,14 +511,21 @@ use crate::statements::Message;
 ///
 /// This can be thought of as a regular collection, like a [`HashMap`] or similar.
 ///
+/// Internally, elements are stored by the hash of their identifier, such that we can use a
+/// [`BuildNoHashHasher`] for the map itself. Hash collisions are detected by comparing the actual
+/// identifiers, so two different elements are never confused even if their identifiers hash to the
+/// same value.
+///
 /// # Generics
 /// - `V`: The type of [`Message`]/[`Action`]s stored in this set.
 /// - `S`: Some kind of [`BuildHasher`] that is used to compute randomized hashes. This means that
 ///   hashes are **not** comparable between set instances, only within.
 #[derive(Clone, Debug)]
 pub struct LocalSet<V, S = RandomState> {
-    /// The elements in this set.
-    data:  HashMap<u64, V, BuildNoHashHasher<u64>>,
+    /// The elements in this set, grouped by the hash of their identifiers.
+    data:  HashMap<u64, Bucket<V>, BuildNoHashHasher<u64>>,
+    /// The number of elements in this set (which may be more than the number of buckets).
+    len:   usize,
     /// The random state used to compute hashes.
     state: S,
 }
@@ -100,7 +540,7 @@ impl<V, S: Default> LocalSet<V, S> {
     /// # Returns
     /// An empty set.
     #[inline]
-    pub fn new() -> Self { Self { data: HashMap::with_hasher(BuildNoHashHasher::default()), state: S::default() } }
+    pub fn new() -> Self { Self::with_hasher(S::default()) }
 
     /// Constructor for the Set that gives it an initial capacity.
     ///
@@ -112,9 +552,7 @@ impl<V, S: Default> LocalSet<V, S> {
     /// # Returns
     /// An empty set that can accept at least `capacity` elements before needing to re-allocate.
     #[inline]
-    pub fn with_capacity(capacity: usize) -> Self {
-        Self { data: HashMap::with_capacity_and_hasher(capacity, BuildNoHashHasher::default()), state: S::default() }
-    }
+    pub fn with_capacity(capacity: usize) -> Self { Self::with_capacity_and_hasher(capacity, S::default()) }
 }
 impl<V, S> LocalSet<V, S> {
     /// Constructor for the Set that uses a custom hash builder.
@@ -125,18 +563,48 @@ impl<V, S> LocalSet<V, S> {
     /// # Returns
     /// An empty set that will compute hashes using the given state.
     #[inline]
-    pub fn with_hasher(hash_builder: S) -> Self { Self { data: HashMap::with_hasher(BuildNoHashHasher::default()), state: hash_builder } }
+    pub fn with_hasher(hash_builder: S) -> Self { Self { data: HashMap::with_hasher(BuildNoHashHasher::default()), len: 0, state: hash_builder } }
+
+    /// Constructor for the Set that gives it an initial capacity and uses a custom hash builder.
+    ///
+    /// # Arguments
+    /// - `capacity`: The _minimum_ number of elements the returned set should be able to accept
+    ///   before needing to re-allocate. Due to optimizations, it _may_ have a higher capacity, but
+    ///   never lower.
+    /// - `hash_builder`: The custom hash builder to use to compute hashes with.
+    ///
+    /// # Returns
+    /// An empty set that can accept at least `capacity` elements before needing to re-allocate and
+    /// that will compute hashes using the given state.
+    #[inline]
+    pub fn with_capacity_and_hasher(capacity: usize, hash_builder: S) -> Self {
+        Self { data: HashMap::with_capacity_and_hasher(capacity, BuildNoHashHasher::default()), len: 0, state: hash_builder }
+    }
 }
 // Capacity management
 impl<V, S> LocalSet<V, S> {
     /// Returns the number of elements this set can accept before resizing.
     ///
-    /// Note that this is the _total_ amount of elements. So subtract [`Self::len()`](Set::len()) from this number to find how many are left to go.
+    /// Note that this is the _total_ amount of elements. So subtract [`Self::len()`](LocalSet::len()) from this number to find how many are left to go.
     ///
     /// # Returns
     /// A [`usize`] describing the total capacity of the inner memory block.
     #[inline]
     pub fn capacity(&self) -> usize { self.data.capacity() }
+
+    /// Returns the number of elements in the set.
+    ///
+    /// # Returns
+    /// A [`usize`] describing how many elements are in this set.
+    #[inline]
+    pub fn len(&self) -> usize { self.len }
+
+    /// Checks if there are any elements in the set.
+    ///
+    /// # Returns
+    /// True if there are **none**, or false otherwise.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.len == 0 }
 }
 // Read-only set functions
 impl<V: Identifiable, S: BuildHasher> LocalSet<V, S> {
@@ -149,9 +617,9 @@ impl<V: Identifiable, S: BuildHasher> LocalSet<V, S> {
     /// The referred element if it was known, or else [`None`].
     #[inline]
     pub fn get(&self, id: &V::Id) -> Option<&V> {
-        // Hash the key and use that to access the map
+        // Hash the key and use that to find the bucket; then find the element with the actual ID
         let hash: u64 = self.state.hash_one(id);
-        self.data.get(&hash)
+        self.data.get(&hash).and_then(|bucket| bucket.get(id))
     }
 
     /// Checks if an element with the given identifier exists in the set.
@@ -163,22 +631,82 @@ impl<V: Identifiable, S: BuildHasher> LocalSet<V, S> {
     /// True if the element existed, or false otherwise.
     #[inline]
     pub fn contains(&self, id: &V::Id) -> bool { self.get(id).is_some() }
+}
+// Set algebra
+impl<V: Identifiable, S: BuildHasher> LocalSet<V, S> {
+    /// Returns a lazy view on the union of this set and another.
+    ///
+    /// Elements are matched by identifier. If both sets contain an element with the same
+    /// identifier, only the one in `self` is yielded.
+    ///
+    /// # Arguments
+    /// - `other`: The other set to unify with.
+    ///
+    /// # Returns
+    /// A [`Union`]-iterator that yields references to the elements in either set.
+    #[inline]
+    pub fn union<'a, S2>(&'a self, other: &'a LocalSet<V, S2>) -> Union<'a, V, S> { Union { lhs: self, left: self.iter(), rght: other.iter() } }
 
+    /// Returns a lazy view on the elements in this set that are also in another.
+    ///
+    /// Elements are matched by identifier, so `other` may contain different (but identically
+    /// identified) types of elements, e.g., [`Agreement`](crate::agreements::Agreement)s or
+    /// references.
+    ///
+    /// # Arguments
+    /// - `other`: The other set to intersect with.
+    ///
+    /// # Returns
+    /// An [`Intersection`]-iterator that yields references to the elements in `self` that are
+    /// also in `other`.
+    #[inline]
+    pub fn intersection<'a, V2, S2>(&'a self, other: &'a LocalSet<V2, S2>) -> Intersection<'a, V, V2, S2>
+    where
+        V2: Identifiable<Id = V::Id>,
+        S2: BuildHasher,
+    {
+        Intersection { left: self.iter(), rhs: other }
+    }
 
-
-    /// Returns the number of elements in the set.
+    /// Returns a lazy view on the elements in this set that are _not_ in another.
+    ///
+    /// Elements are matched by identifier, so `other` may contain different (but identically
+    /// identified) types of elements, e.g., [`Agreement`](crate::agreements::Agreement)s or
+    /// references.
+    ///
+    /// # Arguments
+    /// - `other`: The other set of which to exclude the elements.
     ///
     /// # Returns
-    /// A [`usize`] describing how many elements are in this set.
+    /// A [`Difference`]-iterator that yields references to the elements in `self` that are not
+    /// in `other`.
     #[inline]
-    pub fn len(&self) -> usize { self.data.len() }
+    pub fn difference<'a, V2, S2>(&'a self, other: &'a LocalSet<V2, S2>) -> Difference<'a, V, V2, S2>
+    where
+        V2: Identifiable<Id = V::Id>,
+        S2: BuildHasher,
+    {
+        Difference { left: self.iter(), rhs: other }
+    }
 
-    /// Checks if there are any elements in the set.
+    /// Checks whether all elements in this set are also in another.
+    ///
+    /// Elements are matched by identifier.
+    ///
+    /// # Arguments
+    /// - `other`: The other set that should contain all elements in this set.
     ///
     /// # Returns
-    /// True if there are **none**, or false otherwise.
+    /// True if every element in `self` has an identically identified counterpart in `other`, or
+    /// false otherwise.
     #[inline]
-    pub fn is_empty(&self) -> bool { self.len() == 0 }
+    pub fn is_subset<V2, S2>(&self, other: &LocalSet<V2, S2>) -> bool
+    where
+        V2: Identifiable<Id = V::Id>,
+        S2: BuildHasher,
+    {
+        self.len() <= other.len() && self.iter().all(|elem| other.contains(elem.id()))
+    }
 }
 // Mutable set functions
 impl<V: Identifiable, S: BuildHasher> LocalSet<V, S> {
@@ -193,7 +721,17 @@ impl<V: Identifiable, S: BuildHasher> LocalSet<V, S> {
     pub fn add(&mut self, elem: V) -> Option<V> {
         // Hash the identifier, then use that as index
         let hash: u64 = self.state.hash_one(elem.id());
-        self.data.insert(hash, elem)
+        let old: Option<V> = match self.data.entry(hash) {
+            Entry::Occupied(mut bucket) => bucket.get_mut().add(elem),
+            Entry::Vacant(bucket) => {
+                bucket.insert(Bucket::One(elem));
+                None
+            },
+        };
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
     }
 
     /// Adds a whole bunch of elements to the set.
@@ -225,7 +763,70 @@ impl<V: Identifiable, S: BuildHasher> LocalSet<V, S> {
     pub fn remove(&mut self, id: &V::Id) -> Option<V> {
         // Hash the identifier, then use that as index
         let hash: u64 = self.state.hash_one(id);
-        self.data.remove(&hash)
+        let Entry::Occupied(mut bucket) = self.data.entry(hash) else { return None };
+        let elem: V = match bucket.get_mut() {
+            Bucket::One(elem) => {
+                if elem.id() != id {
+                    return None;
+                }
+                let Bucket::One(elem) = bucket.remove() else { unreachable!() };
+                elem
+            },
+            Bucket::Many(elems) => {
+                let pos: usize = elems.iter().position(|elem| elem.id() == id)?;
+                let elem: V = elems.swap_remove(pos);
+                if elems.len() == 1 {
+                    let last: V = elems.pop().unwrap();
+                    *bucket.get_mut() = Bucket::One(last);
+                }
+                elem
+            },
+        };
+        self.len -= 1;
+        Some(elem)
+    }
+
+    /// Only keeps the elements in this set for which the given predicate holds.
+    ///
+    /// # Arguments
+    /// - `pred`: Some closure that returns true for the elements to keep, or false for those to remove.
+    #[inline]
+    pub fn retain(&mut self, mut pred: impl FnMut(&V) -> bool) {
+        let mut removed: usize = 0;
+        self.data.retain(|_, bucket| match bucket {
+            Bucket::One(elem) => {
+                let keep: bool = pred(elem);
+                removed += (!keep) as usize;
+                keep
+            },
+            Bucket::Many(elems) => {
+                let len: usize = elems.len();
+                elems.retain(|elem| pred(elem));
+                removed += len - elems.len();
+                match elems.len() {
+                    0 => false,
+                    1 => {
+                        let elem: V = elems.pop().unwrap();
+                        *bucket = Bucket::One(elem);
+                        true
+                    },
+                    _ => true,
+                }
+            },
+        });
+        self.len -= removed;
+    }
+
+    /// Removes all elements from this set, returning them as an iterator.
+    ///
+    /// Note that the set is emptied even if the iterator is dropped before it is exhausted.
+    ///
+    /// # Returns
+    /// A [`Drain`]-iterator that yields the removed elements by ownership.
+    #[inline]
+    pub fn drain(&mut self) -> Drain<'_, V> {
+        let left: usize = std::mem::take(&mut self.len);
+        Drain { buckets: self.data.drain(), bucket: BucketIntoIter::One(None), left }
     }
 
 
@@ -273,26 +874,26 @@ where
 impl<M, S> LocalSet<M, S> {
     /// Returns an iterator-by-reference for the message set.
     ///
-    /// This returns exactly the same elements as a [`Self::from_iter()`](Set::from_iter())-call, except that it does not consume the set itself.
+    /// This returns exactly the same elements as a [`Self::into_iter()`](LocalSet::into_iter())-call, except that it does not consume the set itself.
     ///
     /// # Returns
     /// An iterator that returns `&'v M` message references.
     #[inline]
-    pub fn iter(&self) -> <&Self as IntoIterator>::IntoIter { self.into_iter() }
+    pub fn iter(&self) -> Iter<'_, M> { self.into_iter() }
 }
 impl<M, S> IntoIterator for LocalSet<M, S> {
     type Item = M;
-    type IntoIter = std::collections::hash_map::IntoValues<u64, M>;
+    type IntoIter = IntoIter<M>;
 
     #[inline]
-    fn into_iter(self) -> Self::IntoIter { self.data.into_values() }
+    fn into_iter(self) -> Self::IntoIter { IntoIter { buckets: self.data.into_values(), bucket: BucketIntoIter::One(None), left: self.len } }
 }
 impl<'a, M, S> IntoIterator for &'a LocalSet<M, S> {
     type Item = &'a M;
-    type IntoIter = std::collections::hash_map::Values<'a, u64, M>;
+    type IntoIter = Iter<'a, M>;
 
     #[inline]
-    fn into_iter(self) -> Self::IntoIter { self.data.values() }
+    fn into_iter(self) -> Self::IntoIter { Iter { buckets: self.data.values(), bucket: [].iter(), left: self.len } }
 }
 // From-impls
 impl<M, S> FromIterator<M> for LocalSet<M, S>
@@ -303,20 +904,10 @@ where
 {
     #[inline]
     fn from_iter<T: IntoIterator<Item = M>>(iter: T) -> Self {
-        // See if we can get a size hint
-        let iter: T::IntoIter = iter.into_iter();
-        let size_hint: (usize, Option<usize>) = iter.size_hint();
-        let size_hint: usize = size_hint.1.unwrap_or(size_hint.0);
-
-        // Populate a set with at least this many elements
-        let mut set: Self = Self { data: HashMap::with_capacity_and_hasher(size_hint, BuildNoHashHasher::default()), state: S::default() };
-        for elem in iter {
-            // Compute the hash of the message
-            let hash: u64 = set.state.hash_one(elem.id());
-            set.data.insert(hash, elem);
-        }
-
-        // OK, that's it
+        // Populate a set with at least as many elements as the iterator tells us
+        let iter = iter.into_iter();
+        let mut set: Self = Self::with_capacity(iter.size_hint().0);
+        set.extend(iter);
         set
     }
 }
@@ -327,7 +918,7 @@ where
     S: Default + BuildHasher,
 {
     #[inline]
-    fn from(value: [M; LEN]) -> Self { Self::from_iter(value.into_iter()) }
+    fn from(value: [M; LEN]) -> Self { Self::from_iter(value) }
 }
 impl<M, S> From<Vec<M>> for LocalSet<M, S>
 where
@@ -336,5 +927,22 @@ where
     S: Default + BuildHasher,
 {
     #[inline]
-    fn from(value: Vec<M>) -> Self { Self::from_iter(value.into_iter()) }
+    fn from(value: Vec<M>) -> Self { Self::from_iter(value) }
+}
+// Serde-impls
+#[cfg(feature = "serde")]
+impl<M: serde::Serialize, S> serde::Serialize for LocalSet<M, S> {
+    /// Serializes the set as a sequence of its elements.
+    #[inline]
+    fn serialize<SE: serde::Serializer>(&self, serializer: SE) -> Result<SE::Ok, SE::Error> { serializer.collect_seq(self.iter()) }
+}
+#[cfg(feature = "serde")]
+impl<'de, M, S> serde::Deserialize<'de> for LocalSet<M, S>
+where
+    M: serde::Deserialize<'de> + Identifiable,
+    S: Default + BuildHasher,
+{
+    /// Deserializes the set from a sequence of its elements.
+    #[inline]
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> { Vec::<M>::deserialize(deserializer).map(Self::from) }
 }
@@ -4,7 +4,7 @@
 //  Created:
 //    21 May 2024, 16:48:17
 //  Last edited:
-//    29 May 2024, 13:55:26
+//    16 Oct 2026, 22:11:46
 //  Auto updated?
 //    Yes
 //
@@ -12,11 +12,102 @@
 //!   Implements the globally synchronized set of stated messages.
 //
 
+use std::iter::FusedIterator;
+
 use crate::agreements::{Agreement, Agreements};
+use crate::auditors::{ActionAuditExplanation, ActionAuditReport, AuditContext, Auditor, Paper};
 use crate::auxillary::{Authored, Identifiable};
-use crate::policy::{Extractor, Policy};
-use crate::set::LocalSet;
-use crate::times::Timestamp;
+use crate::policy::Extractor;
+use crate::set::{Iter, LocalSet};
+use crate::times::{TimeRange, Timestamp};
+
+
+/***** TESTS *****/
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{scenario, MockExtractor, MockMessage};
+
+
+    #[test]
+    fn test_action_audit_included() {
+        // The well-behaved action should succeed
+        let (stmts, agrs, act) = scenario();
+        assert!(matches!(act.audit::<MockExtractor, _, _>(&stmts, &agrs), Ok(())));
+
+        // Enacting something with the same identifier as the basis shadows the basis
+        let (mut stmts, agrs, mut act) = scenario();
+        act.enacts = MockMessage::new("s1", "amy", "accesses x_rays");
+        stmts.stmts.add(act.enacts.clone());
+        assert!(matches!(act.audit::<MockExtractor, _, _>(&stmts, &agrs), Err(AuditExplanation::Included { stmt: "s1" })));
+
+        // Justifying with another version of the enactment is also not allowed
+        let (stmts, agrs, mut act) = scenario();
+        act.just.add(MockMessage::new("s3", "amy", "error"));
+        assert!(matches!(act.audit::<MockExtractor, _, _>(&stmts, &agrs), Err(AuditExplanation::Included { stmt: "s3" })));
+
+        // But justifying with the same version is fine
+        let (stmts, agrs, mut act) = scenario();
+        act.just.add(act.enacts.clone());
+        assert!(matches!(act.audit::<MockExtractor, _, _>(&stmts, &agrs), Ok(())));
+    }
+
+    #[test]
+    fn test_action_justification() {
+        let (_, _, mut act) = scenario();
+        let ids = |just: Justification<MockMessage>| -> Vec<String> {
+            let mut ids: Vec<String> = just.iter().map(|msg| msg.id.clone()).collect();
+            ids.sort_unstable();
+            ids
+        };
+        assert_eq!(ids(act.justification()), vec!["s1", "s2", "s3"]);
+        assert_eq!(act.justification().len(), 3);
+
+        // Other versions of the basis and the enactment are shadowed by them
+        act.just.add(MockMessage::new("s1", "amy", "other rules"));
+        act.just.add(MockMessage::new("s3", "amy", "error"));
+        let just: Justification<MockMessage> = act.justification();
+        assert_eq!(ids(just), vec!["s1", "s2", "s3"]);
+        assert_eq!(just.len(), 3);
+        assert!(std::ptr::eq(just.get("s1").unwrap(), &act.basis.msg));
+        assert!(std::ptr::eq(just.get("s3").unwrap(), &act.enacts));
+
+        // The enactment shadows the basis
+        act.enacts = MockMessage::new("s1", "amy", "accesses x_rays");
+        assert_eq!(ids(act.justification()), vec!["s1", "s2", "s3"]);
+        assert_eq!(act.justification().len(), 3);
+        assert!(std::ptr::eq(act.justification().get("s1").unwrap(), &act.enacts));
+    }
+
+    #[test]
+    fn test_action_audit_report() {
+        // The well-behaved action should have an empty report
+        let (stmts, agrs, act) = scenario();
+        assert!(act.audit_report::<MockExtractor, _, _>(&stmts, &agrs).is_ok());
+
+        // Now break about everything at once
+        let (stmts, agrs, mut act) = scenario();
+        act.just.add(MockMessage::new("s4", "anton", "error"));
+        act.just.add(MockMessage::new("s5", "anton", "authorises anton"));
+        act.timestamp = Timestamp(2);
+        let report = act.audit_report::<MockExtractor, _, _>(&stmts, &agrs);
+        let mut unstated: Vec<&str> = report
+            .iter()
+            .filter_map(|expl| if let AuditExplanation::Stated { stmt } = expl { Some(*stmt) } else { None })
+            .collect();
+        unstated.sort();
+        assert_eq!(unstated, vec!["s4", "s5"]);
+        assert_eq!(report.violations.len(), 4);
+        assert!(matches!(report.violations[2], AuditExplanation::Valid { .. }));
+        assert!(matches!(report.violations[3], AuditExplanation::Timely { stmt: "s1", applies, taken_at: Timestamp(2) } if applies == TimeRange::at(Timestamp(1))));
+
+        // The fail-fast version only returns the first
+        assert!(matches!(act.audit::<MockExtractor, _, _>(&stmts, &agrs), Err(AuditExplanation::Stated { .. })));
+    }
+}
+
+
+
 
 
 /***** AUXILLARY *****/
@@ -26,10 +117,18 @@ use crate::times::Timestamp;
 /// - `ID`: The identifier used by messages.
 /// - `SYN`: The [`Extractable::SyntaxError`] of the policy language that was potentially erronously extracted.
 /// - `SEM`: The [`Policy::SemanticError`] of the policy language that was potentially invalid.
+/// - `AUTH`: The identifier used by the authors of messages. Defaults to `ID`.
 #[derive(Debug)]
-pub enum AuditExplanation<ID, SYN, SEM> {
+pub enum AuditExplanation<ID, SYN, SEM, AUTH = ID> {
     /// One of the messages in the action was not stated (property 3).
     Stated { stmt: ID },
+    /// Different versions of one of the messages in the action were stated, or the action used a
+    /// version that wasn't stated (property 3).
+    ///
+    /// The authors of every version are listed, starting with the one used in the action.
+    Equivocated { stmt: ID, authors: Vec<AUTH> },
+    /// The basis or the enactment was not included as-is in the justification, because another message with the same identifier shadowed it (property 4).
+    Included { stmt: ID },
     /// Failed to extract the policy from the justification (property 5).
     Extract { err: SYN },
     /// The policy was not valid (property 5).
@@ -37,11 +136,210 @@ pub enum AuditExplanation<ID, SYN, SEM> {
     /// The basis was not an agreement (property 6).
     Based { stmt: ID },
     /// The basis was an agreement but not one for the action's taken time (property 6).
-    Timely { stmt: ID, applies_at: Timestamp, taken_at: Timestamp },
+    Timely { stmt: ID, applies: TimeRange, taken_at: Timestamp },
+    /// A custom property, checked by some user-defined [`Auditor`](crate::auditors::Auditor), did not hold.
+    Custom { property: &'static str, stmts: Vec<ID>, reason: String },
+}
+
+/// Lists all the reasons why an audit of an [`Action`] in a [`Statements`] failed.
+///
+/// # Generics
+/// - `ID`: The identifier used by messages.
+/// - `SYN`: The [`Extractor::SyntaxError`] of the policy language that was potentially erronously extracted.
+/// - `SEM`: The [`Policy::SemanticError`] of the policy language that was potentially invalid.
+/// - `AUTH`: The identifier used by the authors of messages. Defaults to `ID`.
+#[derive(Debug)]
+pub struct AuditReport<ID, SYN, SEM, AUTH = ID> {
+    /// The properties that did not hold, in the order they were checked.
+    pub violations: Vec<AuditExplanation<ID, SYN, SEM, AUTH>>,
+}
+impl<ID, SYN, SEM, AUTH> Default for AuditReport<ID, SYN, SEM, AUTH> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+impl<ID, SYN, SEM, AUTH> AuditReport<ID, SYN, SEM, AUTH> {
+    /// Constructor for the AuditReport that initializes it without any violations.
+    ///
+    /// # Returns
+    /// A new AuditReport that reports a successful audit.
+    #[inline]
+    pub fn new() -> Self { Self { violations: Vec::new() } }
+
+    /// Checks whether the audit succeeded.
+    ///
+    /// # Returns
+    /// True if no property was violated, or false otherwise.
+    #[inline]
+    pub fn is_ok(&self) -> bool { self.violations.is_empty() }
+
+    /// Returns an iterator over the violated properties.
+    ///
+    /// # Returns
+    /// An iterator yielding references to the [`AuditExplanation`]s in this report.
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, AuditExplanation<ID, SYN, SEM, AUTH>> { self.violations.iter() }
+
+    /// Turns this report into a fail-fast result.
+    ///
+    /// # Errors
+    /// If any property was violated, returns the first one that was found.
+    #[inline]
+    pub fn into_result(self) -> Result<(), AuditExplanation<ID, SYN, SEM, AUTH>> {
+        match self.violations.into_iter().next() {
+            Some(expl) => Err(expl),
+            None => Ok(()),
+        }
+    }
+}
+impl<ID, SYN, SEM, AUTH> IntoIterator for AuditReport<ID, SYN, SEM, AUTH> {
+    type Item = AuditExplanation<ID, SYN, SEM, AUTH>;
+    type IntoIter = std::vec::IntoIter<AuditExplanation<ID, SYN, SEM, AUTH>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.violations.into_iter() }
+}
+impl<'a, ID, SYN, SEM, AUTH> IntoIterator for &'a AuditReport<ID, SYN, SEM, AUTH> {
+    type Item = &'a AuditExplanation<ID, SYN, SEM, AUTH>;
+    type IntoIter = std::slice::Iter<'a, AuditExplanation<ID, SYN, SEM, AUTH>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.violations.iter() }
 }
 
 
 
+/// A lazy view on the full justification of an [`Action`], i.e., its `just` together with its
+/// `basis` and `enacts`.
+///
+/// Built by [`Action::justification()`]. Messages in `just` with the same identifier as the basis
+/// or the enactment are shadowed by those, as if they were added to `just`. Likewise, the
+/// enactment shadows the basis if they have the same identifier.
+///
+/// # Generics
+/// - `'a`: The lifetime of the [`Action`].
+/// - `M`: The type of [`Message`]s in the action.
+#[derive(Debug)]
+pub struct Justification<'a, M> {
+    /// The messages given as justification.
+    just:   &'a LocalSet<M>,
+    /// The message of the basis.
+    basis:  &'a M,
+    /// The message enacted.
+    enacts: &'a M,
+}
+impl<'a, M> Clone for Justification<'a, M> {
+    #[inline]
+    fn clone(&self) -> Self { *self }
+}
+impl<'a, M> Copy for Justification<'a, M> {}
+impl<'a, M: Identifiable> Justification<'a, M> {
+    /// Retrieves the message with the given identifier from the justification.
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the message to retrieve.
+    ///
+    /// # Returns
+    /// The referred message if it is in the justification, or else [`None`].
+    #[inline]
+    pub fn get(&self, id: &M::Id) -> Option<&'a M> {
+        if self.enacts.id() == id {
+            Some(self.enacts)
+        } else if self.basis.id() == id {
+            Some(self.basis)
+        } else {
+            self.just.get(id)
+        }
+    }
+
+    /// Checks whether a message with the given identifier is in the justification.
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the message to check.
+    ///
+    /// # Returns
+    /// True if it is, or false otherwise.
+    #[inline]
+    pub fn contains(&self, id: &M::Id) -> bool { self.get(id).is_some() }
+
+    /// Returns the number of messages in the justification.
+    ///
+    /// # Returns
+    /// The number of messages in `just`, plus the basis and the enactment if they are not in there.
+    #[inline]
+    pub fn len(&self) -> usize {
+        let mut len: usize = self.just.len() + !self.just.contains(self.enacts.id()) as usize;
+        if self.basis.id() != self.enacts.id() && !self.just.contains(self.basis.id()) {
+            len += 1;
+        }
+        len
+    }
+
+    /// Checks whether the justification is empty, which it never is.
+    ///
+    /// # Returns
+    /// False, as the justification always contains at least the enactment.
+    #[inline]
+    pub fn is_empty(&self) -> bool { false }
+
+    /// Returns an iterator over the messages in the justification.
+    ///
+    /// # Returns
+    /// A [`JustificationIter`] that yields the enactment, the basis and the rest of `just`, in that
+    /// order.
+    #[inline]
+    pub fn iter(&self) -> JustificationIter<'a, M> {
+        JustificationIter { enacts: Some(self.enacts), basis: Some(self.basis), just: self.just.iter(), shadows: [self.enacts, self.basis] }
+    }
+}
+impl<'a, M: Identifiable> IntoIterator for Justification<'a, M> {
+    type Item = &'a M;
+    type IntoIter = JustificationIter<'a, M>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+/// Iterates over the messages in a [`Justification`].
+#[derive(Debug)]
+pub struct JustificationIter<'a, M> {
+    /// The enactment, if it wasn't yielded yet.
+    enacts:  Option<&'a M>,
+    /// The basis, if it wasn't yielded yet.
+    basis:   Option<&'a M>,
+    /// The messages given as justification.
+    just:    Iter<'a, M>,
+    /// The messages that shadow the ones in `just` with the same identifier.
+    shadows: [&'a M; 2],
+}
+impl<'a, M: Identifiable> Iterator for JustificationIter<'a, M> {
+    type Item = &'a M;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(enacts) = self.enacts.take() {
+            return Some(enacts);
+        }
+        if let Some(basis) = self.basis.take() {
+            // The enactment shadows the basis too
+            if basis.id() != self.shadows[0].id() {
+                return Some(basis);
+            }
+        }
+        let shadows: [&'a M; 2] = self.shadows;
+        self.just.find(|msg| shadows.iter().all(|shadow| shadow.id() != msg.id()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Only the enactment is never shadowed
+        let extra: usize = self.enacts.is_some() as usize + self.basis.is_some() as usize;
+        (self.enacts.is_some() as usize + self.just.len().saturating_sub(2), Some(extra + self.just.len()))
+    }
+}
+impl<'a, M: Identifiable> FusedIterator for JustificationIter<'a, M> {}
+
+
+
 
 
 /***** LIBRARY *****/
@@ -73,6 +371,18 @@ pub trait Message<'v>: Authored + Identifiable {
     /// # Returns
     /// A byte slice ([`&[u8]`](u8)) that represents the message's full payload.
     fn payload(&self) -> &'v [u8];
+
+    /// Returns the language in which the message's payload is written, if it is tagged.
+    ///
+    /// This is used by, e.g., the [`MultiExtractor`](crate::policy::MultiExtractor) to decide how
+    /// to interpret the payload.
+    ///
+    /// By default, messages are untagged.
+    ///
+    /// # Returns
+    /// The language tag of the message, or [`None`] if it has none.
+    #[inline]
+    fn lang(&self) -> Option<&'v str> { None }
 }
 
 /// Implements a representation of actions in the framework.
@@ -84,6 +394,8 @@ pub trait Message<'v>: Authored + Identifiable {
 /// - `M`: The concrete type of [`Message`]s stored in the action.
 /// - `T`: The concrete type of the [`Time`]stamp stored in the action and its nested basis.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "M: serde::Deserialize<'de> + Identifiable")))]
 pub struct Action<M> {
     /// The basis, i.e., agreement upon which the action relies.
     pub basis:     Agreement<M>,
@@ -128,26 +440,16 @@ impl<M> Action<M> {
     //     AgreementFormatter { msg: self, prefix, indent }
     // }
 }
-impl<'a, M> Action<M>
-where
-    &'a M: 'a + Message<'a>,
-{
+impl<M: Identifiable> Action<M> {
     /// Returns the justification of the action.
     ///
     /// Note that, contrary to accessing `just` manually, this includes both the `basis` _and_ the `enacts`.
     ///
     /// # Returns
-    /// A [`Set`] of messages that form the entire justification, including its basis and effects.
-    pub fn justification(&'a self) -> LocalSet<&'a M> {
-        // Get the justification first
-        let mut just: LocalSet<&'a M> = self.just.iter().collect();
-        // Include the agreement
-        just.add(&self.basis.msg);
-        // Include the enactment
-        just.add(&self.enacts);
-        // Done
-        just
-    }
+    /// A lazy [`Justification`] view on the messages that form the entire justification,
+    /// including its basis and effects.
+    #[inline]
+    pub fn justification(&self) -> Justification<'_, M> { Justification { just: &self.just, basis: &self.basis.msg, enacts: &self.enacts } }
 }
 impl<'a, M> Action<M>
 where
@@ -157,76 +459,104 @@ where
     /// Audits this action, checking whether it satisfies the well-behaved properties specified in
     /// the paper.
     ///
-    /// Specifically, it checks:
+    /// This is a fail-fast version of [`Action::audit_report()`], i.e., it only returns the first
+    /// property that does not hold. See that function for the properties checked.
     ///
     /// # Generics
-    /// - `P`: The [`Policy`] language that is used to verify the messages' payload's validity.
+    /// - `E`: The [`Extractor`] of the [`Policy`] language that is used to verify the messages' payload's validity.
     /// - `S`: The type of `stmts` given to check which messages are stated by agents.
-    /// - `A`: The type of `agrmtns` given to check which agreements are actually agreed upon.
+    /// - `A`: The type of `agrs` given to check which agreements are actually agreed upon.
     ///
     /// # Arguments
     /// - `stmts`: The set of [`Statements`] to which the auditing entity has access.
+    /// - `agrs`: The set of [`Agreements`] to which the auditing entity has access.
     ///
     /// # Errors
     /// This function errors if one of the properties does not hold. The returned
     /// [`AuditExplanation`] encodes specifically which one did not.
+    #[inline]
     pub fn audit<'v: 'a, E, S, A>(
         &'a self,
         stmts: &'v S,
         agrs: &'v A,
-    ) -> Result<(), AuditExplanation<&'a <&'a M as Identifiable>::Id, E::SyntaxError<'a>, <E::Policy<'a> as Policy>::SemanticError>>
+    ) -> Result<(), ActionAuditExplanation<'a, M, E>>
     where
         E: Extractor<&'a M>,
         S: Statements<Message = M>,
         A: Agreements<Message = M>,
     {
-        let just: LocalSet<&'a M> = self.justification();
-
-        /* Property 3 */
-        // Checks if the policy is stated correctly.
-        for stmt in &just {
-            if !agrs.agreed().contains((*stmt).id()) && !stmts.stated().contains(stmt.id()) {
-                return Err(AuditExplanation::Stated { stmt: stmt.id_v() });
-            }
-        }
-
-
-
-        /* Property 4 */
-        // Checks if the basis and enactment are included in the justification
-        // Trivial due to [`Action::justification()`]
-
+        self.audit_report::<E, S, A>(stmts, agrs).into_result()
+    }
 
+    /// Audits this action, checking whether it satisfies the well-behaved properties specified in
+    /// the paper.
+    ///
+    /// Specifically, it uses the [`Paper`] auditor to check whether:
+    /// - all messages in the justification are stated or agreed upon (property 3), and they are
+    ///   not equivocated (i.e., no other versions of them are stated);
+    /// - the basis and enactment are included in the justification (property 4);
+    /// - the policy in the justification can be extracted and is valid (property 5); and
+    /// - the basis is an agreement that applies at the time the action was taken (property 6).
+    ///
+    /// Contrary to [`Action::audit()`], this function checks all properties, even if some already
+    /// failed. Only validity is not checked if the policy could not be extracted.
+    ///
+    /// # Generics
+    /// - `E`: The [`Extractor`] of the [`Policy`] language that is used to verify the messages' payload's validity.
+    /// - `S`: The type of `stmts` given to check which messages are stated by agents.
+    /// - `A`: The type of `agrs` given to check which agreements are actually agreed upon.
+    ///
+    /// # Arguments
+    /// - `stmts`: The set of [`Statements`] to which the auditing entity has access.
+    /// - `agrs`: The set of [`Agreements`] to which the auditing entity has access.
+    ///
+    /// # Returns
+    /// An [`AuditReport`] that lists every property that did not hold, together with the messages involved.
+    pub fn audit_report<'v: 'a, E, S, A>(
+        &'a self,
+        stmts: &'v S,
+        agrs: &'v A,
+    ) -> ActionAuditReport<'a, M, E>
+    where
+        E: Extractor<&'a M>,
+        S: Statements<Message = M>,
+        A: Agreements<Message = M>,
+    {
+        self.audit_with::<E, S, A, _>(&Paper, stmts, agrs)
+    }
 
-        /* Property 5 */
-        // Attempt to extract the policy
-        let policy: E::Policy<'a> = match just.extract::<E>() {
-            Ok(policy) => policy,
-            Err(err) => return Err(AuditExplanation::Extract { err }),
+    /// Audits this action using a custom [`Auditor`], checking whether it satisfies the properties it represents.
+    ///
+    /// # Generics
+    /// - `E`: The [`Extractor`] of the [`Policy`] language that is used to verify the messages' payload's validity.
+    /// - `S`: The type of `stmts` given to check which messages are stated by agents.
+    /// - `A`: The type of `agrs` given to check which agreements are actually agreed upon.
+    /// - `U`: The type of [`Auditor`] that checks the properties.
+    ///
+    /// # Arguments
+    /// - `auditor`: The [`Auditor`] (stack) that checks the properties.
+    /// - `stmts`: The set of [`Statements`] to which the auditing entity has access.
+    /// - `agrs`: The set of [`Agreements`] to which the auditing entity has access.
+    ///
+    /// # Returns
+    /// An [`AuditReport`] that lists every property that did not hold, together with the messages involved.
+    pub fn audit_with<'v: 'a, E, S, A, U>(&'a self, auditor: &U, stmts: &'v S, agrs: &'v A) -> ActionAuditReport<'a, M, E>
+    where
+        E: Extractor<&'a M>,
+        S: Statements<Message = M>,
+        A: Agreements<Message = M>,
+        U: Auditor<M>,
+    {
+        let ctx: AuditContext<'a, M> = AuditContext {
+            act: self,
+            just: self.justification(),
+            stated: stmts.stated(),
+            equivocated: stmts.equivocated(),
+            agreed: agrs.agreed(),
         };
-
-        // Check if the policy is valid
-        if let Err(expl) = policy.assert_validity() {
-            return Err(AuditExplanation::Valid { expl });
-        }
-
-
-
-        /* Property 6 */
-        // Assert that the basis is an agreement
-        if !agrs.agreed().contains(self.basis.id()) {
-            return Err(AuditExplanation::Based { stmt: (&self.basis.msg).id_v() });
-        }
-
-        // Assert the agreement's time matches the action's
-        if self.basis.applies_at() != self.timestamp {
-            return Err(AuditExplanation::Timely { stmt: (&self.basis.msg).id_v(), applies_at: self.basis.timestamp, taken_at: self.timestamp });
-        }
-
-
-
-        /* Success */
-        Ok(())
+        let mut report = AuditReport::new();
+        auditor.audit::<E>(&ctx, &mut report);
+        report
     }
 }
 
@@ -282,6 +612,18 @@ pub trait Statements {
     /// A [`Set`] that contains all the messages in this statements.
     fn stated<'s>(&'s self) -> LocalSet<&'s Self::Message>;
 
+    /// Returns all versions of messages that were stated under the same identifier as another,
+    /// different message.
+    ///
+    /// By default, this returns nothing, which is correct for implementations that never accept
+    /// two different messages under the same identifier.
+    ///
+    /// # Returns
+    /// A list of all the conflicting messages known to this Statements, including the ones in
+    /// [`Statements::stated()`].
+    #[inline]
+    fn equivocated(&self) -> Vec<&Self::Message> { Vec::new() }
+
 
 
     /// Enacts a new statement with a justification for it.
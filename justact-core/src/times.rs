@@ -4,7 +4,7 @@
 //  Created:
 //    21 May 2024, 16:34:11
 //  Last edited:
-//    23 May 2024, 11:54:50
+//    16 Oct 2026, 22:21:20
 //  Auto updated?
 //    Yes
 //
@@ -17,17 +17,109 @@ use std::error::Error;
 use std::fmt::{Display, Formatter, Result as FResult};
 
 
+/***** TESTS *****/
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+
+    #[test]
+    fn test_time_range_contains() {
+        assert!(TimeRange::at(Timestamp(1)).contains(Timestamp(1)));
+        assert!(!TimeRange::at(Timestamp(1)).contains(Timestamp(0)));
+        assert!(!TimeRange::at(Timestamp(1)).contains(Timestamp(2)));
+        assert!(TimeRange::at(Timestamp(u128::MAX)).contains(Timestamp(u128::MAX)));
+        assert!(!TimeRange::at(Timestamp(u128::MAX)).contains(Timestamp(u128::MAX - 1)));
+
+        assert!(TimeRange::between(Timestamp(1), Timestamp(3)).contains(Timestamp(2)));
+        assert!(!TimeRange::between(Timestamp(1), Timestamp(3)).contains(Timestamp(3)));
+        assert!(!TimeRange::between(Timestamp(1), Timestamp(1)).contains(Timestamp(1)));
+
+        assert!(TimeRange::starting(Timestamp(1)).contains(Timestamp(u128::MAX)));
+        assert!(!TimeRange::starting(Timestamp(1)).contains(Timestamp(0)));
+    }
+}
+
+
+
+
+
 /***** LIBRARY *****/
 /// Defines what it means for something to be a timestamp.
 ///
 /// This implementation is provided, as we expect it to be the same across implementations.
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Timestamp(pub u128);
 impl Display for Timestamp {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> FResult { write!(f, "{}", self.0) }
 }
 
+/// Defines a range of [`Timestamp`]s, which may not have an end.
+///
+/// The start of the range is inclusive, its end exclusive.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TimeRange {
+    /// The first timestamp in the range.
+    pub from:  Timestamp,
+    /// The first timestamp _after_ the range, or [`None`] if the range never ends.
+    pub until: Option<Timestamp>,
+}
+impl TimeRange {
+    /// Constructor for a TimeRange that contains only a single timestamp.
+    ///
+    /// # Arguments
+    /// - `time`: The only [`Timestamp`] in the range.
+    ///
+    /// # Returns
+    /// A new TimeRange containing exactly `time`. For the last possible timestamp, it never ends,
+    /// since there is no timestamp after it.
+    #[inline]
+    pub fn at(time: Timestamp) -> Self { Self { from: time, until: time.0.checked_add(1).map(Timestamp) } }
+
+    /// Constructor for a TimeRange that never ends.
+    ///
+    /// # Arguments
+    /// - `from`: The first [`Timestamp`] in the range.
+    ///
+    /// # Returns
+    /// A new TimeRange containing `from` and everything after it.
+    #[inline]
+    pub fn starting(from: Timestamp) -> Self { Self { from, until: None } }
+
+    /// Constructor for a TimeRange between two timestamps.
+    ///
+    /// # Arguments
+    /// - `from`: The first [`Timestamp`] in the range.
+    /// - `until`: The first [`Timestamp`] _after_ the range.
+    ///
+    /// # Returns
+    /// A new TimeRange containing `from` up to (but not including) `until`.
+    #[inline]
+    pub fn between(from: Timestamp, until: Timestamp) -> Self { Self { from, until: Some(until) } }
+
+    /// Checks whether the given timestamp is in this range.
+    ///
+    /// # Arguments
+    /// - `time`: The [`Timestamp`] to check.
+    ///
+    /// # Returns
+    /// True if `time` is at or after the start of this range and before its end (if any), or false otherwise.
+    #[inline]
+    pub fn contains(&self, time: Timestamp) -> bool { self.from <= time && self.until.map(|until| time < until).unwrap_or(true) }
+}
+impl Display for TimeRange {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> FResult {
+        match self.until {
+            Some(until) => write!(f, "[{}, {})", self.from, until),
+            None => write!(f, "[{}, ...)", self.from),
+        }
+    }
+}
+
 
 
 /// Implements an abstract set of timestamps, including information about the current one.
@@ -60,4 +152,12 @@ pub trait Times {
     /// However, one should assume that _if_ this function fails, the current time has not
     /// advanced.
     fn advance_to(&mut self, timestamp: Timestamp) -> Result<(), Self::Error>;
+
+    /// Returns the timestamps that are proposed to advance to but have not been agreed upon yet.
+    ///
+    /// # Returns
+    /// The proposed [`Timestamp`]s, in ascending order. It is empty for implementations that do
+    /// not advance the time by voting.
+    #[inline]
+    fn proposed(&self) -> Vec<Timestamp> { Vec::new() }
 }
@@ -0,0 +1,128 @@
+//  SCALING.rs
+//    by Lut99
+//
+//  Created:
+//    16 Oct 2026, 19:44:21
+//  Last edited:
+//    16 Oct 2026, 22:13:37
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Benchmarks how the [`Simulation`] scales with the number of agents.
+//
+
+use std::convert::Infallible;
+
+use console::Style;
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput};
+use justact_core::agents::{Agent, AgentPoll, RationalAgent};
+use justact_core::agreements::Agreements;
+use justact_core::auxillary::Identifiable;
+use justact_core::statements::Statements;
+use justact_core::times::Times;
+use justact_prototype::statements::{Message, Target};
+use justact_prototype::Simulation;
+
+
+/***** CONSTANTS *****/
+/// The numbers of agents to benchmark with.
+const SIZES: [usize; 5] = [16, 64, 256, 1024, 4096];
+
+
+
+
+
+/***** AGENTS *****/
+/// An agent that states a message to everybody once, and then one to its neighbour every round.
+#[derive(Debug)]
+struct Chatter {
+    /// The identifier of this agent.
+    id:    String,
+    /// The identifier of the agent to send messages to.
+    next:  String,
+    /// The number of times this agent has been polled.
+    round: usize,
+}
+impl Identifiable for Chatter {
+    type Id = str;
+
+    #[inline]
+    fn id(&self) -> &Self::Id { &self.id }
+}
+impl Agent for Chatter {}
+impl RationalAgent for Chatter {
+    type Message = Message;
+    type Target = Target;
+    type Error = Infallible;
+
+    fn poll(
+        &mut self,
+        _agrs: impl Agreements<Message = Self::Message>,
+        _times: impl Times,
+        mut stmts: impl Statements<Message = Self::Message, Target = Self::Target>,
+    ) -> Result<AgentPoll<Self, Self::Message>, Self::Error> {
+        // Say something
+        let target: Target = if self.round == 0 { Target::All } else { Target::Agent(self.next.clone()) };
+        let msg: Message = Message { id: format!("{}-{}", self.id, self.round), author: self.id.clone(), payload: Vec::new(), lang: None };
+        black_box(stmts.state(target, msg));
+        self.round += 1;
+        Ok(AgentPoll::Alive)
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Builds a quiet simulation with the given number of [`Chatter`]s that has run one round.
+///
+/// # Arguments
+/// - `n`: The number of agents to add.
+///
+/// # Returns
+/// A new [`Simulation`] in which every agent has stated a message to everybody.
+fn simulation(n: usize) -> Simulation<Chatter> {
+    let mut sim: Simulation<Chatter> = Simulation::with_capacity("agent0", n);
+    sim.set_quiet(true);
+    for i in 0..n {
+        sim.register(Chatter { id: format!("agent{i}"), next: format!("agent{}", (i + 1) % n), round: 0 }, Style::new());
+    }
+    sim.poll().unwrap();
+    sim
+}
+
+
+
+
+
+/***** BENCHMARKS *****/
+/// Measures a single round of polling all agents.
+fn poll(c: &mut Criterion) {
+    let mut group = c.benchmark_group("poll");
+    for n in SIZES {
+        group.throughput(Throughput::Elements(n as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(n), &n, |b, n| {
+            b.iter_batched(|| simulation(*n), |mut sim| black_box(sim.poll().unwrap()), BatchSize::LargeInput)
+        });
+    }
+    group.finish();
+}
+
+/// Measures a single round of polling all agents on multiple threads.
+fn poll_parallel(c: &mut Criterion) {
+    let mut group = c.benchmark_group("poll_parallel");
+    for n in SIZES {
+        group.throughput(Throughput::Elements(n as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(n), &n, |b, n| {
+            b.iter_batched(|| simulation(*n), |mut sim| black_box(sim.poll_parallel().unwrap()), BatchSize::LargeInput)
+        });
+    }
+    group.finish();
+}
+
+
+
+criterion_group!(benches, poll, poll_parallel);
+criterion_main!(benches);
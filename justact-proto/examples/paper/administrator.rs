@@ -4,7 +4,7 @@
 //  Created:
 //    17 May 2024, 14:23:42
 //  Last edited:
-//    27 May 2024, 17:51:10
+//    16 Oct 2026, 19:38:18
 //  Auto updated?
 //    Yes
 //
@@ -44,15 +44,19 @@ impl RationalAgent for Administrator {
         agrmnts: impl Agreements<Message = Self::Message>,
         _times: impl Times,
         mut stmts: impl Statements<Message = Self::Message, Target = Self::Target>,
-    ) -> Result<AgentPoll, Self::Error> {
+    ) -> Result<AgentPoll<Self, Self::Message>, Self::Error> {
         // The administrator emits 's2' after the agreement has een emitted
         if agrmnts.agreed().contains("s1") {
             // Define the policy to emit
             let spec: Spec = datalog! {
                 ctl_authorises(administrator, amy, x_rays).
             };
-            let msg: Message =
-                Message { id: "s2".into(), author: "administrator".into(), payload: spec.reserialize().to_string().into_bytes() };
+            let msg: Message = Message {
+                id:      "s2".into(),
+                author:  "administrator".into(),
+                payload: spec.reserialize().to_string().into_bytes(),
+                lang:    Some("datalog".into()),
+            };
 
             // Emit it
             stmts.state(Target::All, msg);
@@ -4,7 +4,7 @@
 //  Created:
 //    27 May 2024, 17:53:21
 //  Last edited:
-//    27 May 2024, 17:58:27
+//    16 Oct 2026, 19:38:18
 //  Auto updated?
 //    Yes
 //
@@ -15,9 +15,11 @@
 use std::convert::Infallible;
 
 use datalog::ast::{datalog, Reserializable, Spec};
+use datalog::justact::SpecExtractor;
 use justact_core::agents::{Agent, AgentPoll, RationalAgent};
-use justact_core::agreements::Agreements;
+use justact_core::agreements::{Agreement, Agreements};
 use justact_core::auxillary::Identifiable;
+use justact_core::search::find_justification;
 use justact_core::set::LocalSet;
 use justact_core::statements::{Action, Statements};
 use justact_core::times::Times;
@@ -45,7 +47,7 @@ impl RationalAgent for Amy {
         agrs: impl Agreements<Message = Self::Message>,
         times: impl Times,
         mut stmts: impl Statements<Message = Self::Message, Target = Self::Target>,
-    ) -> Result<AgentPoll, Self::Error> {
+    ) -> Result<AgentPoll<Self, Self::Message>, Self::Error> {
         // The amy emits 's3' (an enacted action) after she received authorisation from the amy
         if stmts.stated().contains("s2") {
             // Amy first emits her intended enactment
@@ -54,7 +56,12 @@ impl RationalAgent for Amy {
                 let spec: Spec = datalog! {
                     ctl_accesses(amy, x_rays).
                 };
-                let msg: Message = Message { id: "s3".into(), author: "amy".into(), payload: spec.reserialize().to_string().into_bytes() };
+                let msg: Message = Message {
+                    id:      "s3".into(),
+                    author:  "amy".into(),
+                    payload: spec.reserialize().to_string().into_bytes(),
+                    lang:    Some("datalog".into()),
+                };
 
                 // Emit it
                 stmts.state(Target::All, msg);
@@ -62,12 +69,14 @@ impl RationalAgent for Amy {
 
             // Then, she creates an Action
             {
-                // The action to emit
-                let act: Action<Message> = Action {
-                    basis:     (*agrs.agreed().get("s1").unwrap()).clone(),
-                    just:      LocalSet::from([(*stmts.stated().get("s2").unwrap()).clone()]),
-                    enacts:    (*stmts.stated().get("s3").unwrap()).clone(),
-                    timestamp: times.current(),
+                // The action to emit, for which she searches the justification among what's stated
+                let act: Action<Message> = {
+                    let agreed: LocalSet<&Agreement<Message>> = agrs.agreed();
+                    let stated: LocalSet<&Message> = stmts.stated();
+                    let basis: &Agreement<Message> = agreed.get("s1").unwrap();
+                    let enacts: &Message = stated.get("s3").unwrap();
+                    let just: LocalSet<&Message> = find_justification::<SpecExtractor, _, _>(basis, enacts, &stated, 1024).unwrap();
+                    Action { basis: basis.clone(), just: just.into_iter().cloned().collect(), enacts: enacts.clone(), timestamp: times.current() }
                 };
 
                 // Emit it
@@ -4,7 +4,7 @@
 //  Created:
 //    27 May 2024, 18:01:02
 //  Last edited:
-//    27 May 2024, 18:04:23
+//    16 Oct 2026, 19:38:18
 //  Auto updated?
 //    Yes
 //
@@ -45,7 +45,7 @@ impl RationalAgent for Anton {
         agrs: impl Agreements<Message = Self::Message>,
         times: impl Times,
         mut stmts: impl Statements<Message = Self::Message, Target = Self::Target>,
-    ) -> Result<AgentPoll, Self::Error> {
+    ) -> Result<AgentPoll<Self, Self::Message>, Self::Error> {
         // Anton emits some malicious messages at the end
         if stmts.stated().contains("s3") && !stmts.stated().contains("s5") {
             // To illustrate, we also emit an action at the end
@@ -54,7 +54,12 @@ impl RationalAgent for Anton {
                 let spec: Spec = datalog! {
                     ctl_authorises(administrator, anton, x_rays).
                 };
-                let msg: Message = Message { id: "s4".into(), author: "anton".into(), payload: spec.reserialize().to_string().into_bytes() };
+                let msg: Message = Message {
+                    id:      "s4".into(),
+                    author:  "anton".into(),
+                    payload: spec.reserialize().to_string().into_bytes(),
+                    lang:    Some("datalog".into()),
+                };
 
                 // Emit it
                 stmts.state(Target::All, msg);
@@ -64,7 +69,12 @@ impl RationalAgent for Anton {
                 let spec: Spec = datalog! {
                     ctl_accesses(anton, x_rays).
                 };
-                let msg: Message = Message { id: "s5".into(), author: "anton".into(), payload: spec.reserialize().to_string().into_bytes() };
+                let msg: Message = Message {
+                    id:      "s5".into(),
+                    author:  "anton".into(),
+                    payload: spec.reserialize().to_string().into_bytes(),
+                    lang:    Some("datalog".into()),
+                };
 
                 // Emit it
                 stmts.state(Target::All, msg);
@@ -86,7 +96,12 @@ impl RationalAgent for Anton {
             let spec: Spec = datalog! {
                 owns(anton, x_rays).
             };
-            let msg: Message = Message { id: "s6".into(), author: "anton".into(), payload: spec.reserialize().to_string().into_bytes() };
+            let msg: Message = Message {
+                id:      "s6".into(),
+                author:  "anton".into(),
+                payload: spec.reserialize().to_string().into_bytes(),
+                lang:    Some("datalog".into()),
+            };
 
             // Emit it
             stmts.state(Target::All, msg);
@@ -4,7 +4,7 @@
 //  Created:
 //    27 May 2024, 17:42:39
 //  Last edited:
-//    27 May 2024, 17:58:37
+//    16 Oct 2026, 19:38:18
 //  Auto updated?
 //    Yes
 //
@@ -19,7 +19,7 @@ use justact_core::agents::{Agent, AgentPoll, RationalAgent};
 use justact_core::agreements::{Agreement, Agreements};
 use justact_core::auxillary::Identifiable;
 use justact_core::statements::Statements;
-use justact_core::times::Times;
+use justact_core::times::{TimeRange, Times};
 use justact_prototype::statements::{Message, Target};
 
 
@@ -44,7 +44,7 @@ impl RationalAgent for Consortium {
         mut agrs: impl Agreements<Message = Self::Message>,
         times: impl Times,
         _stmts: impl Statements<Message = Self::Message, Target = Self::Target>,
-    ) -> Result<AgentPoll, Self::Error> {
+    ) -> Result<AgentPoll<Self, Self::Message>, Self::Error> {
         // The consortium emits 's1' at the start of the interaction
         if !agrs.agreed().contains("s1") {
             // Define the policy to emit
@@ -52,10 +52,15 @@ impl RationalAgent for Consortium {
                 owns(administrator, Data) :- ctl_accesses(Accessor, Data).
                 error :- ctl_accesses(Accessor, Data), owns(Owner, Data), not ctl_authorises(Owner, Accessor, Data).
             };
-            let msg: Message = Message { id: "s1".into(), author: "consortium".into(), payload: spec.reserialize().to_string().into_bytes() };
+            let msg: Message = Message {
+                id:      "s1".into(),
+                author:  "consortium".into(),
+                payload: spec.reserialize().to_string().into_bytes(),
+                lang:    Some("datalog".into()),
+            };
 
             // Emit it
-            agrs.agree(Agreement { msg, timestamp: times.current() }).unwrap();
+            agrs.agree(Agreement { msg, applies: TimeRange::starting(times.current()) }).unwrap();
 
             // The admin is done for this example
             return Ok(AgentPoll::Dead);
@@ -4,7 +4,7 @@
 //  Created:
 //    17 May 2024, 14:20:44
 //  Last edited:
-//    27 May 2024, 18:02:25
+//    16 Oct 2026, 19:38:18
 //  Auto updated?
 //    Yes
 //
@@ -66,12 +66,12 @@ impl RationalAgent for AbstractAgent {
         agrmnts: impl Agreements<Message = Self::Message>,
         times: impl Times,
         stmts: impl Statements<Message = Self::Message, Target = Self::Target>,
-    ) -> Result<AgentPoll, Self::Error> {
+    ) -> Result<AgentPoll<Self, Self::Message>, Self::Error> {
         match self {
-            Self::Administrator(a) => a.poll(agrmnts, times, stmts),
-            Self::Amy(a) => a.poll(agrmnts, times, stmts),
-            Self::Anton(a) => a.poll(agrmnts, times, stmts),
-            Self::Consortium(c) => c.poll(agrmnts, times, stmts),
+            Self::Administrator(a) => a.poll(agrmnts, times, stmts).map(|poll| poll.map_agents(Self::from)),
+            Self::Amy(a) => a.poll(agrmnts, times, stmts).map(|poll| poll.map_agents(Self::from)),
+            Self::Anton(a) => a.poll(agrmnts, times, stmts).map(|poll| poll.map_agents(Self::from)),
+            Self::Consortium(c) => c.poll(agrmnts, times, stmts).map(|poll| poll.map_agents(Self::from)),
         }
     }
 }
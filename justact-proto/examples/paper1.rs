@@ -4,7 +4,7 @@
 //  Created:
 //    16 Apr 2024, 11:00:44
 //  Last edited:
-//    29 May 2024, 13:41:57
+//    16 Oct 2026, 19:50:27
 //  Auto updated?
 //    Yes
 //
@@ -24,7 +24,8 @@ use console::Style;
 use datalog::justact::SpecExtractor;
 use error_trace::trace;
 use humanlog::{DebugMode, HumanLogger};
-use justact_prototype::Simulation;
+use justact_core::auditors::Paper;
+use justact_prototype::{Schedule, Simulation};
 use log::{error, info};
 
 use crate::paper::{AbstractAgent, Administrator, Amy, Anton, Consortium};
@@ -40,6 +41,9 @@ struct Arguments {
     /// If given, enables INFO-, DEBUG- and TRACE-level logging. Implies '--debug'.
     #[clap(long, global = true)]
     trace: bool,
+    /// If given, polls the agents in a random order every round that is determined by this seed.
+    #[clap(long, global = true)]
+    seed: Option<u64>,
 }
 
 
@@ -63,10 +67,13 @@ fn main() {
     sim.register(Administrator, Style::new().bold().yellow());
     sim.register(Amy, Style::new().bold().green());
     sim.register(Anton, Style::new().bold().magenta());
+    if let Some(seed) = args.seed {
+        sim.set_schedule(Schedule::Shuffled { seed });
+    }
 
     // Run it
     println!();
-    if let Err(err) = sim.run::<SpecExtractor>() {
+    if let Err(err) = sim.run::<SpecExtractor>(Paper) {
         error!("{}", trace!(("Failed to run simulation"), err));
         std::process::exit(1);
     };
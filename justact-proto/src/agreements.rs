@@ -4,7 +4,7 @@
 //  Created:
 //    23 May 2024, 17:42:56
 //  Last edited:
-//    29 May 2024, 13:40:32
+//    16 Oct 2026, 22:19:08
 //  Auto updated?
 //    Yes
 //
@@ -13,24 +13,203 @@
 //
 
 use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use std::error::Error;
 use std::fmt::{Display, Formatter, Result as FResult};
+use std::hash::{Hash as _, Hasher};
 use std::rc::Rc;
 
 use justact_core::agreements::{Agreement, Agreements as JAAgreements};
 use justact_core::auxillary::Identifiable as _;
 use justact_core::set::LocalSet;
+use justact_core::times::Timestamp;
+use serde::{Deserialize, Serialize};
 
+use crate::explorer::{hash_agreement, hash_unordered};
 use crate::interface::Interface;
 use crate::statements::Message;
 
 
+/***** TESTS *****/
+#[cfg(test)]
+mod tests {
+    use justact_core::times::TimeRange;
+
+    use super::*;
+    use crate::events::{Event, MemorySink};
+
+
+    /// Creates a GlobalAgreementsConsensus with agents `a`, `b` and `c` as voters.
+    ///
+    /// # Arguments
+    /// - `quorum`: The [`Quorum`] that must vote in favour of a proposal.
+    /// - `timeout`: The number of rounds before proposals expire.
+    ///
+    /// # Returns
+    /// A new [`GlobalAgreementsConsensus`], and a [`MemorySink`] that receives its events.
+    fn consensus(quorum: Quorum, timeout: usize) -> (GlobalAgreementsConsensus, MemorySink) {
+        let sink: MemorySink = MemorySink::new();
+        let mut interface: Interface = Interface::new();
+        interface.set_quiet(true);
+        interface.add_sink(Box::new(sink.clone()));
+        let mut agrs: GlobalAgreementsConsensus = GlobalAgreementsConsensus::new(quorum, timeout, Rc::new(RefCell::new(interface)));
+        for agent in ["a", "b", "c"] {
+            agrs.register(agent);
+        }
+        (agrs, sink)
+    }
+
+    /// Creates an agreement proposed by `a` that applies from the start.
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the agreement, which is also its payload.
+    ///
+    /// # Returns
+    /// A new [`Agreement`].
+    fn agr(id: &str) -> Agreement<Message> {
+        Agreement { msg: Message { id: id.into(), author: "a".into(), payload: id.as_bytes().to_vec(), lang: None }, applies: TimeRange::starting(Timestamp(0)) }
+    }
+
+    /// Lists the outcomes of the decisions among some events.
+    ///
+    /// # Arguments
+    /// - `events`: The [`Event`]s to search.
+    ///
+    /// # Returns
+    /// The identifiers of the decided proposals and their [`Outcome`]s, in order.
+    fn decided(events: Vec<Event>) -> Vec<(String, Outcome)> {
+        events
+            .into_iter()
+            .filter_map(|event| if let Event::Decided { id, outcome, .. } = event { Some((id, outcome)) } else { None })
+            .collect()
+    }
+
+
+    #[test]
+    fn test_quorum_decide() {
+        let set = |agents: &[&str]| -> HashSet<String> { agents.iter().map(|agent| agent.to_string()).collect() };
+        let voters: [&str; 3] = ["a", "b", "c"];
+
+        // Majority
+        assert_eq!(Quorum::Majority.decide(voters, &set(&["a"]), &set(&[])), None);
+        assert_eq!(Quorum::Majority.decide(voters, &set(&["a", "b"]), &set(&[])), Some(Outcome::Accepted));
+        assert_eq!(Quorum::Majority.decide(voters, &set(&["a"]), &set(&["b"])), None);
+        assert_eq!(Quorum::Majority.decide(voters, &set(&["a"]), &set(&["b", "c"])), Some(Outcome::Rejected));
+
+        // Unanimity
+        assert_eq!(Quorum::Unanimity.decide(voters, &set(&["a", "b"]), &set(&[])), None);
+        assert_eq!(Quorum::Unanimity.decide(voters, &set(&["a", "b", "c"]), &set(&[])), Some(Outcome::Accepted));
+        assert_eq!(Quorum::Unanimity.decide(voters, &set(&["a", "b"]), &set(&["c"])), Some(Outcome::Rejected));
+
+        // Weighted, where `c` has no weight and `a` more than half
+        let weighted: Quorum = Quorum::Weighted { weights: HashMap::from([("a".into(), 3), ("b".into(), 2)]) };
+        assert_eq!(weighted.weight("c"), 0);
+        assert_eq!(weighted.decide(voters, &set(&["b", "c"]), &set(&[])), None);
+        assert_eq!(weighted.decide(voters, &set(&["a"]), &set(&[])), Some(Outcome::Accepted));
+        assert_eq!(weighted.decide(voters, &set(&["b", "c"]), &set(&["a"])), Some(Outcome::Rejected));
+
+        // Weights that do not fit in a `u64` together still count in full
+        let heavy: Quorum = Quorum::Weighted { weights: HashMap::from([("a".into(), u64::MAX), ("b".into(), u64::MAX), ("c".into(), 1)]) };
+        assert_eq!(heavy.decide(voters, &set(&["a"]), &set(&[])), None);
+        assert_eq!(heavy.decide(voters, &set(&["a", "c"]), &set(&[])), Some(Outcome::Accepted));
+        assert_eq!(heavy.decide(voters, &set(&["a"]), &set(&["b", "c"])), Some(Outcome::Rejected));
+
+        // Without any weight, one is needed, so nothing can ever be accepted
+        let weightless: Quorum = Quorum::Weighted { weights: HashMap::from([("a".into(), 0)]) };
+        assert_eq!(weightless.decide(voters, &set(&["a", "b", "c"]), &set(&[])), Some(Outcome::Rejected));
+        assert_eq!(Quorum::Unanimity.decide([], &set(&[]), &set(&[])), Some(Outcome::Rejected));
+    }
+
+    #[test]
+    fn test_consensus_sync() {
+        // Accepted once a majority approves
+        let (mut agrs, sink): (GlobalAgreementsConsensus, MemorySink) = consensus(Quorum::Majority, 1);
+        agrs.sync("a", vec![Ballot::Propose(agr("x"))]);
+        assert!(agrs.proposed().contains("x"));
+        assert!(!agrs.agreed().contains("x"));
+        agrs.sync("b", vec![Ballot::Approve("x".into())]);
+        assert!(agrs.proposed().is_empty());
+        assert!(agrs.agreed().contains("x"));
+        assert_eq!(decided(sink.take()), vec![("x".into(), Outcome::Accepted)]);
+
+        // Votes on decided proposals are dropped silently
+        agrs.sync("c", vec![Ballot::Reject("x".into())]);
+        assert!(agrs.agreed().contains("x"));
+        assert!(sink.take().is_empty());
+
+        // Rejected once a majority rejects, even after approving first
+        agrs.sync("b", vec![Ballot::Propose(agr("y")), Ballot::Reject("y".into())]);
+        agrs.sync("c", vec![Ballot::Reject("y".into())]);
+        assert!(agrs.proposed().is_empty());
+        assert!(!agrs.agreed().contains("y"));
+        assert_eq!(decided(sink.take()), vec![("y".into(), Outcome::Rejected)]);
+
+        // Nothing is accepted without any weight
+        let (mut agrs, sink): (GlobalAgreementsConsensus, MemorySink) = consensus(Quorum::Weighted { weights: HashMap::new() }, 1);
+        agrs.sync("a", vec![Ballot::Propose(agr("z"))]);
+        assert!(agrs.proposed().is_empty());
+        assert!(agrs.agreed().is_empty());
+        assert_eq!(decided(sink.take()), vec![("z".into(), Outcome::Rejected)]);
+    }
+
+    #[test]
+    fn test_consensus_expiry() {
+        let (mut agrs, sink): (GlobalAgreementsConsensus, MemorySink) = consensus(Quorum::Unanimity, 1);
+        agrs.sync("a", vec![Ballot::Propose(agr("x"))]);
+        agrs.sync("b", vec![Ballot::Approve("x".into())]);
+
+        // It may still be voted on in the round after it was proposed...
+        agrs.finish_round();
+        assert!(agrs.proposed().contains("x"));
+        agrs.sync("a", vec![Ballot::Propose(agr("y"))]);
+
+        // ...but expires at the end of that round, unlike the newer one
+        agrs.finish_round();
+        assert!(!agrs.proposed().contains("x"));
+        assert!(agrs.proposed().contains("y"));
+        assert!(agrs.agreed().is_empty());
+        assert_eq!(decided(sink.take()), vec![("x".into(), Outcome::Expired)]);
+
+        // Late votes are dropped
+        agrs.sync("c", vec![Ballot::Approve("x".into())]);
+        assert!(agrs.agreed().is_empty());
+    }
+
+    #[test]
+    fn test_dictator_reject() {
+        let mut interface: Interface = Interface::new();
+        interface.set_quiet(true);
+        let mut agrs: GlobalAgreements = GlobalAgreementsDictator::new("a", Rc::new(RefCell::new(interface))).into();
+        let mut view: Agreements = agrs.view("a");
+        view.agree(agr("x")).unwrap();
+
+        // Nobody can veto, not even the dictator itself
+        assert!(matches!(view.reject("x"), Err(AgreementsError::Dictator { err: AgreementsDictatorError::NotVoting { .. } })));
+        let queue: AgreementsQueue = view.into_queue();
+        agrs.sync("a", queue);
+        assert!(matches!(agrs.view("b").reject("x"), Err(AgreementsError::Dictator { err: AgreementsDictatorError::NotVoting { .. } })));
+        assert!(matches!(agrs.reject("x"), Err(AgreementsError::Dictator { err: AgreementsDictatorError::NotVoting { .. } })));
+
+        // The agreement is unaffected
+        assert!(agrs.agreed().contains("x"));
+        assert!(agrs.proposed().is_empty());
+    }
+}
+
+
+
+
+
 /***** ERRORS *****/
 /// Determines the possible errors for the [`AgreementsDictator`] set.
 #[derive(Debug)]
 pub enum AgreementsDictatorError {
     /// The agent attempting to advance the time was not the dictator.
     NotTheDictator { id: String, agent: String, dictator: String },
+    /// The agreement that the agent attempted to revoke does not exist.
+    UnknownAgreement { id: String, agent: String },
+    /// The agent attempted to vote against an agreement, but a dictator does not vote.
+    NotVoting { id: String, agent: String },
 }
 impl Display for AgreementsDictatorError {
     #[inline]
@@ -40,22 +219,496 @@ impl Display for AgreementsDictatorError {
             NotTheDictator { id, agent, dictator } => {
                 write!(f, "Agent '{agent}' failed to create an agreement out of statement '{id}' because they are not the dictator ('{dictator}' is)")
             },
+            UnknownAgreement { id, agent } => write!(f, "Agent '{agent}' failed to revoke agreement '{id}' because it does not exist"),
+            NotVoting { id, agent } => {
+                write!(f, "Agent '{agent}' failed to vote against agreement '{id}' because agreements are decided by a dictator, not by voting")
+            },
         }
     }
 }
 impl Error for AgreementsDictatorError {}
 
+/// Determines the possible errors for the [`AgreementsConsensus`] set.
+#[derive(Debug)]
+pub enum AgreementsConsensusError {
+    /// The agreement that the agent attempted to revoke does not exist.
+    UnknownAgreement { id: String, agent: String },
+    /// The proposal that the agent attempted to vote against does not exist.
+    UnknownProposal { id: String, agent: String },
+    /// The agent attempted to agree on a different version of an agreement that is already proposed.
+    ConflictingProposal { id: String, agent: String },
+}
+impl Display for AgreementsConsensusError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use AgreementsConsensusError::*;
+        match self {
+            UnknownAgreement { id, agent } => write!(f, "Agent '{agent}' failed to revoke agreement '{id}' because it does not exist"),
+            UnknownProposal { id, agent } => write!(f, "Agent '{agent}' failed to vote against agreement '{id}' because it is not proposed"),
+            ConflictingProposal { id, agent } => {
+                write!(f, "Agent '{agent}' failed to agree on agreement '{id}' because a different version of it is already proposed")
+            },
+        }
+    }
+}
+impl Error for AgreementsConsensusError {}
+
+/// Determines the possible errors for the [`Agreements`] set.
+#[derive(Debug)]
+pub enum AgreementsError {
+    /// The agreements are decided by a dictator.
+    Dictator { err: AgreementsDictatorError },
+    /// The agreements are decided by consensus.
+    Consensus { err: AgreementsConsensusError },
+}
+impl Display for AgreementsError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use AgreementsError::*;
+        match self {
+            Dictator { err } => err.fmt(f),
+            Consensus { err } => err.fmt(f),
+        }
+    }
+}
+impl Error for AgreementsError {}
+impl From<AgreementsDictatorError> for AgreementsError {
+    #[inline]
+    fn from(value: AgreementsDictatorError) -> Self { Self::Dictator { err: value } }
+}
+impl From<AgreementsConsensusError> for AgreementsError {
+    #[inline]
+    fn from(value: AgreementsConsensusError) -> Self { Self::Consensus { err: value } }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Checks whether two agreements are the same version of an agreement.
+///
+/// # Arguments
+/// - `lhs`: The one [`Agreement`].
+/// - `rhs`: The other [`Agreement`].
+///
+/// # Returns
+/// True if both agree on the same message for the same times, or false otherwise.
+#[inline]
+fn same_version(lhs: &Agreement<Message>, rhs: &Agreement<Message>) -> bool { lhs.msg == rhs.msg && lhs.applies == rhs.applies }
+
+
+
+
+
+/***** AUXILLARY *****/
+/// Determines how many agents must vote in favour of a proposal before it is accepted.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Quorum {
+    /// More than half of the agents must vote in favour.
+    #[default]
+    Majority,
+    /// All agents must vote in favour.
+    Unanimity,
+    /// The agents voting in favour must together have more than half of the total weight of all
+    /// agents. Agents without a weight have none.
+    Weighted { weights: HashMap<String, u64> },
+}
+impl Quorum {
+    /// Returns the weight of the vote of a particular agent.
+    ///
+    /// # Arguments
+    /// - `agent`: The identifier of the agent who votes.
+    ///
+    /// # Returns
+    /// The weight of the agent's vote, which is 1 unless the quorum is [`Quorum::Weighted`].
+    #[inline]
+    pub fn weight(&self, agent: &str) -> u64 {
+        match self {
+            Self::Majority | Self::Unanimity => 1,
+            Self::Weighted { weights } => weights.get(agent).copied().unwrap_or(0),
+        }
+    }
+
+    /// Decides a vote, if possible.
+    ///
+    /// # Arguments
+    /// - `voters`: The identifiers of all agents who may vote.
+    /// - `approvals`: The identifiers of the agents who voted in favour.
+    /// - `rejections`: The identifiers of the agents who voted against.
+    ///
+    /// # Returns
+    /// [`Outcome::Accepted`] if enough voters voted in favour, [`Outcome::Rejected`] if enough
+    /// voters voted against that it can no longer be accepted, or [`None`] if the vote is still
+    /// open.
+    pub fn decide<'a>(&self, voters: impl IntoIterator<Item = &'a str>, approvals: &HashSet<String>, rejections: &HashSet<String>) -> Option<Outcome> {
+        // Sum as `u128`, which not even every voter having the largest weight overflows
+        let (mut total, mut yes, mut no): (u128, u128, u128) = (0, 0, 0);
+        for voter in voters {
+            let weight: u128 = self.weight(voter).into();
+            total += weight;
+            if approvals.contains(voter) {
+                yes += weight;
+            } else if rejections.contains(voter) {
+                no += weight;
+            }
+        }
+        let needed: u128 = match self {
+            Self::Majority | Self::Weighted { .. } => total / 2 + 1,
+            Self::Unanimity => total.max(1),
+        };
+        if yes >= needed {
+            Some(Outcome::Accepted)
+        } else if total - no < needed {
+            Some(Outcome::Rejected)
+        } else {
+            None
+        }
+    }
+}
+impl Display for Quorum {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        match self {
+            Self::Majority => write!(f, "majority"),
+            Self::Unanimity => write!(f, "unanimity"),
+            Self::Weighted { .. } => write!(f, "weighted majority"),
+        }
+    }
+}
+
+/// The outcome of voting on a proposal.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    /// The proposal reached its quorum.
+    Accepted,
+    /// The proposal can no longer reach its quorum.
+    Rejected,
+    /// The proposal was not decided in time.
+    Expired,
+}
+impl Display for Outcome {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        match self {
+            Self::Accepted => write!(f, "accepted"),
+            Self::Rejected => write!(f, "rejected"),
+            Self::Expired => write!(f, "expired"),
+        }
+    }
+}
+
+/// Something an agent does in an [`AgreementsConsensus`].
+#[derive(Clone, Debug)]
+pub(crate) enum Ballot {
+    /// The agent proposes a new agreement, or a new version of an existing one.
+    Propose(Agreement<Message>),
+    /// The agent votes in favour of the proposal with the given identifier.
+    Approve(String),
+    /// The agent votes against the proposal with the given identifier.
+    Reject(String),
+}
+
+/// An agreement proposed in a [`GlobalAgreementsConsensus`] that is still being voted on.
+#[derive(Clone, Debug)]
+struct Proposal {
+    /// The proposed agreement.
+    agr:        Agreement<Message>,
+    /// The agent who proposed it.
+    by:         String,
+    /// The agents who voted in favour, including the one who proposed it.
+    approvals:  HashSet<String>,
+    /// The agents who voted against.
+    rejections: HashSet<String>,
+    /// The number of rounds that ended since it was proposed.
+    age:        usize,
+}
+
+/// Whatever an agent queued in its [`Agreements`], to be synchronized later.
+#[derive(Clone, Debug)]
+pub(crate) enum AgreementsQueue {
+    /// The agreements queued in an [`AgreementsDictator`].
+    Dictator(Vec<Agreement<Message>>),
+    /// The ballots queued in an [`AgreementsConsensus`].
+    Consensus(Vec<Ballot>),
+}
+impl AgreementsQueue {
+    /// Hashes the queue.
+    ///
+    /// # Arguments
+    /// - `state`: The [`Hasher`] to hash into.
+    pub(crate) fn fingerprint(&self, state: &mut impl Hasher) {
+        match self {
+            Self::Dictator(agrs) => {
+                state.write_usize(agrs.len());
+                for agr in agrs {
+                    hash_agreement(agr, state);
+                }
+            },
+            Self::Consensus(ballots) => {
+                state.write_usize(ballots.len());
+                for ballot in ballots {
+                    match ballot {
+                        Ballot::Propose(agr) => {
+                            state.write_u8(0);
+                            hash_agreement(agr, state);
+                        },
+                        Ballot::Approve(id) => {
+                            state.write_u8(1);
+                            id.hash(state);
+                        },
+                        Ballot::Reject(id) => {
+                            state.write_u8(2);
+                            id.hash(state);
+                        },
+                    }
+                }
+            },
+        }
+    }
+}
+
 
 
 
 
 /***** LIBRARY *****/
+/// The agreements of a [`Simulation`](crate::Simulation), which are either decided by a
+/// dictator or by consensus.
+///
+/// Agents will see the agent-scoped variation [`Agreements`].
+#[derive(Clone, Debug)]
+pub enum GlobalAgreements {
+    /// A single agent decides on all agreements.
+    Dictator(GlobalAgreementsDictator),
+    /// Agents vote on every agreement.
+    Consensus(GlobalAgreementsConsensus),
+}
+impl GlobalAgreements {
+    /// Registers a new agent, who may vote on agreements if they are decided by consensus.
+    ///
+    /// # Arguments
+    /// - `agent`: The identifier of the agent to register.
+    #[inline]
+    pub(crate) fn register(&mut self, agent: &str) {
+        if let Self::Consensus(agrs) = self {
+            agrs.register(agent);
+        }
+    }
+
+    /// Changes the interface to which whatever happens is logged.
+    ///
+    /// # Arguments
+    /// - `interface`: The new interface to log to.
+    #[inline]
+    pub(crate) fn set_interface(&mut self, interface: Rc<RefCell<Interface>>) {
+        match self {
+            Self::Dictator(agrs) => agrs.set_interface(interface),
+            Self::Consensus(agrs) => agrs.set_interface(interface),
+        }
+    }
+
+    /// Returns an [`Agreements`] for a particular agent without synchronizing it back.
+    ///
+    /// # Arguments
+    /// - `agent`: The agent to scope this [`GlobalAgreements`] for.
+    ///
+    /// # Returns
+    /// A new [`Agreements`] of which the queue should be given to [`Self::sync()`].
+    #[inline]
+    pub(crate) fn view<'s>(&'s self, agent: &'s str) -> Agreements<'s> {
+        match self {
+            Self::Dictator(agrs) => Agreements::Dictator(agrs.view(agent)),
+            Self::Consensus(agrs) => Agreements::Consensus(agrs.view(agent)),
+        }
+    }
+
+    /// Synchronizes whatever an agent queued.
+    ///
+    /// # Arguments
+    /// - `agent`: The agent who queued it.
+    /// - `queue`: The [`AgreementsQueue`] of an [`Agreements`] returned by [`Self::view()`].
+    ///
+    /// # Panics
+    /// This function panics if the queue was made for the other kind of agreements.
+    #[inline]
+    pub(crate) fn sync(&mut self, agent: &str, queue: AgreementsQueue) {
+        match (self, queue) {
+            (Self::Dictator(agrs), AgreementsQueue::Dictator(queue)) => agrs.sync(agent, queue),
+            (Self::Consensus(agrs), AgreementsQueue::Consensus(queue)) => agrs.sync(agent, queue),
+            _ => panic!("Cannot synchronize agreements queued for the other kind of agreements"),
+        }
+    }
+
+    /// Ends a round, e.g., to let proposals that were not decided in time expire.
+    #[inline]
+    pub(crate) fn finish_round(&mut self) {
+        if let Self::Consensus(agrs) = self {
+            agrs.finish_round();
+        }
+    }
+
+    /// Hashes the agreements, regardless of the order in which they were agreed upon.
+    ///
+    /// # Arguments
+    /// - `state`: The [`Hasher`] to hash into.
+    #[inline]
+    pub(crate) fn fingerprint(&self, state: &mut impl Hasher) {
+        match self {
+            Self::Dictator(agrs) => agrs.fingerprint(state),
+            Self::Consensus(agrs) => agrs.fingerprint(state),
+        }
+    }
+}
+impl From<GlobalAgreementsDictator> for GlobalAgreements {
+    #[inline]
+    fn from(value: GlobalAgreementsDictator) -> Self { Self::Dictator(value) }
+}
+impl From<GlobalAgreementsConsensus> for GlobalAgreements {
+    #[inline]
+    fn from(value: GlobalAgreementsConsensus) -> Self { Self::Consensus(value) }
+}
+impl JAAgreements for GlobalAgreements {
+    type Message = Message;
+    type Error = AgreementsError;
+
+    #[inline]
+    fn agree(&mut self, agr: Agreement<Self::Message>) -> Result<(), Self::Error> {
+        match self {
+            Self::Dictator(agrs) => Ok(agrs.agree(agr)?),
+            Self::Consensus(agrs) => Ok(agrs.agree(agr)?),
+        }
+    }
+
+    #[inline]
+    fn revoke(&mut self, id: &str, at: Timestamp) -> Result<(), Self::Error> {
+        match self {
+            Self::Dictator(agrs) => Ok(agrs.revoke(id, at)?),
+            Self::Consensus(agrs) => Ok(agrs.revoke(id, at)?),
+        }
+    }
+
+    #[inline]
+    fn reject(&mut self, id: &str) -> Result<(), Self::Error> {
+        match self {
+            Self::Dictator(agrs) => Ok(agrs.reject(id)?),
+            Self::Consensus(agrs) => Ok(agrs.reject(id)?),
+        }
+    }
+
+    #[inline]
+    fn agreed(&self) -> LocalSet<&Agreement<Self::Message>> {
+        match self {
+            Self::Dictator(agrs) => agrs.agreed(),
+            Self::Consensus(agrs) => agrs.agreed(),
+        }
+    }
+
+    #[inline]
+    fn proposed(&self) -> LocalSet<&Agreement<Self::Message>> {
+        match self {
+            Self::Dictator(agrs) => agrs.proposed(),
+            Self::Consensus(agrs) => agrs.proposed(),
+        }
+    }
+}
+
+/// Provides agents with a global view on the agreed upon agreements, which are either decided by
+/// a dictator or by consensus.
+#[derive(Debug)]
+pub enum Agreements<'v> {
+    /// A single agent decides on all agreements.
+    Dictator(AgreementsDictator<'v>),
+    /// Agents vote on every agreement.
+    Consensus(AgreementsConsensus<'v>),
+}
+impl<'v> Agreements<'v> {
+    /// Releases this view, keeping only whatever the agent queued.
+    ///
+    /// # Returns
+    /// An [`AgreementsQueue`] to give to [`GlobalAgreements::sync()`].
+    #[inline]
+    pub(crate) fn into_queue(self) -> AgreementsQueue {
+        match self {
+            Self::Dictator(agrs) => AgreementsQueue::Dictator(agrs.queue),
+            Self::Consensus(agrs) => AgreementsQueue::Consensus(agrs.queue),
+        }
+    }
+}
+impl<'v> JAAgreements for Agreements<'v> {
+    type Message = Message;
+    type Error = AgreementsError;
+
+    #[inline]
+    fn agree(&mut self, agr: Agreement<Self::Message>) -> Result<(), Self::Error> {
+        match self {
+            Self::Dictator(agrs) => Ok(agrs.agree(agr)?),
+            Self::Consensus(agrs) => Ok(agrs.agree(agr)?),
+        }
+    }
+
+    #[inline]
+    fn revoke(&mut self, id: &str, at: Timestamp) -> Result<(), Self::Error> {
+        match self {
+            Self::Dictator(agrs) => Ok(agrs.revoke(id, at)?),
+            Self::Consensus(agrs) => Ok(agrs.revoke(id, at)?),
+        }
+    }
+
+    #[inline]
+    fn reject(&mut self, id: &str) -> Result<(), Self::Error> {
+        match self {
+            Self::Dictator(agrs) => Ok(agrs.reject(id)?),
+            Self::Consensus(agrs) => Ok(agrs.reject(id)?),
+        }
+    }
+
+    #[inline]
+    fn agreed(&self) -> LocalSet<&Agreement<Self::Message>> {
+        match self {
+            Self::Dictator(agrs) => agrs.agreed(),
+            Self::Consensus(agrs) => agrs.agreed(),
+        }
+    }
+
+    #[inline]
+    fn proposed(&self) -> LocalSet<&Agreement<Self::Message>> {
+        match self {
+            Self::Dictator(agrs) => agrs.proposed(),
+            Self::Consensus(agrs) => agrs.proposed(),
+        }
+    }
+}
+impl<'v> JAAgreements for &mut Agreements<'v> {
+    type Message = <Agreements<'v> as JAAgreements>::Message;
+    type Error = <Agreements<'v> as JAAgreements>::Error;
+
+    #[inline]
+    fn agree(&mut self, agr: Agreement<Self::Message>) -> Result<(), Self::Error> { Agreements::agree(self, agr) }
+
+    #[inline]
+    fn revoke(&mut self, id: &str, at: Timestamp) -> Result<(), Self::Error> { Agreements::revoke(self, id, at) }
+
+    #[inline]
+    fn reject(&mut self, id: &str) -> Result<(), Self::Error> { Agreements::reject(self, id) }
+
+    #[inline]
+    fn agreed(&self) -> LocalSet<&Agreement<Self::Message>> { Agreements::agreed(self) }
+
+    #[inline]
+    fn proposed(&self) -> LocalSet<&Agreement<Self::Message>> { Agreements::proposed(self) }
+}
+
+
+
 /// An owned version of the agreements.
 ///
 /// This variation synchronizes new agreements if and only if it's a particular agent claiming it.
 ///
 /// Agents will see the agent-scoped variation [`AgreementsDictator`].
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct GlobalAgreementsDictator {
     /// The only agent allowed to make changes.
     dictator:  String,
@@ -79,6 +732,13 @@ impl GlobalAgreementsDictator {
         Self { dictator: dictator.clone(), interface, agrs: LocalSet::new() }
     }
 
+    /// Changes the interface to which whatever happens is logged.
+    ///
+    /// # Arguments
+    /// - `interface`: The new interface to log to.
+    #[inline]
+    pub(crate) fn set_interface(&mut self, interface: Rc<RefCell<Interface>>) { self.interface = interface; }
+
     /// Returns an [`AgreementsDictator`] which is scoped for a particular agent.
     ///
     /// # Arguments
@@ -90,22 +750,54 @@ impl GlobalAgreementsDictator {
     #[inline]
     pub fn scope<R>(&mut self, agent: &str, func: impl FnOnce(&mut AgreementsDictator) -> R) -> R {
         // Call the closure
-        let (res, mut queue): (R, Vec<Agreement<Message>>) = {
-            let mut view = AgreementsDictator { agent, dictator: &self.dictator, agrs: &self.agrs, queue: vec![] };
+        let (res, queue): (R, Vec<Agreement<Message>>) = {
+            let mut view: AgreementsDictator = self.view(agent);
             let res: R = func(&mut view);
             (res, view.queue)
         };
 
         // Sync the changes back
+        self.sync(agent, queue);
+        res
+    }
+
+    /// Returns an [`AgreementsDictator`] for a particular agent without synchronizing it back.
+    ///
+    /// # Arguments
+    /// - `agent`: The agent to scope this [`GlobalAgreementsDictator`] for.
+    ///
+    /// # Returns
+    /// A new [`AgreementsDictator`] of which the queue should be given to [`Self::sync()`].
+    #[inline]
+    pub(crate) fn view<'s>(&'s self, agent: &'s str) -> AgreementsDictator<'s> {
+        AgreementsDictator { agent, dictator: &self.dictator, agrs: &self.agrs, queue: vec![] }
+    }
+
+    /// Synchronizes the agreements queued by an agent.
+    ///
+    /// # Arguments
+    /// - `agent`: The agent who queued them.
+    /// - `queue`: The queue of an [`AgreementsDictator`] returned by [`Self::view()`].
+    #[inline]
+    pub(crate) fn sync(&mut self, agent: &str, queue: Vec<Agreement<Message>>) {
         self.agrs.reserve(queue.len());
-        for agr in queue.drain(..) {
-            self.interface.borrow().log_agree(&agr);
+        for agr in queue {
+            // Revoked agreements are queued as new versions of existing ones
+            if self.agrs.contains(agr.id()) {
+                self.interface.borrow_mut().log_revoke(agent, &agr);
+            } else {
+                self.interface.borrow_mut().log_agree(agent, &agr);
+            }
             self.agrs.add(agr);
         }
-
-        // OK, done
-        res
     }
+
+    /// Hashes the agreements, regardless of the order in which they were agreed upon.
+    ///
+    /// # Arguments
+    /// - `state`: The [`Hasher`] to hash into.
+    #[inline]
+    pub(crate) fn fingerprint(&self, state: &mut impl Hasher) { hash_unordered(&self.agrs, hash_agreement, state); }
 }
 impl JAAgreements for GlobalAgreementsDictator {
     type Message = Message;
@@ -123,7 +815,28 @@ impl JAAgreements for GlobalAgreementsDictator {
     }
 
     #[inline]
-    fn agreed<'s>(&'s self) -> LocalSet<&'s Agreement<Self::Message>> { self.agrs.iter().collect() }
+    fn revoke(&mut self, id: &str, at: Timestamp) -> Result<(), Self::Error> {
+        // Do not revoke if we're not the dictator
+        if self.dictator != "<system>" {
+            return Err(AgreementsDictatorError::NotTheDictator { id: id.into(), agent: "<system>".into(), dictator: self.dictator.clone() });
+        }
+
+        // Shorten the existing agreement
+        let mut agr: Agreement<Message> =
+            self.agrs.remove(id).ok_or_else(|| AgreementsDictatorError::UnknownAgreement { id: id.into(), agent: "<system>".into() })?;
+        agr.revoke(at);
+        self.agrs.add(agr);
+        Ok(())
+    }
+
+    #[inline]
+    fn reject(&mut self, id: &str) -> Result<(), Self::Error> {
+        // Nothing is ever proposed, so there is nothing to veto
+        Err(AgreementsDictatorError::NotVoting { id: id.into(), agent: "<system>".into() })
+    }
+
+    #[inline]
+    fn agreed(&self) -> LocalSet<&Agreement<Self::Message>> { self.agrs.iter().collect() }
 }
 
 /// Provides agents with a global view on the agreed upon agreements.
@@ -138,7 +851,7 @@ pub struct AgreementsDictator<'v> {
 
     /// The statements that this agent knows of.
     agrs: &'v LocalSet<Agreement<Message>>,
-    /// A queue of statements that this agent pushed.
+    /// A queue of statements that this agent pushed. Revocations are pushed as new versions of existing agreements.
     pub(crate) queue: Vec<Agreement<Message>>,
 }
 impl<'v> JAAgreements for AgreementsDictator<'v> {
@@ -157,9 +870,32 @@ impl<'v> JAAgreements for AgreementsDictator<'v> {
     }
 
     #[inline]
-    fn agreed<'s>(&'s self) -> LocalSet<&'s Agreement<Self::Message>> { self.agrs.iter().chain(self.queue.iter()).collect() }
+    fn revoke(&mut self, id: &str, at: Timestamp) -> Result<(), Self::Error> {
+        // Do not revoke if we're not the dictator
+        if self.agent != self.dictator {
+            return Err(AgreementsDictatorError::NotTheDictator { id: id.into(), agent: self.agent.into(), dictator: self.dictator.into() });
+        }
+
+        // Find the most recent version of the agreement, then queue a shortened version of it
+        let mut agr: Agreement<Message> = match self.queue.iter().rev().find(|agr| agr.id() == id) {
+            Some(agr) => agr.clone(),
+            None => self.agrs.get(id).cloned().ok_or_else(|| AgreementsDictatorError::UnknownAgreement { id: id.into(), agent: self.agent.into() })?,
+        };
+        agr.revoke(at);
+        self.queue.push(agr);
+        Ok(())
+    }
+
+    #[inline]
+    fn reject(&mut self, id: &str) -> Result<(), Self::Error> {
+        // Nothing is ever proposed, so there is nothing to veto
+        Err(AgreementsDictatorError::NotVoting { id: id.into(), agent: self.agent.into() })
+    }
+
+    #[inline]
+    fn agreed(&self) -> LocalSet<&Agreement<Self::Message>> { self.agrs.iter().chain(self.queue.iter()).collect() }
 }
-impl<'a, 'v> JAAgreements for &'a mut AgreementsDictator<'v> {
+impl<'v> JAAgreements for &mut AgreementsDictator<'v> {
     type Message = <AgreementsDictator<'v> as JAAgreements>::Message;
     type Error = <AgreementsDictator<'v> as JAAgreements>::Error;
 
@@ -167,5 +903,428 @@ impl<'a, 'v> JAAgreements for &'a mut AgreementsDictator<'v> {
     fn agree(&mut self, agr: Agreement<Self::Message>) -> Result<(), Self::Error> { AgreementsDictator::agree(self, agr) }
 
     #[inline]
-    fn agreed<'s>(&'s self) -> LocalSet<&'s Agreement<Self::Message>> { AgreementsDictator::agreed(self) }
+    fn revoke(&mut self, id: &str, at: Timestamp) -> Result<(), Self::Error> { AgreementsDictator::revoke(self, id, at) }
+
+    #[inline]
+    fn reject(&mut self, id: &str) -> Result<(), Self::Error> { AgreementsDictator::reject(self, id) }
+
+    #[inline]
+    fn agreed(&self) -> LocalSet<&Agreement<Self::Message>> { AgreementsDictator::agreed(self) }
+
+    #[inline]
+    fn proposed(&self) -> LocalSet<&Agreement<Self::Message>> { AgreementsDictator::proposed(self) }
+}
+
+
+
+/// An owned version of the agreements.
+///
+/// This variation synchronizes new agreements if and only if a [`Quorum`] of agents votes in
+/// favour of them. Any agent may propose an agreement by agreeing on it, after which the others
+/// vote over the following rounds by agreeing on it as well (in favour) or by rejecting it
+/// (against). Revoking an agreement proposes a shortened version of it.
+///
+/// Agents will see the agent-scoped variation [`AgreementsConsensus`].
+#[derive(Clone, Debug)]
+pub struct GlobalAgreementsConsensus {
+    /// Determines how many agents must vote in favour of a proposal.
+    quorum:    Quorum,
+    /// The number of rounds after the one in which an agreement is proposed before it expires.
+    timeout:   usize,
+    /// The agents who may vote.
+    voters:    Vec<String>,
+    /// The proposals that are still being voted on, by identifier.
+    proposals: HashMap<String, Proposal>,
+    /// An interface we use to log whatever happens in pretty ways.
+    interface: Rc<RefCell<Interface>>,
+    /// All agreements in the land.
+    agrs:      LocalSet<Agreement<Message>>,
+}
+impl GlobalAgreementsConsensus {
+    /// Constructor for the GlobalAgreementsConsensus.
+    ///
+    /// # Arguments
+    /// - `quorum`: The [`Quorum`] that must vote in favour of a proposal.
+    /// - `timeout`: The number of rounds after the one in which an agreement is proposed before
+    ///   it expires if it isn't decided by then.
+    /// - `interface`: An interface we use to log whatever happens in pretty ways.
+    ///
+    /// # Returns
+    /// A new GlobalAgreementsConsensus without any voters.
+    #[inline]
+    pub fn new(quorum: Quorum, timeout: usize, interface: Rc<RefCell<Interface>>) -> Self {
+        Self { quorum, timeout, voters: Vec::new(), proposals: HashMap::new(), interface, agrs: LocalSet::new() }
+    }
+
+    /// Changes the interface to which whatever happens is logged.
+    ///
+    /// # Arguments
+    /// - `interface`: The new interface to log to.
+    #[inline]
+    pub(crate) fn set_interface(&mut self, interface: Rc<RefCell<Interface>>) { self.interface = interface; }
+
+    /// Returns the quorum that must vote in favour of a proposal.
+    ///
+    /// # Returns
+    /// The [`Quorum`] of this GlobalAgreementsConsensus.
+    #[inline]
+    pub fn quorum(&self) -> &Quorum { &self.quorum }
+
+    /// Returns the number of rounds after the one in which an agreement is proposed before it
+    /// expires.
+    ///
+    /// # Returns
+    /// The timeout, in rounds.
+    #[inline]
+    pub fn timeout(&self) -> usize { self.timeout }
+
+    /// Registers a new agent who may vote.
+    ///
+    /// # Arguments
+    /// - `agent`: The identifier of the agent to register.
+    #[inline]
+    pub fn register(&mut self, agent: &str) {
+        if !self.voters.iter().any(|voter| voter == agent) {
+            self.voters.push(agent.into());
+        }
+    }
+
+    /// Returns an [`AgreementsConsensus`] which is scoped for a particular agent.
+    ///
+    /// # Arguments
+    /// - `agent`: The agent to scope this [`GlobalAgreementsConsensus`] for.
+    /// - `func`: Some function that is executed for this scope.
+    ///
+    /// # Returns
+    /// The result of the given closure `func`.
+    #[inline]
+    pub fn scope<R>(&mut self, agent: &str, func: impl FnOnce(&mut AgreementsConsensus) -> R) -> R {
+        // Call the closure
+        let (res, queue): (R, Vec<Ballot>) = {
+            let mut view: AgreementsConsensus = self.view(agent);
+            let res: R = func(&mut view);
+            (res, view.queue)
+        };
+
+        // Sync the changes back
+        self.sync(agent, queue);
+        res
+    }
+
+    /// Returns an [`AgreementsConsensus`] for a particular agent without synchronizing it back.
+    ///
+    /// # Arguments
+    /// - `agent`: The agent to scope this [`GlobalAgreementsConsensus`] for.
+    ///
+    /// # Returns
+    /// A new [`AgreementsConsensus`] of which the queue should be given to [`Self::sync()`].
+    #[inline]
+    pub(crate) fn view<'s>(&'s self, agent: &'s str) -> AgreementsConsensus<'s> {
+        AgreementsConsensus { agent, agrs: &self.agrs, proposals: &self.proposals, queue: vec![] }
+    }
+
+    /// Synchronizes the proposals and votes queued by an agent, deciding any proposals that reach
+    /// (or can no longer reach) their quorum.
+    ///
+    /// # Arguments
+    /// - `agent`: The agent who queued them.
+    /// - `queue`: The queue of an [`AgreementsConsensus`] returned by [`Self::view()`].
+    pub(crate) fn sync(&mut self, agent: &str, queue: Vec<Ballot>) {
+        for ballot in queue {
+            let id: String = match ballot {
+                Ballot::Propose(agr) => {
+                    let id: String = agr.id().into();
+                    match self.proposals.get_mut(&id) {
+                        // Proposing the same version again is simply a vote in favour
+                        Some(prop) if same_version(&prop.agr, &agr) => {
+                            prop.rejections.remove(agent);
+                            prop.approvals.insert(agent.into());
+                            self.interface.borrow_mut().log_vote(agent, &id, true);
+                        },
+                        Some(_) => {
+                            self.interface
+                                .borrow_mut()
+                                .error(agent, format!("Ignoring proposal of agreement '{id}' because a different version of it is already proposed"));
+                            continue;
+                        },
+                        None => {
+                            self.interface.borrow_mut().log_propose(agent, &agr);
+                            let approvals: HashSet<String> = HashSet::from([agent.into()]);
+                            self.proposals.insert(id.clone(), Proposal { agr, by: agent.into(), approvals, rejections: HashSet::new(), age: 0 });
+                        },
+                    }
+                    id
+                },
+                Ballot::Approve(id) | Ballot::Reject(id) if !self.proposals.contains_key(&id) => {
+                    // It was decided before this vote was synchronized
+                    continue;
+                },
+                Ballot::Approve(id) => {
+                    if let Some(prop) = self.proposals.get_mut(&id) {
+                        prop.rejections.remove(agent);
+                        prop.approvals.insert(agent.into());
+                    }
+                    self.interface.borrow_mut().log_vote(agent, &id, true);
+                    id
+                },
+                Ballot::Reject(id) => {
+                    if let Some(prop) = self.proposals.get_mut(&id) {
+                        prop.approvals.remove(agent);
+                        prop.rejections.insert(agent.into());
+                    }
+                    self.interface.borrow_mut().log_vote(agent, &id, false);
+                    id
+                },
+            };
+            self.decide(agent, &id);
+        }
+    }
+
+    /// Replays that an agent proposed an agreement, without deciding it.
+    ///
+    /// # Arguments
+    /// - `agent`: The agent who proposed it.
+    /// - `agr`: The proposed [`Agreement`].
+    #[inline]
+    pub(crate) fn replay_propose(&mut self, agent: &str, agr: Agreement<Message>) {
+        let approvals: HashSet<String> = HashSet::from([agent.into()]);
+        self.proposals.insert(agr.id().into(), Proposal { agr, by: agent.into(), approvals, rejections: HashSet::new(), age: 0 });
+    }
+
+    /// Replays that an agent voted on a proposal, without deciding it.
+    ///
+    /// # Arguments
+    /// - `agent`: The agent who voted.
+    /// - `id`: The identifier of the proposed agreement.
+    /// - `approve`: Whether the agent voted in favour.
+    pub(crate) fn replay_vote(&mut self, agent: &str, id: &str, approve: bool) {
+        if let Some(prop) = self.proposals.get_mut(id) {
+            if approve {
+                prop.rejections.remove(agent);
+                prop.approvals.insert(agent.into());
+            } else {
+                prop.approvals.remove(agent);
+                prop.rejections.insert(agent.into());
+            }
+        }
+    }
+
+    /// Replays that a proposal was decided, removing it. If it was accepted, the agreement is
+    /// added by [`Self::replay_agree()`].
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the proposed agreement.
+    #[inline]
+    pub(crate) fn replay_decide(&mut self, id: &str) { self.proposals.remove(id); }
+
+    /// Replays that an agreement was agreed upon (or revoked).
+    ///
+    /// # Arguments
+    /// - `agr`: The [`Agreement`] as it applies from now on.
+    #[inline]
+    pub(crate) fn replay_agree(&mut self, agr: Agreement<Message>) { self.agrs.add(agr); }
+
+    /// Ends a round, letting any proposals that were not decided in time expire.
+    pub(crate) fn finish_round(&mut self) {
+        let mut expired: Vec<String> = Vec::new();
+        for (id, prop) in &mut self.proposals {
+            prop.age += 1;
+            if prop.age > self.timeout {
+                expired.push(id.clone());
+            }
+        }
+
+        // Expire them in a predictable order
+        expired.sort();
+        for id in expired {
+            if let Some(prop) = self.proposals.remove(&id) {
+                self.conclude("<system>", prop, Outcome::Expired);
+            }
+        }
+    }
+
+    /// Decides a proposal if it reached (or can no longer reach) its quorum.
+    ///
+    /// # Arguments
+    /// - `agent`: The agent whose vote may have decided it.
+    /// - `id`: The identifier of the proposed agreement.
+    fn decide(&mut self, agent: &str, id: &str) {
+        let outcome: Option<Outcome> = match self.proposals.get(id) {
+            Some(prop) => self.quorum.decide(self.voters.iter().map(String::as_str), &prop.approvals, &prop.rejections),
+            None => None,
+        };
+        if let Some(outcome) = outcome {
+            if let Some(prop) = self.proposals.remove(id) {
+                self.conclude(agent, prop, outcome);
+            }
+        }
+    }
+
+    /// Concludes a proposal, adding it to the agreements if it was accepted.
+    ///
+    /// # Arguments
+    /// - `agent`: The agent whose vote decided it.
+    /// - `prop`: The decided [`Proposal`].
+    /// - `outcome`: The [`Outcome`] of the vote.
+    fn conclude(&mut self, agent: &str, prop: Proposal, outcome: Outcome) {
+        self.interface.borrow_mut().log_decide(agent, prop.agr.id(), outcome);
+        if outcome == Outcome::Accepted {
+            // Revocations are proposed as new versions of existing agreements
+            if self.agrs.contains(prop.agr.id()) {
+                self.interface.borrow_mut().log_revoke(&prop.by, &prop.agr);
+            } else {
+                self.interface.borrow_mut().log_agree(&prop.by, &prop.agr);
+            }
+            self.agrs.add(prop.agr);
+        }
+    }
+
+    /// Hashes the agreements and proposals, regardless of the order in which they were made.
+    ///
+    /// # Arguments
+    /// - `state`: The [`Hasher`] to hash into.
+    pub(crate) fn fingerprint(&self, state: &mut impl Hasher) {
+        hash_unordered(&self.agrs, hash_agreement, state);
+        hash_unordered(
+            &self.proposals,
+            |(id, prop), state| {
+                id.hash(state);
+                hash_agreement(&prop.agr, state);
+                prop.by.hash(state);
+                hash_unordered(&prop.approvals, |agent, state| agent.hash(state), state);
+                hash_unordered(&prop.rejections, |agent, state| agent.hash(state), state);
+                prop.age.hash(state);
+            },
+            state,
+        );
+    }
+}
+impl JAAgreements for GlobalAgreementsConsensus {
+    type Message = Message;
+    type Error = AgreementsConsensusError;
+
+    #[inline]
+    fn agree(&mut self, agr: Agreement<Self::Message>) -> Result<(), Self::Error> {
+        // The system itself does not need a vote
+        self.agrs.add(agr);
+        Ok(())
+    }
+
+    #[inline]
+    fn revoke(&mut self, id: &str, at: Timestamp) -> Result<(), Self::Error> {
+        let mut agr: Agreement<Message> =
+            self.agrs.remove(id).ok_or_else(|| AgreementsConsensusError::UnknownAgreement { id: id.into(), agent: "<system>".into() })?;
+        agr.revoke(at);
+        self.agrs.add(agr);
+        Ok(())
+    }
+
+    #[inline]
+    fn reject(&mut self, id: &str) -> Result<(), Self::Error> {
+        // The system itself vetoes the proposal
+        let prop: Proposal =
+            self.proposals.remove(id).ok_or_else(|| AgreementsConsensusError::UnknownProposal { id: id.into(), agent: "<system>".into() })?;
+        self.conclude("<system>", prop, Outcome::Rejected);
+        Ok(())
+    }
+
+    #[inline]
+    fn agreed(&self) -> LocalSet<&Agreement<Self::Message>> { self.agrs.iter().collect() }
+
+    #[inline]
+    fn proposed(&self) -> LocalSet<&Agreement<Self::Message>> { self.proposals.values().map(|prop| &prop.agr).collect() }
+}
+
+/// Provides agents with a global view on the agreed upon agreements.
+///
+/// This variation synchronizes new agreements if and only if a [`Quorum`] of agents votes in
+/// favour of them.
+#[derive(Debug)]
+pub struct AgreementsConsensus<'v> {
+    /// This agent
+    agent: &'v str,
+
+    /// The agreements that this agent knows of.
+    agrs: &'v LocalSet<Agreement<Message>>,
+    /// The proposals that are still being voted on.
+    proposals: &'v HashMap<String, Proposal>,
+    /// A queue of proposals and votes that this agent pushed.
+    pub(crate) queue: Vec<Ballot>,
+}
+impl<'v> JAAgreements for AgreementsConsensus<'v> {
+    type Message = Message;
+    type Error = AgreementsConsensusError;
+
+    #[inline]
+    fn agree(&mut self, agr: Agreement<Self::Message>) -> Result<(), Self::Error> {
+        // Find any version of it that is already proposed, by others or by ourselves
+        let proposed: Option<&Agreement<Message>> = self.proposals.get(agr.id()).map(|prop| &prop.agr).or_else(|| {
+            self.queue.iter().find_map(|ballot| match ballot {
+                Ballot::Propose(prop) if prop.id() == agr.id() => Some(prop),
+                _ => None,
+            })
+        });
+
+        // Vote in favour of the same version, or propose it if there is none
+        match proposed {
+            Some(prop) if !same_version(prop, &agr) => Err(AgreementsConsensusError::ConflictingProposal { id: agr.id().into(), agent: self.agent.into() }),
+            Some(_) => {
+                self.queue.push(Ballot::Approve(agr.id().into()));
+                Ok(())
+            },
+            None => {
+                self.queue.push(Ballot::Propose(agr));
+                Ok(())
+            },
+        }
+    }
+
+    #[inline]
+    fn revoke(&mut self, id: &str, at: Timestamp) -> Result<(), Self::Error> {
+        // Propose a shortened version of the existing agreement
+        let mut agr: Agreement<Message> =
+            self.agrs.get(id).cloned().ok_or_else(|| AgreementsConsensusError::UnknownAgreement { id: id.into(), agent: self.agent.into() })?;
+        agr.revoke(at);
+        self.agree(agr)
+    }
+
+    #[inline]
+    fn reject(&mut self, id: &str) -> Result<(), Self::Error> {
+        if !self.proposals.contains_key(id) {
+            return Err(AgreementsConsensusError::UnknownProposal { id: id.into(), agent: self.agent.into() });
+        }
+        self.queue.push(Ballot::Reject(id.into()));
+        Ok(())
+    }
+
+    #[inline]
+    fn agreed(&self) -> LocalSet<&Agreement<Self::Message>> { self.agrs.iter().collect() }
+
+    #[inline]
+    fn proposed(&self) -> LocalSet<&Agreement<Self::Message>> {
+        self.proposals
+            .values()
+            .map(|prop| &prop.agr)
+            .chain(self.queue.iter().filter_map(|ballot| if let Ballot::Propose(agr) = ballot { Some(agr) } else { None }))
+            .collect()
+    }
+}
+impl<'v> JAAgreements for &mut AgreementsConsensus<'v> {
+    type Message = <AgreementsConsensus<'v> as JAAgreements>::Message;
+    type Error = <AgreementsConsensus<'v> as JAAgreements>::Error;
+
+    #[inline]
+    fn agree(&mut self, agr: Agreement<Self::Message>) -> Result<(), Self::Error> { AgreementsConsensus::agree(self, agr) }
+
+    #[inline]
+    fn revoke(&mut self, id: &str, at: Timestamp) -> Result<(), Self::Error> { AgreementsConsensus::revoke(self, id, at) }
+
+    #[inline]
+    fn reject(&mut self, id: &str) -> Result<(), Self::Error> { AgreementsConsensus::reject(self, id) }
+
+    #[inline]
+    fn agreed(&self) -> LocalSet<&Agreement<Self::Message>> { AgreementsConsensus::agreed(self) }
+
+    #[inline]
+    fn proposed(&self) -> LocalSet<&Agreement<Self::Message>> { AgreementsConsensus::proposed(self) }
 }
@@ -0,0 +1,493 @@
+//  BYZANTINE.rs
+//    by Lut99
+//
+//  Created:
+//    16 Oct 2026, 20:19:47
+//  Last edited:
+//    16 Oct 2026, 21:18:14
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements ready-made adversarial agents that misbehave in known
+//!   ways, such that the audit and the [`Simulation`](crate::Simulation)
+//!   can be checked against them.
+//
+
+use std::convert::Infallible;
+
+use justact_core::agents::{Agent, AgentPoll, RationalAgent};
+use justact_core::agreements::{Agreement, Agreements};
+use justact_core::auxillary::Identifiable;
+use justact_core::set::LocalSet;
+use justact_core::statements::{Action, Statements};
+use justact_core::times::{TimeRange, Times, Timestamp};
+use log::{debug, warn};
+
+use crate::statements::{Message, Target};
+
+
+/***** TESTS *****/
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use console::Style;
+    use justact_core::auditors::{Auditor, AuditorExt, Based, Included, Paper, Stated, Timely, Unequivocal, Valid};
+    use justact_core::auxillary::Authored;
+    use justact_core::policy::{Extractor, Policy};
+    use justact_core::statements::Message as JAMessage;
+
+    use super::*;
+    use crate::events::{Event, MemorySink, Violation};
+    use crate::Simulation;
+
+
+    /// A policy that is always valid, such that only the properties under attack fail.
+    struct Lenient;
+    impl Policy for Lenient {
+        type SemanticError = Infallible;
+
+        #[inline]
+        fn assert_validity(&self) -> Result<(), Self::SemanticError> { Ok(()) }
+    }
+
+    /// The [`Extractor`] for the [`Lenient`] policy.
+    struct LenientExtractor;
+    impl<M> Extractor<M> for LenientExtractor {
+        type Policy<'v> = Lenient where Self: 'v;
+        type SyntaxError<'v> = Infallible where Self: 'v;
+
+        #[inline]
+        fn extract<'v, R>(_set: &LocalSet<M, R>) -> Result<Self::Policy<'v>, Self::SyntaxError<'v>>
+        where
+            Self: Sized,
+            M: Authored + Identifiable + JAMessage<'v>,
+        {
+            Ok(Lenient)
+        }
+    }
+
+    /// The agents in a scenario: an honest consortium setting the stage, and the adversary.
+    #[derive(Debug)]
+    enum Party {
+        /// Agrees on `s1` (applying now) and `s2` (applying from time 1), and states `s3`.
+        Consortium,
+        /// The adversary under test.
+        Byzantine(Byzantine),
+    }
+    impl Identifiable for Party {
+        type Id = str;
+
+        #[inline]
+        fn id(&self) -> &Self::Id {
+            match self {
+                Self::Consortium => "consortium",
+                Self::Byzantine(agent) => agent.id(),
+            }
+        }
+    }
+    impl Agent for Party {}
+    impl RationalAgent for Party {
+        type Message = Message;
+        type Target = Target;
+        type Error = Infallible;
+
+        fn poll(
+            &mut self,
+            mut agrs: impl Agreements<Message = Self::Message>,
+            times: impl Times,
+            mut stmts: impl Statements<Message = Self::Message, Target = Self::Target>,
+        ) -> Result<AgentPoll<Self, Self::Message>, Self::Error> {
+            match self {
+                Self::Consortium => {
+                    let msg = |id: &str| -> Message { Message { id: id.into(), author: "consortium".into(), payload: vec![], lang: None } };
+                    agrs.agree(Agreement { msg: msg("s1"), applies: TimeRange::starting(times.current()) }).unwrap();
+                    agrs.agree(Agreement { msg: msg("s2"), applies: TimeRange::starting(Timestamp(1)) }).unwrap();
+                    assert!(state(&mut stmts, msg("s3")));
+                    Ok(AgentPoll::Dead)
+                },
+                Self::Byzantine(agent) => Ok(agent.poll(agrs, times, stmts)?.map_agents(Self::Byzantine)),
+            }
+        }
+    }
+
+    /// Runs a scenario with the given attack until the adversary is done.
+    ///
+    /// # Arguments
+    /// - `attack`: The [`Attack`] carried out by an adversary called `mallory`.
+    /// - `limit`: The rate limit of the simulation, if any.
+    /// - `auditor`: The [`Auditor`] (stack) used to audit the actions enacted.
+    ///
+    /// # Returns
+    /// The [`Event`]s that happened.
+    fn scenario(attack: Attack, limit: Option<usize>, auditor: impl Auditor<Message>) -> Vec<Event> {
+        let sink: MemorySink = MemorySink::new();
+        let mut sim: Simulation<Party> = Simulation::new("consortium");
+        sim.add_sink(sink.clone());
+        sim.set_quiet(true);
+        sim.set_rate_limit(limit);
+        sim.register(Party::Consortium, Style::new());
+        sim.register(Party::Byzantine(Byzantine::new("mallory", attack)), Style::new());
+        sim.run::<LenientExtractor>(auditor).unwrap();
+        sink.take()
+    }
+
+    /// Collects the violations of all failed audits in a scenario.
+    ///
+    /// # Arguments
+    /// - `events`: The [`Event`]s that happened in the scenario.
+    ///
+    /// # Returns
+    /// The identifier of every action that failed its audit, together with its [`Violation`]s.
+    fn violations(events: &[Event]) -> Vec<(&str, &[Violation])> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                Event::AuditFailed { act, violations, .. } => Some((act.id(), violations.as_slice())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Asserts that an attack is caught by one auditor on its own, and not by the others.
+    ///
+    /// # Arguments
+    /// - `attack`: The [`Attack`] carried out by an adversary called `mallory`.
+    /// - `by`: The [`Auditor`] that should catch it.
+    /// - `rest`: The [`Auditor`]s of all the other properties from the paper, which should not.
+    /// - `expected`: The [`Violation`]s that `by` should report for `mallory`'s action.
+    fn assert_caught_by(attack: Attack, by: impl Auditor<Message>, rest: impl Auditor<Message>, expected: &[Violation]) {
+        let events: Vec<Event> = scenario(attack.clone(), None, by);
+        assert_eq!(violations(&events), vec![("mallory-enacts", expected)]);
+        let events: Vec<Event> = scenario(attack, None, rest);
+        assert!(violations(&events).is_empty(), "other properties caught {:?}", violations(&events));
+    }
+
+
+    #[test]
+    fn test_byzantine_forge_author() {
+        let attack = Attack::ForgeAuthor { victim: "consortium".into() };
+        let events: Vec<Event> = scenario(attack.clone(), None, Paper);
+
+        // Stating or enacting the forged message directly is refused...
+        assert!(!events.iter().any(|event| matches!(event, Event::Stated { msg, .. } if msg.id == "mallory-forged")));
+        assert!(!events.iter().any(|event| matches!(event, Event::Enacted { act, .. } if act.id() == "mallory-forged")));
+        // ...and sneaking it into a justification is caught by property 3
+        let expected: [Violation; 1] = [Violation::Stated { stmt: "mallory-forged".into() }];
+        assert_eq!(violations(&events), vec![("mallory-enacts", &expected[..])]);
+        assert_caught_by(attack, Stated, Unequivocal.and(Included).and(Valid).and(Based).and(Timely), &expected);
+    }
+
+    #[test]
+    fn test_byzantine_equivocate() {
+        let attack = Attack::Equivocate { id: "s3".into() };
+        let events: Vec<Event> = scenario(attack.clone(), None, Paper);
+
+        // Stating another version is reported immediately...
+        assert!(events.iter().any(|event| matches!(
+            event,
+            Event::Equivocated { agent, versions, .. }
+                if agent == "mallory" && versions.iter().map(|msg| msg.author.as_str()).collect::<Vec<&str>>() == vec!["consortium", "mallory"]
+        )));
+        // ...and using it is caught by property 3
+        let expected: [Violation; 1] = [Violation::Equivocated { stmt: "s3".into(), authors: vec!["mallory".into(), "consortium".into()] }];
+        assert_eq!(violations(&events), vec![("mallory-enacts", &expected[..])]);
+        assert_caught_by(attack, Unequivocal, Stated.and(Included).and(Valid).and(Based).and(Timely), &expected);
+    }
+
+    #[test]
+    fn test_byzantine_baseless() {
+        let events: Vec<Event> = scenario(Attack::Baseless, None, Paper);
+        let expected: [Violation; 1] = [Violation::Based { stmt: "mallory-basis".into() }];
+        assert_eq!(violations(&events), vec![("mallory-enacts", &expected[..])]);
+        assert_caught_by(Attack::Baseless, Based, Stated.and(Unequivocal).and(Included).and(Valid).and(Timely), &expected);
+    }
+
+    #[test]
+    fn test_byzantine_anachronism() {
+        let events: Vec<Event> = scenario(Attack::Anachronism, None, Paper);
+        let expected: [Violation; 1] = [Violation::Timely { stmt: "s2".into(), applies: TimeRange::starting(Timestamp(1)), taken_at: Timestamp(0) }];
+        assert_eq!(violations(&events), vec![("mallory-enacts", &expected[..])]);
+        assert_caught_by(Attack::Anachronism, Timely, Stated.and(Unequivocal).and(Included).and(Valid).and(Based), &expected);
+    }
+
+    #[test]
+    fn test_byzantine_flood() {
+        let stated = |events: &[Event]| -> usize {
+            events.iter().filter(|event| matches!(event, Event::Stated { agent, .. } if agent == "mallory")).count()
+        };
+
+        // Without a rate limit, anything goes
+        let events: Vec<Event> = scenario(Attack::Flood { per_round: 5, rounds: 2 }, None, Paper);
+        assert!(!events.iter().any(|event| matches!(event, Event::Flooded { .. })));
+        assert_eq!(stated(&events), 10);
+
+        // With one, every round is reported and cut off
+        let events: Vec<Event> = scenario(Attack::Flood { per_round: 5, rounds: 2 }, Some(2), Paper);
+        let flooded: Vec<(usize, usize, usize)> = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Flooded { round, agent, stated, limit } if agent == "mallory" => Some((*round, *stated, *limit)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(flooded, vec![(1, 5, 2), (2, 5, 2)]);
+        assert_eq!(stated(&events), 4);
+
+        // Neither is an audit problem, as nothing is enacted
+        assert!(violations(&events).is_empty());
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// States a message to all agents, and checks whether it was accepted.
+///
+/// The [`Statements::Status`] is opaque to agents, so instead this checks whether the message
+/// shows up in the agent's own view afterwards.
+///
+/// # Arguments
+/// - `stmts`: The [`Statements`] to state it in.
+/// - `msg`: The [`Message`] to state.
+///
+/// # Returns
+/// True if the message was accepted, or false if it was refused.
+fn state(stmts: &mut impl Statements<Message = Message, Target = Target>, msg: Message) -> bool {
+    stmts.state(Target::All, msg.clone());
+    stmts.stated().iter().any(|stated| **stated == msg) || stmts.equivocated().contains(&&msg)
+}
+
+/// Enacts an action to all agents, and checks whether it was accepted.
+///
+/// Like [`state()`], this checks the agent's own view afterwards.
+///
+/// # Arguments
+/// - `stmts`: The [`Statements`] to enact it in.
+/// - `act`: The [`Action`] to enact.
+///
+/// # Returns
+/// True if the action was accepted, or false if it was refused.
+fn enact(stmts: &mut impl Statements<Message = Message, Target = Target>, act: Action<Message>) -> bool {
+    let enacts: Message = act.enacts.clone();
+    stmts.enact(Target::All, act);
+    stmts.enacted().iter().any(|enacted| enacted.enacts == enacts)
+}
+
+
+
+
+
+/***** AUXILLARY *****/
+/// The misbehaviour of a [`Byzantine`] agent.
+///
+/// Attacks that enact something need an agreement to cite. Unless noted otherwise, the agent waits
+/// (i.e., stays alive without doing anything) until an agreement applies at the current time, such
+/// that its action only violates the property under attack.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Attack {
+    /// Attempts to state and enact a message (`<id>-forged`) authored by the given victim, which
+    /// agents' [`Statements`](crate::statements::Statements) refuse. Then it uses the forged
+    /// message in the justification of an action of its own instead.
+    ///
+    /// Caught by [`Stated`](justact_core::auditors::Stated) (property 3), as the forged message is
+    /// never stated.
+    ForgeAuthor { victim: String },
+    /// Waits until another agent stated the message with the given identifier, and then states
+    /// its own version of it and uses that in the justification of an action.
+    ///
+    /// Reported as an [`Event::Equivocated`](crate::events::Event::Equivocated) when stated, and
+    /// caught by [`Unequivocal`](justact_core::auditors::Unequivocal) (property 3) when used.
+    Equivocate { id: String },
+    /// Enacts an action of which the basis is a message it stated itself (`<id>-basis`) instead of
+    /// an agreement.
+    ///
+    /// Caught by [`Based`](justact_core::auditors::Based) (property 6).
+    Baseless,
+    /// Waits until some agreement is known that does _not_ apply at the current time, and then
+    /// enacts an action that cites it anyway.
+    ///
+    /// Caught by [`Timely`](justact_core::auditors::Timely) (property 6).
+    Anachronism,
+    /// States `per_round` messages to all agents in each of the first `rounds` rounds it is polled.
+    ///
+    /// This is not a problem for the audit. Instead, it is reported as an
+    /// [`Event::Flooded`](crate::events::Event::Flooded) if the simulation has a rate limit (see
+    /// [`Simulation::set_rate_limit()`](crate::Simulation::set_rate_limit())).
+    Flood { per_round: usize, rounds: usize },
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// An agent that carries out an [`Attack`] on the system.
+///
+/// The messages it states are authored by itself and have an empty payload, unless noted
+/// otherwise by the attack. Those it enacts are identified as `<id>-enacts`, and any action it
+/// takes claims to be taken at the current time. Once its attack is carried out, the agent dies.
+///
+/// To use it in a [`Simulation`](crate::Simulation) with other agents, wrap it in some sum agent
+/// type (see [`AgentPoll::map_agents()`]).
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Byzantine {
+    /// The identifier of this agent.
+    id:     String,
+    /// The attack it carries out.
+    attack: Attack,
+    /// The number of times it has been polled.
+    polled: usize,
+}
+impl Byzantine {
+    /// Constructor for the Byzantine agent.
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the agent.
+    /// - `attack`: The [`Attack`] it carries out.
+    ///
+    /// # Returns
+    /// A new Byzantine agent.
+    #[inline]
+    pub fn new(id: impl Into<String>, attack: Attack) -> Self { Self { id: id.into(), attack, polled: 0 } }
+
+    /// Returns the attack this agent carries out.
+    ///
+    /// # Returns
+    /// A reference to its [`Attack`].
+    #[inline]
+    pub fn attack(&self) -> &Attack { &self.attack }
+
+    /// Creates a message authored by this agent.
+    ///
+    /// # Arguments
+    /// - `name`: Some name of the message, which is prefixed with the agent's identifier.
+    ///
+    /// # Returns
+    /// A new [`Message`] with an empty payload.
+    #[inline]
+    fn message(&self, name: &str) -> Message { Message { id: format!("{}-{}", self.id, name), author: self.id.clone(), payload: vec![], lang: None } }
+
+    /// States a message to all agents, and logs whether that was refused as expected.
+    ///
+    /// # Arguments
+    /// - `stmts`: The [`Statements`] to state it in.
+    /// - `msg`: The [`Message`] to state.
+    /// - `expected`: Whether the message should be accepted.
+    fn state(&self, stmts: &mut impl Statements<Message = Message, Target = Target>, msg: Message, expected: bool) {
+        let id: String = msg.id.clone();
+        self.report("stating", &id, state(stmts, msg), expected);
+    }
+
+    /// Enacts an action to all agents, and logs whether that was refused as expected.
+    ///
+    /// # Arguments
+    /// - `stmts`: The [`Statements`] to enact it in.
+    /// - `act`: The [`Action`] to enact.
+    /// - `expected`: Whether the action should be accepted.
+    fn enact(&self, stmts: &mut impl Statements<Message = Message, Target = Target>, act: Action<Message>, expected: bool) {
+        let id: String = act.enacts.id.clone();
+        self.report("enacting", &id, enact(stmts, act), expected);
+    }
+
+    /// Logs the outcome of stating or enacting something.
+    ///
+    /// Something accepted that should be refused (or vice versa) means that the attack does not
+    /// test what it claims to, so this is a warning.
+    ///
+    /// # Arguments
+    /// - `what`: What was attempted (e.g., `"stating"`).
+    /// - `id`: The identifier of the message stated or enacted.
+    /// - `accepted`: Whether it was accepted.
+    /// - `expected`: Whether it should have been.
+    fn report(&self, what: &str, id: &str, accepted: bool, expected: bool) {
+        let outcome: &str = if accepted { "accepted" } else { "refused" };
+        if accepted == expected {
+            debug!("Byzantine agent {:?} {} {:?} was {}", self.id, what, id, outcome);
+        } else {
+            warn!("Byzantine agent {:?} {} {:?} was unexpectedly {}", self.id, what, id, outcome);
+        }
+    }
+}
+impl Identifiable for Byzantine {
+    type Id = str;
+
+    #[inline]
+    fn id(&self) -> &Self::Id { &self.id }
+}
+impl Agent for Byzantine {}
+impl RationalAgent for Byzantine {
+    type Message = Message;
+    type Target = Target;
+    type Error = Infallible;
+
+    fn poll(
+        &mut self,
+        agrs: impl Agreements<Message = Self::Message>,
+        times: impl Times,
+        mut stmts: impl Statements<Message = Self::Message, Target = Self::Target>,
+    ) -> Result<AgentPoll<Self, Self::Message>, Self::Error> {
+        self.polled += 1;
+        let now: Timestamp = times.current();
+        let current: Option<Agreement<Message>> = agrs.agreed().iter().find(|agr| agr.applies_at(now)).map(|agr| (*agr).clone());
+        match &self.attack {
+            Attack::ForgeAuthor { victim } => {
+                let Some(basis) = current else { return Ok(AgentPoll::Alive) };
+                let forged: Message = Message { id: format!("{}-forged", self.id), author: victim.clone(), payload: vec![], lang: None };
+
+                // These are refused...
+                self.state(&mut stmts, forged.clone(), false);
+                self.enact(&mut stmts, Action { basis: basis.clone(), just: LocalSet::new(), enacts: forged.clone(), timestamp: now }, false);
+
+                // ...so slip it in somewhere else
+                let enacts: Message = self.message("enacts");
+                self.state(&mut stmts, enacts.clone(), true);
+                self.enact(&mut stmts, Action { basis, just: LocalSet::from([forged]), enacts, timestamp: now }, true);
+            },
+            Attack::Equivocate { id } => {
+                let Some(basis) = current else { return Ok(AgentPoll::Alive) };
+                let Some(original) = stmts.stated().iter().find(|msg| msg.id == *id && msg.author != self.id).map(|msg| (*msg).clone()) else {
+                    return Ok(AgentPoll::Alive);
+                };
+
+                // State our own version of it and use that
+                let version: Message = Message { id: id.clone(), author: self.id.clone(), payload: original.payload, lang: original.lang };
+                let enacts: Message = self.message("enacts");
+                self.state(&mut stmts, version.clone(), true);
+                self.state(&mut stmts, enacts.clone(), true);
+                self.enact(&mut stmts, Action { basis, just: LocalSet::from([version]), enacts, timestamp: now }, true);
+            },
+            Attack::Baseless => {
+                let basis: Message = self.message("basis");
+                let enacts: Message = self.message("enacts");
+                self.state(&mut stmts, basis.clone(), true);
+                self.state(&mut stmts, enacts.clone(), true);
+                self.enact(
+                    &mut stmts,
+                    Action { basis: Agreement { msg: basis, applies: TimeRange::starting(now) }, just: LocalSet::new(), enacts, timestamp: now },
+                    true,
+                );
+            },
+            Attack::Anachronism => {
+                let Some(basis) = agrs.agreed().iter().find(|agr| !agr.applies_at(now)).map(|agr| (*agr).clone()) else {
+                    return Ok(AgentPoll::Alive);
+                };
+                let enacts: Message = self.message("enacts");
+                self.state(&mut stmts, enacts.clone(), true);
+                self.enact(&mut stmts, Action { basis, just: LocalSet::new(), enacts, timestamp: now }, true);
+            },
+            Attack::Flood { per_round, rounds } => {
+                for i in 0..*per_round {
+                    self.state(&mut stmts, self.message(&format!("flood-{}-{i}", self.polled)), true);
+                }
+                if self.polled < *rounds {
+                    return Ok(AgentPoll::Alive);
+                }
+            },
+        }
+        Ok(AgentPoll::Dead)
+    }
+}
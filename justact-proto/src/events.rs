@@ -0,0 +1,618 @@
+//  EVENTS.rs
+//    by Lut99
+//
+//  Created:
+//    16 Oct 2026, 20:03:29
+//  Last edited:
+//    16 Oct 2026, 22:22:15
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Defines the events that happen in a [`Simulation`](crate::Simulation),
+//!   and the sinks that receive them (e.g., to print them to the terminal).
+//
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::io;
+use std::rc::Rc;
+
+use console::{style, Style};
+use justact_core::agreements::Agreement;
+use justact_core::auxillary::{Authored as _, Identifiable as _};
+use justact_core::statements::{Action, AuditExplanation, Justification};
+use justact_core::times::{TimeRange, Timestamp};
+use serde::{Deserialize, Serialize};
+
+use crate::agreements::{Outcome, Quorum};
+use crate::interface::Displayable as _;
+use crate::network::{DropReason, NetworkConfig};
+use crate::simulation::FailurePolicy;
+use crate::statements::{Message, Target};
+
+
+/***** TESTS *****/
+#[cfg(test)]
+mod tests {
+    use console::Style;
+    use justact_core::times::Timestamp;
+    use serde_json::Value;
+
+    use super::*;
+    use crate::mock::{MockAgent, MockStep};
+    use crate::Simulation;
+
+
+    /// Summarizes an event by its kind, round and agent.
+    ///
+    /// # Arguments
+    /// - `event`: The [`Event`] to summarize.
+    ///
+    /// # Returns
+    /// The name of the event as serialized, and its `round` and `agent` fields (if any).
+    fn summarize(event: &Event) -> (String, Option<u64>, Option<String>) {
+        let Value::Object(event) = serde_json::to_value(event).unwrap() else { panic!("Event is not serialized as an object") };
+        let (kind, fields): (&String, &Value) = event.iter().next().unwrap();
+        (kind.clone(), fields["round"].as_u64(), fields["agent"].as_str().map(String::from))
+    }
+
+
+    #[test]
+    fn test_events_memory_sink() {
+        let mut sim: Simulation<MockAgent> = Simulation::new("a");
+        sim.set_quiet(true);
+        sim.set_failure_policy("b", FailurePolicy::Kill);
+        let sink: MemorySink = MemorySink::new();
+        sim.add_sink(sink.clone());
+        sim.register(MockAgent::new("a", [MockStep::State(vec!["a1".into()]), MockStep::Agree("a1".into()), MockStep::Advance(Timestamp(1))]), Style::new());
+        sim.register(MockAgent::new("b", [MockStep::State(vec!["b1".into()]), MockStep::Fail]), Style::new());
+        while sim.poll().unwrap().alive {}
+
+        let event = |kind: &str, round: Option<u64>, agent: Option<&str>| (kind.to_string(), round, agent.map(String::from));
+        assert_eq!(sink.events().iter().map(summarize).collect::<Vec<_>>(), vec![
+            event("started", None, None),
+            event("registered", Some(0), Some("a")),
+            event("registered", Some(0), Some("b")),
+            event("stated", Some(1), Some("a")),
+            event("stated", Some(1), Some("b")),
+            event("finished", Some(1), None),
+            event("agreed", Some(2), Some("a")),
+            event("agent_failed", Some(2), Some("b")),
+            event("finished", Some(2), None),
+            event("time_advanced", Some(3), Some("a")),
+            event("finished", Some(3), None),
+            event("finished", Some(4), None),
+        ]);
+    }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Writes the identifiers of the messages in an action's justification as a comma-separated list.
+///
+/// # Arguments
+/// - `just`: The justification of the action.
+///
+/// # Returns
+/// A [`String`] with the identifiers.
+fn just_ids(just: Justification<Message>) -> String {
+    let mut just_ids: String = String::new();
+    for msg in just.iter() {
+        if !just_ids.is_empty() {
+            just_ids.push_str(", ");
+        }
+        just_ids.push_str(msg.id());
+    }
+    just_ids
+}
+
+
+
+
+
+/***** AUXILLARY *****/
+/// How agreements or times are decided in a [`Simulation`](crate::Simulation).
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Decision {
+    /// The dictator decides.
+    Dictator,
+    /// Agents vote, and a proposal is accepted once the `quorum` votes in favour of it. Proposals
+    /// expire `timeout` rounds after the one in which they were made, or never if there is none
+    /// (as for times).
+    Consensus { quorum: Quorum, timeout: Option<usize> },
+}
+
+/// An owned version of an [`AuditExplanation`], i.e., a property that an action violates.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Violation {
+    /// One of the messages in the action was not stated (property 3).
+    Stated { stmt: String },
+    /// Different versions of one of the messages in the action were stated (property 3). The
+    /// authors of every version are listed, starting with the one used in the action.
+    Equivocated { stmt: String, authors: Vec<String> },
+    /// The basis or the enactment was not included as-is in the justification (property 4).
+    Included { stmt: String },
+    /// Failed to extract the policy from the justification (property 5).
+    Extract { err: String },
+    /// The policy was not valid (property 5).
+    Valid { expl: String },
+    /// The basis was not an agreement (property 6).
+    Based { stmt: String },
+    /// The basis was an agreement but not one for the action's taken time (property 6).
+    Timely { stmt: String, applies: TimeRange, taken_at: Timestamp },
+    /// A custom property did not hold.
+    Custom { property: String, stmts: Vec<String>, reason: String },
+}
+impl<SYN: Display, SEM: Display> From<AuditExplanation<&str, SYN, SEM>> for Violation {
+    #[inline]
+    fn from(value: AuditExplanation<&str, SYN, SEM>) -> Self {
+        match value {
+            AuditExplanation::Stated { stmt } => Self::Stated { stmt: stmt.into() },
+            AuditExplanation::Equivocated { stmt, authors } => {
+                Self::Equivocated { stmt: stmt.into(), authors: authors.into_iter().map(String::from).collect() }
+            },
+            AuditExplanation::Included { stmt } => Self::Included { stmt: stmt.into() },
+            AuditExplanation::Extract { err } => Self::Extract { err: err.to_string() },
+            AuditExplanation::Valid { expl } => Self::Valid { expl: expl.to_string() },
+            AuditExplanation::Based { stmt } => Self::Based { stmt: stmt.into() },
+            AuditExplanation::Timely { stmt, applies, taken_at } => Self::Timely { stmt: stmt.into(), applies, taken_at },
+            AuditExplanation::Custom { property, stmts, reason } => {
+                Self::Custom { property: property.into(), stmts: stmts.into_iter().map(String::from).collect(), reason }
+            },
+        }
+    }
+}
+
+
+
+
+
+/***** INTERFACES *****/
+/// Receives the [`Event`]s that happen in a [`Simulation`](crate::Simulation).
+///
+/// Sinks are added with [`Simulation::add_sink()`](crate::Simulation::add_sink()).
+pub trait EventSink {
+    /// Handles a single event.
+    ///
+    /// # Arguments
+    /// - `event`: The [`Event`] that happened.
+    fn handle(&mut self, event: &Event);
+
+    /// Flushes any events handled so far.
+    ///
+    /// This is called by the simulation at the end of every round.
+    ///
+    /// # Errors
+    /// This function errors if handling or flushing any event failed.
+    #[inline]
+    fn flush(&mut self) -> Result<(), io::Error> { Ok(()) }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Something that happened in a [`Simulation`](crate::Simulation).
+///
+/// Every event (except [`Event::Started`]) lists the round in which it happened and, unless it is
+/// an [`Event::Finished`], the agent who caused it. Events are serialized as JSON objects with a
+/// single field named after the event, e.g., `{"stated":{"round":1,...}}`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Event {
+    /// The sink was added to a simulation with the given dictator, which decides agreements and
+    /// times as given and sends statements over the given network (if any) with the given rate
+    /// limit (if any).
+    Started { dictator: String, agreements: Decision, times: Decision, network: Option<NetworkConfig>, rate_limit: Option<usize> },
+    /// An agent joined the simulation.
+    Registered { round: usize, agent: String },
+    /// An agent logged something.
+    Logged { round: usize, agent: String, msg: String },
+    /// An agent reported some error.
+    Errored { round: usize, agent: String, msg: String },
+    /// An agent stated a message.
+    Stated { round: usize, agent: String, target: Target, msg: Message },
+    /// An agent enacted an action.
+    Enacted { round: usize, agent: String, target: Target, act: Action<Message> },
+    /// An agent agreed upon a new agreement.
+    Agreed { round: usize, agent: String, agr: Agreement<Message> },
+    /// An agent revoked an agreement. It is listed as it applies after the revocation.
+    Revoked { round: usize, agent: String, agr: Agreement<Message> },
+    /// An agent proposed a new agreement (or a new version of an existing one) to vote on.
+    Proposed { round: usize, agent: String, agr: Agreement<Message> },
+    /// An agent voted in favour of (`approve` is true) or against a proposed agreement.
+    Voted { round: usize, agent: String, id: String, approve: bool },
+    /// The vote on a proposed agreement was decided, e.g., by the vote of the given agent. If
+    /// accepted, it is followed by an [`Event::Agreed`] or an [`Event::Revoked`].
+    Decided { round: usize, agent: String, id: String, outcome: Outcome },
+    /// A message stated (or, if `enacted`, the action enacting it) by an agent arrived at another
+    /// agent over the network. Agents learning of what they send themselves is not reported.
+    Delivered { round: usize, agent: String, to: String, id: String, enacted: bool },
+    /// A message stated (or, if `enacted`, the action enacting it) by an agent never arrived at
+    /// another agent.
+    Dropped { round: usize, agent: String, to: String, id: String, enacted: bool, reason: DropReason },
+    /// An agent advanced the time.
+    TimeAdvanced { round: usize, agent: String, time: Timestamp },
+    /// An agent proposed to advance the time.
+    TimeProposed { round: usize, agent: String, time: Timestamp },
+    /// An agent voted in favour of a proposed time.
+    TimeVoted { round: usize, agent: String, time: Timestamp },
+    /// The vote on a proposed time was decided, e.g., by the vote of the given agent. If
+    /// accepted, it is followed by an [`Event::TimeAdvanced`].
+    TimeDecided { round: usize, agent: String, time: Timestamp, outcome: Outcome },
+    /// Different versions of a message were stated under the same identifier.
+    Equivocated { round: usize, agent: String, versions: Vec<Message> },
+    /// An agent stated more messages in a single round than the rate limit allows. Only the
+    /// first `limit` of them were stated.
+    Flooded { round: usize, agent: String, stated: usize, limit: usize },
+    /// An agent failed to poll, and the given policy is applied to it.
+    AgentFailed { round: usize, agent: String, err: String, policy: FailurePolicy },
+    /// An action passed its audit, but has messages in its justification that play no part in
+    /// its validity. These are listed by identifier, sorted.
+    Superfluous { round: usize, agent: String, act: Action<Message>, superfluous: Vec<String> },
+    /// An action failed its audit because of the listed violations.
+    AuditFailed { round: usize, agent: String, act: Action<Message>, violations: Vec<Violation> },
+    /// A round ended.
+    Finished { round: usize },
+}
+impl Event {
+    /// Returns the round in which this event happened.
+    ///
+    /// # Returns
+    /// The round, or [`None`] if this is an [`Event::Started`].
+    #[inline]
+    pub fn round(&self) -> Option<usize> {
+        use Event::*;
+        match self {
+            Started { .. } => None,
+            Registered { round, .. }
+            | Logged { round, .. }
+            | Errored { round, .. }
+            | Stated { round, .. }
+            | Enacted { round, .. }
+            | Delivered { round, .. }
+            | Dropped { round, .. }
+            | Agreed { round, .. }
+            | Revoked { round, .. }
+            | Proposed { round, .. }
+            | Voted { round, .. }
+            | Decided { round, .. }
+            | TimeAdvanced { round, .. }
+            | TimeProposed { round, .. }
+            | TimeVoted { round, .. }
+            | TimeDecided { round, .. }
+            | Equivocated { round, .. }
+            | Flooded { round, .. }
+            | AgentFailed { round, .. }
+            | Superfluous { round, .. }
+            | AuditFailed { round, .. }
+            | Finished { round } => Some(*round),
+        }
+    }
+}
+
+
+
+/// An [`EventSink`] that writes events to stdout in pretty ways.
+#[derive(Clone, Debug, Default)]
+pub struct ConsoleSink {
+    /// The mapping of agents to their styles.
+    styles: HashMap<String, Style>,
+}
+impl ConsoleSink {
+    /// Constructor for the ConsoleSink.
+    ///
+    /// # Returns
+    /// A new ConsoleSink that writes all agents without any style.
+    #[inline]
+    pub fn new() -> Self { Self { styles: HashMap::new() } }
+
+    /// Registers the style for an agent.
+    ///
+    /// Agents without a style are written plainly.
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the agent to register.
+    /// - `style`: The style to use when formatting the agent's `id`entifier.
+    #[inline]
+    pub fn register(&mut self, id: &str, style: Style) { self.styles.insert(id.into(), style); }
+
+    /// Formats the identifier of an agent with its style.
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the agent.
+    ///
+    /// # Returns
+    /// Something [`Display`]able that writes the styled `id`.
+    #[inline]
+    fn agent<'i>(&self, id: &'i str) -> impl 'i + Display {
+        match self.styles.get(id) {
+            Some(style) => style.apply_to(id),
+            None => Style::new().apply_to(id),
+        }
+    }
+
+    /// Formats a [`Violation`] for humans.
+    ///
+    /// # Arguments
+    /// - `violation`: The [`Violation`] to format.
+    ///
+    /// # Returns
+    /// A [`String`] explaining the violation.
+    fn violation(violation: &Violation) -> String {
+        match violation {
+            Violation::Stated { stmt } => format!("Message '{}' is not stated", style(stmt).bold()),
+            Violation::Equivocated { stmt, authors } => {
+                let others: Vec<String> = authors.iter().skip(1).map(|author| format!("'{}'", style(author).bold())).collect();
+                format!(
+                    "Message '{}' is equivocated; the action uses the version by '{}', but other versions by {} are stated",
+                    style(stmt).bold(),
+                    style(authors.first().map(String::as_str).unwrap_or("")).bold(),
+                    others.join(", ")
+                )
+            },
+            Violation::Included { stmt } => {
+                format!("Message '{}' is not included as-is in the justification (another message shadows it)", style(stmt).bold())
+            },
+            Violation::Extract { .. } => "Cannot extract policy".into(),
+            Violation::Valid { .. } => "Extracted policy is not valid".into(),
+            Violation::Based { stmt } => format!("Message '{}' is not in the set of agreements", style(stmt).bold()),
+            Violation::Timely { stmt, applies, taken_at } => format!(
+                "Message '{}' is an agreement valid for times {}, but the action was taken at time {}",
+                style(stmt).bold(),
+                applies,
+                taken_at
+            ),
+            Violation::Custom { property, stmts, reason } => {
+                let stmts: Vec<String> = stmts.iter().map(|stmt| format!("'{}'", style(stmt).bold())).collect();
+                format!("Property '{}' does not hold for message(s) {}: {}", style(property).bold(), stmts.join(", "), reason)
+            },
+        }
+    }
+}
+impl EventSink for ConsoleSink {
+    fn handle(&mut self, event: &Event) {
+        match event {
+            Event::Started { .. } | Event::Registered { .. } | Event::Finished { .. } => {},
+            Event::Logged { agent, msg, .. } => println!("{}{}{} {}\n", style("[INFO] [").bold(), self.agent(agent), style("]").bold(), msg),
+            Event::Errored { agent, msg, .. } => println!(
+                "{}{}{}{}{} {}\n",
+                style("[").bold(),
+                style("ERROR").bold().red(),
+                style("] [").bold(),
+                self.agent(agent),
+                style("]").bold(),
+                msg
+            ),
+
+            Event::Stated { agent, msg, .. } => {
+                println!("{}{}{} Emitted message '{}'", style("[INFO] [").bold(), self.agent(agent), style("]").bold(), msg.id());
+                println!(" └> {}", msg.display("Message", "    "));
+                println!();
+            },
+            Event::Enacted { agent, act, .. } => {
+                let just: Justification<Message> = act.justification();
+                println!(
+                    "{}{}{} Enacted message '{}' using '{}' (basis '{}')",
+                    style("[INFO] [").bold(),
+                    self.agent(agent),
+                    style("]").bold(),
+                    act.enacts().id(),
+                    just_ids(just),
+                    act.basis().id(),
+                );
+                print!(" ├> {}", act.basis().display("Basis", " |  "));
+                print!(" ├> {}", just.display("Justification", " |  "));
+                print!(" └> {}", act.enacts().display("Enacts", "    "));
+                println!();
+            },
+
+            Event::Delivered { agent, to, id, .. } => {
+                println!(
+                    "{}{}{} Message '{}' arrived at '{}'",
+                    style("[INFO] [").bold(),
+                    self.agent(agent),
+                    style("]").bold(),
+                    style(id).bold(),
+                    self.agent(to)
+                );
+                println!();
+            },
+            Event::Dropped { agent, to, id, reason, .. } => {
+                println!(
+                    "{}{}{}{}{} Message '{}' never arrived at '{}' ({})",
+                    style("[").bold(),
+                    style("WARN").bold().yellow(),
+                    style("] [").bold(),
+                    self.agent(agent),
+                    style("]").bold(),
+                    style(id).bold(),
+                    self.agent(to),
+                    reason
+                );
+                println!();
+            },
+
+            Event::Agreed { agr, .. } => {
+                println!("{}{}{} New agreement '{}' created", style("[INFO] [").bold(), self.agent("<system>"), style("]").bold(), style(agr.id()).bold());
+                println!(" └> {}", agr.display("Agreement", "    "));
+                println!();
+            },
+            Event::Revoked { agr, .. } => {
+                println!(
+                    "{}{}{} Agreement '{}' revoked; it now applies {}",
+                    style("[INFO] [").bold(),
+                    self.agent("<system>"),
+                    style("]").bold(),
+                    style(agr.id()).bold(),
+                    style(agr.applies).bold()
+                );
+                println!();
+            },
+            Event::Proposed { agent, agr, .. } => {
+                println!("{}{}{} Proposed agreement '{}'", style("[INFO] [").bold(), self.agent(agent), style("]").bold(), style(agr.id()).bold());
+                println!(" └> {}", agr.display("Agreement", "    "));
+                println!();
+            },
+            Event::Voted { agent, id, approve, .. } => {
+                println!(
+                    "{}{}{} Voted {} agreement '{}'",
+                    style("[INFO] [").bold(),
+                    self.agent(agent),
+                    style("]").bold(),
+                    if *approve { "in favour of" } else { "against" },
+                    style(id).bold()
+                );
+                println!();
+            },
+            Event::Decided { id, outcome, .. } => {
+                println!("{}{}{} Proposed agreement '{}' {}", style("[INFO] [").bold(), self.agent("<system>"), style("]").bold(), style(id).bold(), style(outcome).bold());
+                println!();
+            },
+            Event::TimeAdvanced { time, .. } => {
+                println!("{}{}{} Time advanced to {}", style("[INFO] [").bold(), self.agent("<system>"), style("]").bold(), style(time).bold());
+                println!();
+            },
+            Event::TimeProposed { agent, time, .. } => {
+                println!("{}{}{} Proposed to advance the time to {}", style("[INFO] [").bold(), self.agent(agent), style("]").bold(), style(time).bold());
+                println!();
+            },
+            Event::TimeVoted { agent, time, .. } => {
+                println!("{}{}{} Voted in favour of time {}", style("[INFO] [").bold(), self.agent(agent), style("]").bold(), style(time).bold());
+                println!();
+            },
+            Event::TimeDecided { time, outcome, .. } => {
+                println!("{}{}{} Proposed time {} {}", style("[INFO] [").bold(), self.agent("<system>"), style("]").bold(), style(time).bold(), style(outcome).bold());
+                println!();
+            },
+
+            Event::Equivocated { agent, versions, .. } => {
+                let authors: Vec<String> = versions.iter().map(|msg| format!("'{}'", style(msg.author()).bold())).collect();
+                println!(
+                    "{}{}{}{}{} Message '{}' is equivocated; different versions were stated by {}",
+                    style("[").bold(),
+                    style("ERROR").bold().red(),
+                    style("] [").bold(),
+                    self.agent(agent),
+                    style("]").bold(),
+                    versions.first().map(|msg| msg.id()).unwrap_or(""),
+                    authors.join(", "),
+                );
+                for (i, msg) in versions.iter().enumerate() {
+                    if i < versions.len() - 1 {
+                        print!(" ├> {}", msg.display("Version", " |  "));
+                    } else {
+                        print!(" └> {}", msg.display("Version", "    "));
+                    }
+                }
+                println!();
+            },
+            Event::Flooded { agent, stated, limit, .. } => {
+                println!(
+                    "{}{}{}{}{} Agent '{}' stated {} messages in one round, but only {} are allowed; the rest are dropped",
+                    style("[").bold(),
+                    style("ERROR").bold().red(),
+                    style("] [").bold(),
+                    self.agent("<system>"),
+                    style("]").bold(),
+                    style(agent).bold(),
+                    style(stated).bold(),
+                    style(limit).bold(),
+                );
+                println!();
+            },
+            Event::AgentFailed { agent, err, policy, .. } => {
+                println!(
+                    "{}{}{}{}{} Agent failed: {} ({})",
+                    style("[").bold(),
+                    style("ERROR").bold().red(),
+                    style("] [").bold(),
+                    self.agent(agent),
+                    style("]").bold(),
+                    err,
+                    policy,
+                );
+                println!();
+            },
+            Event::Superfluous { agent, act, superfluous, .. } => {
+                let ids: Vec<String> = superfluous.iter().map(|id| format!("'{}'", style(id).bold())).collect();
+                println!(
+                    "{}{}{}{}{} Action that enacts '{}' has superfluous message(s) {} in its justification",
+                    style("[").bold(),
+                    style("WARN").bold().yellow(),
+                    style("] [").bold(),
+                    self.agent(agent),
+                    style("]").bold(),
+                    act.enacts().id(),
+                    ids.join(", "),
+                );
+                println!();
+            },
+            Event::AuditFailed { agent, act, violations, .. } => {
+                println!(
+                    "{}{}{}{}{} Action that enacts '{}' did not succeed audit",
+                    style("[").bold(),
+                    style("ERROR").bold().red(),
+                    style("] [").bold(),
+                    self.agent(agent),
+                    style("]").bold(),
+                    act.enacts().id(),
+                );
+                let just: Justification<Message> = act.justification();
+                print!(" ├> {}", act.basis().display("Basis", " |  "));
+                print!(" ├> {}", just.display("Justification", " |  "));
+                print!(" ├> {}", act.enacts().display("Enacts", " |  "));
+                for (i, violation) in violations.iter().enumerate() {
+                    println!(" {}> {}", if i < violations.len() - 1 { '├' } else { '└' }, Self::violation(violation).trim_end());
+                }
+                println!();
+            },
+        }
+    }
+}
+
+
+
+/// An [`EventSink`] that collects all events in memory, e.g., to inspect them in tests.
+///
+/// Clones of a MemorySink share the same events, such that one clone can be given to the
+/// simulation while another is kept to inspect them.
+#[derive(Clone, Debug, Default)]
+pub struct MemorySink {
+    /// The events collected so far.
+    events: Rc<RefCell<Vec<Event>>>,
+}
+impl MemorySink {
+    /// Constructor for the MemorySink.
+    ///
+    /// # Returns
+    /// A new MemorySink without any events.
+    #[inline]
+    pub fn new() -> Self { Self { events: Rc::new(RefCell::new(Vec::new())) } }
+
+    /// Returns the events collected so far.
+    ///
+    /// # Returns
+    /// A copy of the [`Event`]s, in the order in which they happened.
+    #[inline]
+    pub fn events(&self) -> Vec<Event> { self.events.borrow().clone() }
+
+    /// Removes all events collected so far.
+    ///
+    /// # Returns
+    /// The removed [`Event`]s, in the order in which they happened.
+    #[inline]
+    pub fn take(&self) -> Vec<Event> { std::mem::take(&mut *self.events.borrow_mut()) }
+}
+impl EventSink for MemorySink {
+    #[inline]
+    fn handle(&mut self, event: &Event) { self.events.borrow_mut().push(event.clone()); }
+}
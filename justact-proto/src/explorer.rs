@@ -0,0 +1,585 @@
+//  EXPLORER.rs
+//    by Lut99
+//
+//  Created:
+//    16 Oct 2026, 19:51:12
+//  Last edited:
+//    16 Oct 2026, 22:13:15
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements a model checker that explores every order in which the
+//!   agents of a (small) [`Simulation`] can be polled.
+//
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashSet, VecDeque};
+use std::fmt::{Display, Formatter, Result as FResult};
+use std::hash::{Hash, Hasher};
+
+use justact_core::agents::RationalAgent;
+use justact_core::agreements::Agreement;
+use justact_core::auditors::Auditor;
+use justact_core::auxillary::Identifiable;
+use justact_core::policy::Extractor;
+use justact_core::statements::Action;
+use log::{debug, info};
+
+use crate::simulation::{Changes, Error, Polled, Round, Simulation};
+use crate::statements::{Message, Target};
+
+
+/***** TESTS *****/
+#[cfg(test)]
+mod tests {
+    use console::Style;
+    use justact_core::auditors::Paper;
+
+    use super::*;
+    use crate::mock::{MockAgent, MockExtractor, MockFailure, MockStep};
+
+
+    /// Creates a quiet [`Simulation`] with agents `b` and `a` registered, in that order.
+    ///
+    /// The dictator `a` agrees on `rules` and then states `error`, which `b` uses to enact
+    /// `b-act`. Because `b` is polled first, the smallest schedule that makes `b-act` fail its
+    /// audit needs a different order in the second round.
+    ///
+    /// # Arguments
+    /// - `first`: The first step of `b`.
+    ///
+    /// # Returns
+    /// A new [`Simulation`].
+    fn simulation(first: MockStep) -> Simulation<MockAgent> {
+        let mut sim: Simulation<MockAgent> = Simulation::new("a");
+        sim.set_quiet(true);
+        sim.register(MockAgent::new("b", [first, MockStep::Enact { id: "b-act".into(), just: vec!["error".into()] }]), Style::new());
+        sim.register(MockAgent::new("a", [MockStep::Agree("rules".into()), MockStep::State(vec!["error".into()])]), Style::new());
+        sim
+    }
+
+    /// Shorthand for creating a schedule.
+    ///
+    /// # Arguments
+    /// - `rounds`: The agents polled in every round.
+    ///
+    /// # Returns
+    /// The [`Step`]s taken in every round.
+    fn schedule(rounds: &[&[&str]]) -> Vec<Vec<Step>> {
+        rounds.iter().map(|agents| agents.iter().map(|agent| Step::Poll { agent: agent.to_string() }).collect()).collect()
+    }
+
+
+    #[test]
+    fn test_explorer_counterexample() {
+        let explorer: Explorer<MockAgent> = Explorer::new(simulation(MockStep::State(vec![])));
+        let report: ExploreReport<MockFailure> = explorer.explore::<MockExtractor>(Paper);
+        let counter: Counterexample = report.counterexample.unwrap();
+        assert_eq!(counter.action.enacts.id, "b-act");
+        assert_eq!(counter.schedule, schedule(&[&["b", "a"], &["a", "b"]]));
+        assert!(report.aborted.is_none());
+
+        // Both orders of the first round end in the same state
+        assert!(report.pruned >= 1);
+    }
+
+    #[test]
+    fn test_explorer_pruning() {
+        // Agents that don't interact end every round in the same state, whatever the order
+        let mut sim: Simulation<MockAgent> = Simulation::new("a");
+        sim.set_quiet(true);
+        for id in ["a", "b", "c"] {
+            sim.register(MockAgent::new(id, [MockStep::State(vec![format!("{id}1")]), MockStep::State(vec![format!("{id}2")])]), Style::new());
+        }
+        let report: ExploreReport<MockFailure> = Explorer::new(sim).explore::<MockExtractor>(Paper);
+        assert!(report.counterexample.is_none());
+        assert!(report.complete);
+
+        // In the first two rounds, polling 1, 2 or 3 of the agents leads to 3 + 3 + 1 distinct states
+        // out of the 3 + 6 + 3 reached. In the third, the agents die, so the last poll ends it.
+        assert_eq!((report.states, report.pruned), (1 + 2 * 7 + 6, 2 * 5 + 3));
+    }
+
+    #[test]
+    fn test_explorer_deliveries() {
+        // `p` and `q` only state their claim if they don't know of the other's, which is only
+        // possible if they are both polled before either is delivered. Then `r` uses both claims,
+        // and `p`'s makes its action invalid.
+        let mut sim: Simulation<MockAgent> = Simulation::new("r");
+        sim.set_quiet(true);
+        sim.register(MockAgent::new("p", [MockStep::StateUnless { id: "error".into(), unless: "claim".into() }]), Style::new());
+        sim.register(MockAgent::new("q", [MockStep::StateUnless { id: "claim".into(), unless: "error".into() }]), Style::new());
+        sim.register(
+            MockAgent::new("r", [MockStep::Agree("rules".into()), MockStep::Enact { id: "r-act".into(), just: vec!["error".into(), "claim".into()] }]),
+            Style::new(),
+        );
+
+        // Polling them in any order finds nothing...
+        let mut explorer: Explorer<MockAgent> = Explorer::new(sim);
+        explorer.set_max_rounds(3);
+        let report: ExploreReport<MockFailure> = explorer.explore::<MockExtractor>(Paper);
+        assert!(report.counterexample.is_none());
+        assert!(report.complete);
+
+        // ...but delivering their changes late does
+        explorer.set_deliveries(true);
+        let report: ExploreReport<MockFailure> = explorer.explore::<MockExtractor>(Paper);
+        let counter: Counterexample = report.counterexample.unwrap();
+        assert_eq!(counter.action.enacts.id, "r-act");
+        let round: Vec<Step> = ["p", "q", "r"]
+            .map(|agent| Step::Poll { agent: agent.into() })
+            .into_iter()
+            .chain(["p", "q", "r"].map(|agent| Step::Deliver { agent: agent.into() }))
+            .collect();
+        assert_eq!(counter.schedule, vec![round.clone(), round]);
+    }
+
+    #[test]
+    fn test_explorer_abort() {
+        // `b` aborts if it is polled after `a` in the first round, which does not stop the search
+        let explorer: Explorer<MockAgent> = Explorer::new(simulation(MockStep::FailOn("rules".into())));
+        let report: ExploreReport<MockFailure> = explorer.explore::<MockExtractor>(Paper);
+        let aborted: Aborted<MockFailure> = report.aborted.unwrap();
+        assert_eq!((aborted.agent.as_str(), aborted.schedule), ("b", schedule(&[&["a", "b"]])));
+        assert_eq!(report.aborts, 1);
+        assert_eq!(report.counterexample.unwrap().schedule, schedule(&[&["b", "a"], &["a", "b"]]));
+    }
+}
+
+
+
+
+
+/***** CONSTANTS *****/
+/// The number of rounds explored by default.
+pub const DEFAULT_MAX_ROUNDS: usize = 8;
+
+/// The number of distinct states visited by default before giving up.
+pub const DEFAULT_MAX_STATES: usize = 1 << 16;
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Hashes a collection of elements regardless of their order.
+///
+/// # Arguments
+/// - `elems`: The elements to hash.
+/// - `hash`: Some function that hashes a single element.
+/// - `state`: The [`Hasher`] to hash into.
+pub(crate) fn hash_unordered<I: IntoIterator>(elems: I, hash: impl Fn(I::Item, &mut DefaultHasher), state: &mut impl Hasher) {
+    let mut hashes: Vec<u64> = elems
+        .into_iter()
+        .map(|elem| {
+            let mut hasher: DefaultHasher = DefaultHasher::new();
+            hash(elem, &mut hasher);
+            hasher.finish()
+        })
+        .collect();
+    hashes.sort_unstable();
+    hashes.hash(state);
+}
+
+/// Hashes an [`Agreement`].
+///
+/// # Arguments
+/// - `agr`: The [`Agreement`] to hash.
+/// - `state`: The [`Hasher`] to hash into.
+#[inline]
+pub(crate) fn hash_agreement(agr: &Agreement<Message>, state: &mut impl Hasher) {
+    agr.msg.hash(state);
+    agr.applies.hash(state);
+}
+
+/// Hashes an [`Action`], regardless of the order of its justification.
+///
+/// # Arguments
+/// - `act`: The [`Action`] to hash.
+/// - `state`: The [`Hasher`] to hash into.
+#[inline]
+pub(crate) fn hash_action(act: &Action<Message>, state: &mut impl Hasher) {
+    hash_agreement(&act.basis, state);
+    hash_unordered(&act.just, |msg, state| msg.hash(state), state);
+    act.enacts.hash(state);
+    act.timestamp.hash(state);
+}
+
+/// Hashes the [`Changes`] that some agent wants to make.
+///
+/// # Arguments
+/// - `changes`: The [`Changes`] to hash.
+/// - `state`: The [`Hasher`] to hash into.
+fn hash_changes(changes: &Changes, state: &mut impl Hasher) {
+    changes.agrs.fingerprint(state);
+    changes.times.hash(state);
+    changes.stmts.hash(state);
+    state.write_usize(changes.encts.len());
+    for (target, act) in &changes.encts {
+        target.hash(state);
+        hash_action(act, state);
+    }
+}
+
+
+
+
+
+/***** AUXILLARY *****/
+/// A single step in a schedule explored by the [`Explorer`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Step {
+    /// The agent with the given identifier is polled. Unless deliveries are explored (see
+    /// [`Explorer::set_deliveries()`]), its changes are synchronized immediately.
+    Poll { agent: String },
+    /// The changes made by the agent with the given identifier are synchronized.
+    Deliver { agent: String },
+}
+impl Display for Step {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use Step::*;
+        match self {
+            Poll { agent } => write!(f, "poll '{agent}'"),
+            Deliver { agent } => write!(f, "deliver '{agent}'"),
+        }
+    }
+}
+
+/// A schedule found by the [`Explorer`] that makes an action fail its audit.
+#[derive(Clone, Debug)]
+pub struct Counterexample {
+    /// The steps taken in every round, up to and including the round after which the action
+    /// failed its audit.
+    pub schedule: Vec<Vec<Step>>,
+    /// The action that failed its audit.
+    pub action:   Action<Message>,
+}
+impl Display for Counterexample {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        writeln!(f, "Action that enacts '{}' fails its audit after:", self.action.enacts.id)?;
+        for (i, steps) in self.schedule.iter().enumerate() {
+            write!(f, " - round {}:", i + 1)?;
+            for (j, step) in steps.iter().enumerate() {
+                write!(f, "{} {step}", if j > 0 { "," } else { "" })?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// A schedule found by the [`Explorer`] in which an agent with
+/// [`FailurePolicy::Abort`](crate::simulation::FailurePolicy::Abort) fails.
+///
+/// # Generics
+/// - `E`: The type of error the agent failed with.
+#[derive(Clone, Debug)]
+pub struct Aborted<E> {
+    /// The steps taken in every round, up to and including the poll of the agent that failed.
+    pub schedule: Vec<Vec<Step>>,
+    /// The agent that failed.
+    pub agent:    String,
+    /// The error it failed with.
+    pub err:      E,
+}
+
+/// Describes the outcome of an [`Explorer::explore()`].
+///
+/// # Generics
+/// - `E`: The type of error agents may fail with.
+#[derive(Clone, Debug)]
+pub struct ExploreReport<E> {
+    /// The smallest schedule (in number of steps) that makes an action fail its audit, if any.
+    pub counterexample: Option<Counterexample>,
+    /// The smallest schedule in which an agent with
+    /// [`FailurePolicy::Abort`](crate::simulation::FailurePolicy::Abort) fails, if any. Such
+    /// schedules are not explored any further, but the others are.
+    pub aborted: Option<Aborted<E>>,
+    /// The number of schedules that were cut short because an agent aborted.
+    pub aborts: usize,
+    /// The number of distinct states visited.
+    pub states: usize,
+    /// The number of times a state was skipped because it was visited before.
+    pub pruned: usize,
+    /// Whether all schedules within the maximum number of rounds were explored. If not, the
+    /// explorer stopped after visiting the maximum number of states.
+    pub complete: bool,
+}
+
+/// A state of the explored [`Simulation`], somewhere within a round.
+struct Node<A: RationalAgent> {
+    /// The simulation itself.
+    sim:      Simulation<A>,
+    /// The agents that have yet to be polled this round.
+    todo:     Vec<A>,
+    /// What happened to the agents polled this round.
+    round:    Round<A>,
+    /// The changes that agents polled this round have yet to deliver.
+    pending:  Vec<(String, Changes)>,
+    /// The steps taken to get here, per round.
+    schedule: Vec<Vec<Step>>,
+}
+impl<A> Node<A>
+where
+    A: Clone + Hash + RationalAgent,
+{
+    /// Copies this Node, such that another step can be taken from it.
+    ///
+    /// # Returns
+    /// A new Node in the same state.
+    #[inline]
+    fn fork(&self) -> Self {
+        Self {
+            sim:      self.sim.fork(),
+            todo:     self.todo.clone(),
+            round:    self.round.fork(),
+            pending:  self.pending.clone(),
+            schedule: self.schedule.clone(),
+        }
+    }
+
+    /// Hashes the state of this Node, regardless of the steps taken to get here.
+    ///
+    /// # Returns
+    /// The hash of this Node's state.
+    fn fingerprint(&self) -> u64 {
+        let mut state: DefaultHasher = DefaultHasher::new();
+        self.sim.fingerprint(&mut state);
+        hash_unordered(&self.todo, |agent, state| agent.hash(state), &mut state);
+        hash_unordered(&self.round.next, |agent, state| agent.hash(state), &mut state);
+        hash_unordered(
+            &self.round.spawned,
+            |(parent, agent), state| {
+                parent.hash(state);
+                agent.hash(state);
+            },
+            &mut state,
+        );
+        hash_unordered(
+            &self.pending,
+            |(agent, changes), state| {
+                agent.hash(state);
+                hash_changes(changes, state);
+            },
+            &mut state,
+        );
+        state.finish()
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// Explores every order in which the agents of a [`Simulation`] can be polled, and checks whether
+/// any of them makes an action fail its audit.
+///
+/// Every round, every awake agent is polled exactly once, like in [`Simulation::poll()`].
+/// Optionally, agents' changes are not synchronized right after they are polled but at any later
+/// point in the same round, such that every order in which they are delivered is explored too.
+///
+/// Schedules are explored breadth-first, such that the first counterexample found is the smallest.
+/// States that were visited before are pruned by their hash. Two states are considered the same if
+/// they have the same agents (as hashed by `A`) that know the same statements, enactments,
+/// agreements and time, regardless of the order in which those were sent.
+///
+/// Note that the number of schedules grows factorially with the number of agents, so this is only
+/// feasible for small scenarios.
+///
+/// # Generics
+/// - `A`: The type of agents in the explored [`Simulation`].
+#[derive(Debug)]
+pub struct Explorer<A> {
+    /// The simulation to start exploring from.
+    sim: Simulation<A>,
+    /// The maximum number of rounds to explore.
+    max_rounds: usize,
+    /// The maximum number of distinct states to visit.
+    max_states: usize,
+    /// Whether to explore the orders in which agents' changes are delivered.
+    deliveries: bool,
+}
+impl<A> Explorer<A> {
+    /// Constructor for the Explorer.
+    ///
+    /// # Arguments
+    /// - `sim`: The [`Simulation`] to start exploring from. It is not changed by exploring it.
+    ///
+    /// # Returns
+    /// A new Explorer that explores at most [`DEFAULT_MAX_ROUNDS`] rounds and [`DEFAULT_MAX_STATES`] states.
+    #[inline]
+    pub fn new(sim: Simulation<A>) -> Self { Self { sim, max_rounds: DEFAULT_MAX_ROUNDS, max_states: DEFAULT_MAX_STATES, deliveries: false } }
+
+    /// Sets the maximum number of rounds to explore.
+    ///
+    /// # Arguments
+    /// - `max`: The number of rounds after which schedules are not explored any further.
+    #[inline]
+    pub fn set_max_rounds(&mut self, max: usize) { self.max_rounds = max; }
+
+    /// Sets the maximum number of distinct states to visit.
+    ///
+    /// # Arguments
+    /// - `max`: The number of states after which the explorer gives up.
+    #[inline]
+    pub fn set_max_states(&mut self, max: usize) { self.max_states = max; }
+
+    /// Sets whether to explore the orders in which agents' changes are delivered.
+    ///
+    /// # Arguments
+    /// - `deliveries`: If true, agents' changes may be synchronized at any point in the round after
+    ///   they are polled. Otherwise, they are synchronized immediately.
+    #[inline]
+    pub fn set_deliveries(&mut self, deliveries: bool) { self.deliveries = deliveries; }
+
+    /// Returns the simulation that is explored.
+    ///
+    /// # Returns
+    /// A reference to the [`Simulation`] to start exploring from.
+    #[inline]
+    pub fn simulation(&self) -> &Simulation<A> { &self.sim }
+}
+impl<A> Explorer<A>
+where
+    A: Clone + Hash + Identifiable<Id = str>,
+    A: RationalAgent<Message = Message, Target = Target>,
+{
+    /// Explores every schedule of the simulation until one makes an action fail its audit.
+    ///
+    /// Nothing is printed about the simulations explored.
+    ///
+    /// # Generics
+    /// - `E`: The [`Extractor`] of the policy language used to audit actions.
+    ///
+    /// # Arguments
+    /// - `auditor`: The [`Auditor`] (stack) used to audit the actions enacted by agents.
+    ///
+    /// # Returns
+    /// An [`ExploreReport`] with the smallest [`Counterexample`], if any. Schedules in which an
+    /// agent with [`FailurePolicy::Abort`](crate::simulation::FailurePolicy::Abort) fails are
+    /// reported in it too.
+    pub fn explore<E>(&self, auditor: impl Auditor<Message>) -> ExploreReport<<A as RationalAgent>::Error>
+    where
+        E: for<'e> Extractor<&'e Message>,
+    {
+        info!("Exploring at most {} rounds of Simulation{}", self.max_rounds, if self.deliveries { " (including deliveries)" } else { "" });
+        self.search::<E>(&auditor)
+    }
+
+    /// Explores every schedule of the simulation breadth-first.
+    ///
+    /// # Generics
+    /// - `E`: The [`Extractor`] of the policy language used to audit actions.
+    ///
+    /// # Arguments
+    /// - `auditor`: The [`Auditor`] (stack) used to audit the actions enacted by agents.
+    ///
+    /// # Returns
+    /// An [`ExploreReport`] with the smallest [`Counterexample`], if any.
+    fn search<E>(&self, auditor: &impl Auditor<Message>) -> ExploreReport<<A as RationalAgent>::Error>
+    where
+        E: for<'e> Extractor<&'e Message>,
+    {
+        let mut report: ExploreReport<<A as RationalAgent>::Error> =
+            ExploreReport { counterexample: None, aborted: None, aborts: 0, states: 0, pruned: 0, complete: true };
+        let mut seen: HashSet<u64> = HashSet::new();
+        let mut queue: VecDeque<Node<A>> = VecDeque::new();
+        if let Some(node) = self.begin(self.sim.fork(), Vec::new()) {
+            seen.insert(node.fingerprint());
+            report.states += 1;
+            queue.push_back(node);
+        }
+        while let Some(node) = queue.pop_front() {
+            // Either poll an agent that hasn't been, or deliver the changes of one that has
+            for i in 0..node.todo.len() + node.pending.len() {
+                let mut child: Node<A> = node.fork();
+                let step: Step = if i < node.todo.len() {
+                    let agent: A = child.todo.remove(i);
+                    let id: String = agent.id().into();
+                    let polled: Polled<A> = child.sim.poll_detached(agent);
+                    if let Err(err) = child.sim.settle(&mut child.round, polled.agent, polled.res) {
+                        // Record the abort as this schedule's outcome, and try the others
+                        let Error::AgentPoll { agent, err } = err else { unreachable!("Settling an agent does not flush sinks") };
+                        report.aborts += 1;
+                        if report.aborted.is_none() {
+                            debug!("Agent '{agent}' aborted after visiting {} states", report.states);
+                            let mut schedule: Vec<Vec<Step>> = child.schedule;
+                            if let Some(steps) = schedule.last_mut() {
+                                steps.push(Step::Poll { agent: id });
+                            }
+                            report.aborted = Some(Aborted { schedule, agent, err });
+                        }
+                        continue;
+                    }
+                    if self.deliveries {
+                        child.pending.push((id.clone(), polled.changes));
+                    } else {
+                        child.sim.deliver(&id, polled.changes);
+                    }
+                    Step::Poll { agent: id }
+                } else {
+                    let (id, changes): (String, Changes) = child.pending.remove(i - node.todo.len());
+                    child.sim.deliver(&id, changes);
+                    Step::Deliver { agent: id }
+                };
+                if let Some(steps) = child.schedule.last_mut() {
+                    steps.push(step);
+                }
+
+                // End the round once every agent has been polled and delivered
+                if child.todo.is_empty() && child.pending.is_empty() {
+                    let Node { mut sim, round, schedule, .. } = child;
+                    let alive: bool = sim.finish(round).alive;
+                    let failed: Vec<Action<Message>> = sim.audit::<E>(auditor);
+                    if let Some(action) = failed.into_iter().min_by(|lhs, rhs| lhs.enacts.id.cmp(&rhs.enacts.id)) {
+                        debug!("Found counterexample after visiting {} states", report.states);
+                        report.counterexample = Some(Counterexample { schedule, action });
+                        return report;
+                    }
+                    if !alive {
+                        continue;
+                    }
+                    child = match self.begin(sim, schedule) {
+                        Some(child) => child,
+                        None => continue,
+                    };
+                }
+
+                // Don't explore states we've seen before
+                if !seen.insert(child.fingerprint()) {
+                    report.pruned += 1;
+                    continue;
+                }
+                if report.states >= self.max_states {
+                    debug!("Giving up after visiting {} states", report.states);
+                    report.complete = false;
+                    return report;
+                }
+                report.states += 1;
+                queue.push_back(child);
+            }
+        }
+        report
+    }
+
+    /// Starts the next round of a simulation.
+    ///
+    /// # Arguments
+    /// - `sim`: The [`Simulation`] to start the round of.
+    /// - `schedule`: The steps taken in the previous rounds.
+    ///
+    /// # Returns
+    /// A new [`Node`] at the start of the round, or [`None`] if the maximum number of rounds has
+    /// been explored.
+    fn begin(&self, mut sim: Simulation<A>, mut schedule: Vec<Vec<Step>>) -> Option<Node<A>> {
+        if schedule.len() >= self.max_rounds {
+            return None;
+        }
+        let todo: Vec<A> = sim.begin_round();
+        schedule.push(Vec::with_capacity(todo.len()));
+        Some(Node { sim, round: Round::with_capacity(todo.len()), todo, pending: Vec::new(), schedule })
+    }
+}
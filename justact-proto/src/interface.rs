@@ -4,7 +4,7 @@
 //  Created:
 //    16 Apr 2024, 10:58:56
 //  Last edited:
-//    29 May 2024, 13:43:26
+//    16 Oct 2026, 22:13:15
 //  Auto updated?
 //    Yes
 //
@@ -13,17 +13,22 @@
 //
 
 use std::borrow::Cow;
-use std::collections::HashMap;
-use std::fmt::{Display, Formatter, Result as FResult};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter, Result as FResult};
+use std::io;
 
 use console::{style, Style};
 use justact_core::agreements::Agreement;
-use justact_core::auxillary::{Authored as _, Identifiable as _};
+use justact_core::auxillary::{Authored as _, Identifiable};
 use justact_core::set::LocalSet;
-use justact_core::statements::{Action, AuditExplanation, Message as _};
+use justact_core::statements::{Action, AuditReport, Justification, Message as _};
 use justact_core::times::Timestamp;
 
-use crate::statements::Message;
+use crate::agreements::Outcome;
+use crate::events::{ConsoleSink, Event, EventSink, Violation};
+use crate::network::DropReason;
+use crate::simulation::FailurePolicy;
+use crate::statements::{Message, Target};
 
 
 /***** FORMATTERS *****/
@@ -48,6 +53,30 @@ impl<'m, V: Displayable, S, P: Display, I: Display> Display for MessageSetFormat
     }
 }
 
+/// Formats a [`Justification`] with proper indentation and such.
+pub struct JustificationFormatter<'j, M, P, I> {
+    /// The justification to format.
+    just:   Justification<'j, M>,
+    /// Some prefix to use when writing the message.
+    prefix: P,
+    /// The indentation to use while formatting.
+    indent: I,
+}
+impl<'j, M, P: Display, I: Display> Display for JustificationFormatter<'j, M, P, I>
+where
+    M: Identifiable + Displayable,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        // Write the messages, one-by-one
+        writeln!(f, "{} {{", self.prefix)?;
+        for msg in self.just {
+            write!(f, "{}    {}", self.indent, msg.display("Message", &format!("{}    ", self.indent)))?;
+        }
+        writeln!(f, "{}}}", self.indent)
+    }
+}
+
 /// Formats an [`Agreement`] with proper indentation and such.
 pub struct AgreementFormatter<'a, P, I, M> {
     /// The agreement to format.
@@ -66,11 +95,11 @@ impl<'a, P: Display, I: Display> Display for AgreementFormatter<'a, P, I, Messag
         // Write the agreement
         writeln!(
             f,
-            "{} '{}' by '{}' (applies at {}) {{",
+            "{} '{}' by '{}' (applies {}) {{",
             self.prefix,
             style(self.agr.id()).bold(),
             style(self.agr.msg.author()).bold(),
-            style(self.agr.timestamp).bold(),
+            style(self.agr.applies).bold(),
         )?;
         writeln!(f, "{}    {}", self.indent, spayload.replace('\n', &format!("\n{}    ", self.indent)).trim_end())?;
         writeln!(f, "{}}}", self.indent)
@@ -110,6 +139,14 @@ impl<V: Displayable, S> Displayable for LocalSet<V, S> {
         MessageSetFormatter { msgs: self, prefix, indent }
     }
 }
+impl<'j, M: Identifiable + Displayable> Displayable for Justification<'j, M> {
+    type Formatter<'s, P: Display, I: Display> = JustificationFormatter<'j, M, P, I> where Self: 's;
+
+    #[inline]
+    fn display<'s, P: Display, I: Display>(&'s self, prefix: P, indent: I) -> Self::Formatter<'s, P, I> {
+        JustificationFormatter { just: *self, prefix, indent }
+    }
+}
 impl Displayable for Agreement<Message> {
     type Formatter<'s, P: Display, I: Display> = AgreementFormatter<'s, P, I, Message> where Self: 's;
 
@@ -133,235 +170,396 @@ impl<'v, D: Displayable> Displayable for &'v D {
 
 /***** LIBRARY *****/
 /// Implements a [`justact::Interface`] that allows agents to communicate with the simulation environment's end user.
-#[derive(Clone, Debug)]
+///
+/// It turns everything that happens into [`Event`]s, which are written to the terminal by a
+/// [`ConsoleSink`] and given to any other [`EventSink`]s.
 pub struct Interface {
-    /// The mapping of agents to their styles.
-    styles: HashMap<String, Style>,
+    /// The sink writing events to the terminal, which also knows the styles of agents.
+    console: ConsoleSink,
+    /// Any other sinks to give events to.
+    sinks:   Vec<Box<dyn EventSink>>,
+    /// The round that is currently simulated.
+    round:   usize,
+    /// Whether to suppress all output to the terminal.
+    quiet:   bool,
+    /// Whether to suppress all events, including those given to other sinks.
+    muted:   bool,
+    /// The events held back from the other sinks until they know how the simulation started, if
+    /// it hasn't yet.
+    held:    Option<Vec<Event>>,
 }
 
+impl Default for Interface {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
 impl Interface {
     /// Constructor for the Interface.
     ///
     /// # Returns
     /// A new Interface ready for use in the simulation.
     #[inline]
-    pub fn new() -> Self { Self { styles: HashMap::new() } }
+    pub fn new() -> Self { Self { console: ConsoleSink::new(), sinks: Vec::new(), round: 0, quiet: false, muted: false, held: None } }
 
-    /// Registers the style for a new agent.
+    /// Registers a new agent, together with the style with which the terminal shows it.
     ///
-    /// Note that all other functions panic if you call it for an agent that hasn't been registered yet.
+    /// Agents that aren't registered are shown without any style.
     ///
     /// # Arguments
     /// - `id`: The identifier of the agent to register.
     /// - `style`: The style to use when formatting the agent's `id`entifier.
+    pub fn register(&mut self, id: &str, style: Style) {
+        self.console.register(id, style);
+        self.emit(|round| Event::Registered { round, agent: id.into() });
+    }
+
+    /// Adds a sink that receives all events from now on.
+    ///
+    /// # Arguments
+    /// - `sink`: The [`EventSink`] to add.
     #[inline]
-    pub fn register(&mut self, id: &str, style: Style) { self.styles.insert(id.into(), style); }
+    pub fn add_sink(&mut self, sink: Box<dyn EventSink>) { self.sinks.push(sink); }
 
+    /// Returns whether any sinks other than the terminal were added.
+    ///
+    /// # Returns
+    /// True if events are given to at least one other sink, or false otherwise.
+    #[inline]
+    pub(crate) fn has_sinks(&self) -> bool { !self.sinks.is_empty() }
 
+    /// Holds back all events from the other sinks until [`Interface::start()`] is called.
+    ///
+    /// This allows sinks to learn how a simulation is set up before anything happens in it, even
+    /// if it is set up after the sinks were added. Events are still written to the terminal.
+    #[inline]
+    pub(crate) fn hold(&mut self) { self.held = Some(Vec::new()); }
 
-    /// Logs an arbitrary message to stdout.
+    /// Returns whether events are held back from the other sinks.
+    ///
+    /// # Returns
+    /// True if [`Interface::hold()`] was called but [`Interface::start()`] wasn't yet, or false
+    /// otherwise.
+    #[inline]
+    pub(crate) fn is_held(&self) -> bool { self.held.is_some() }
+
+    /// Gives the other sinks the event that starts their trace, followed by all events held back
+    /// since [`Interface::hold()`]. Events are given to them immediately from then on.
+    ///
+    /// Does nothing if events are not held back, or while this interface is muted.
     ///
     /// # Arguments
-    /// - `id`: The identifier of the agent who is logging.
-    /// - `msg`: Some message (retrieved as [`Display`]) to show.
-    pub fn log(&self, id: &str, msg: impl Display) {
-        println!("{}{}{} {}\n", style("[INFO] [").bold(), self.styles.get(id).unwrap().apply_to(id), style("]").bold(), msg);
+    /// - `started`: Some closure that builds the [`Event::Started`].
+    pub(crate) fn start(&mut self, started: impl FnOnce() -> Event) {
+        if self.muted {
+            return;
+        }
+        let Some(held) = self.held.take() else { return };
+        if self.sinks.is_empty() {
+            return;
+        }
+        let started: Event = started();
+        for sink in &mut self.sinks {
+            sink.handle(&started);
+            for event in &held {
+                sink.handle(event);
+            }
+        }
+    }
+
+    /// Flushes all sinks.
+    ///
+    /// # Errors
+    /// This function errors with the first error of any sink. The other sinks are still flushed.
+    pub fn flush(&mut self) -> Result<(), io::Error> {
+        let mut res: Result<(), io::Error> = Ok(());
+        for sink in &mut self.sinks {
+            if let Err(err) = sink.flush() {
+                res = res.and(Err(err));
+            }
+        }
+        res
+    }
+
+    /// Sets the round that is currently simulated, which is reported with every event.
+    ///
+    /// # Arguments
+    /// - `round`: The current round.
+    #[inline]
+    pub fn set_round(&mut self, round: usize) { self.round = round; }
+
+    /// Sets whether this interface suppresses all output to the terminal.
+    ///
+    /// This is useful when running many agents, e.g., for benchmarking. Events are still given
+    /// to any other sinks.
+    ///
+    /// # Arguments
+    /// - `quiet`: If true, nothing is written anymore.
+    #[inline]
+    pub fn set_quiet(&mut self, quiet: bool) { self.quiet = quiet; }
+
+    /// Returns whether this interface suppresses all output to the terminal.
+    ///
+    /// # Returns
+    /// True if nothing is written, or false otherwise.
+    #[inline]
+    pub fn is_quiet(&self) -> bool { self.quiet }
+
+    /// Sets whether this interface suppresses all events, including those given to other sinks.
+    ///
+    /// # Arguments
+    /// - `muted`: If true, no events are emitted anymore.
+    #[inline]
+    pub(crate) fn set_muted(&mut self, muted: bool) { self.muted = muted; }
+
+    /// Gives an event to all sinks, unless nobody would receive it.
+    ///
+    /// # Arguments
+    /// - `event`: Some closure that builds the [`Event`] given the current round.
+    fn emit(&mut self, event: impl FnOnce(usize) -> Event) {
+        if self.muted || (self.quiet && self.sinks.is_empty() && self.held.is_none()) {
+            return;
+        }
+        let event: Event = event(self.round);
+        if !self.quiet {
+            self.console.handle(&event);
+        }
+        if let Some(held) = &mut self.held {
+            // NOTE: Sinks may still be added before the simulation starts, so hold on to it anyway
+            held.push(event);
+            return;
+        }
+        for sink in &mut self.sinks {
+            sink.handle(&event);
+        }
     }
 
-    /// Logs the statement of a [`Message`] to stdout.
+
+
+    /// Logs an arbitrary message.
     ///
     /// # Arguments
     /// - `id`: The identifier of the agent who is logging.
-    /// - `msg`: Some [`Message`] to emit.
-    pub fn log_state(&self, id: &str, msg: &Message) {
-        // Write the main log-line
-        println!("{}{}{} Emitted message '{}'", style("[INFO] [").bold(), self.styles.get(id).unwrap().apply_to(id), style("]").bold(), msg.id());
+    /// - `msg`: Some message (retrieved as [`Display`]) to show.
+    #[inline]
+    pub fn log(&mut self, id: &str, msg: impl Display) { self.emit(|round| Event::Logged { round, agent: id.into(), msg: msg.to_string() }); }
 
-        // Write the message
-        println!(" └> {}", msg.display("Message", "    "));
-        println!();
+    /// Logs that the current round ended.
+    #[inline]
+    pub fn log_finish(&mut self) { self.emit(|round| Event::Finished { round }); }
 
-        // Done
+    /// Logs the statement of a [`Message`].
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the agent who is logging.
+    /// - `target`: The [`Target`] to which the message is stated.
+    /// - `msg`: Some [`Message`] to emit.
+    #[inline]
+    pub fn log_state(&mut self, id: &str, target: &Target, msg: &Message) {
+        self.emit(|round| Event::Stated { round, agent: id.into(), target: target.clone(), msg: msg.clone() });
     }
 
-    /// Logs the enactment of an [`Action`] over [`Message`]s to stdout.
+    /// Logs the enactment of an [`Action`] over [`Message`]s.
     ///
     /// # Arguments
     /// - `id`: The identifier of the agent who is logging.
+    /// - `target`: The [`Target`] to which the action is enacted.
     /// - `act`: Some [`Action`] to emit.
-    pub fn log_enact(&self, id: &str, act: &Action<Message>) {
-        let just: LocalSet<&Message> = act.justification();
-
-        // Retrieve the message IDs for the justication
-        let mut just_ids: String = String::new();
-        for msg in just.iter() {
-            if !just_ids.is_empty() {
-                just_ids.push_str(", ");
-            }
-            just_ids.push_str(&msg.id().to_string());
-        }
+    #[inline]
+    pub fn log_enact(&mut self, id: &str, target: &Target, act: &Action<Message>) {
+        self.emit(|round| Event::Enacted { round, agent: id.into(), target: target.clone(), act: act.clone() });
+    }
+
+
 
-        // Write the main log-line
-        println!(
-            "{}{}{} Enacted message '{}' using '{}' (basis '{}')",
-            style("[INFO] [").bold(),
-            self.styles.get(id).unwrap().apply_to(id),
-            style("]").bold(),
-            act.enacts().id(),
-            just_ids,
-            act.basis().id(),
-        );
-
-        // Write the sets
-        print!(" ├> {}", act.basis().display("Basis", " |  "));
-        print!(" ├> {}", just.display("Justification", " |  "));
-        print!(" └> {}", act.enacts().display("Enacts", "    "));
-        println!();
-
-        // Done
+    /// Logs that something an agent sent arrived at another agent over the network.
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the agent who sent it.
+    /// - `to`: The identifier of the agent who received it.
+    /// - `msg`: The identifier of the message stated, or of the message enacted.
+    /// - `enacted`: Whether it is the action enacting the message rather than the message itself.
+    #[inline]
+    pub fn log_deliver(&mut self, id: &str, to: &str, msg: &str, enacted: bool) {
+        self.emit(|round| Event::Delivered { round, agent: id.into(), to: to.into(), id: msg.into(), enacted });
     }
 
+    /// Logs that something an agent sent never arrived at another agent.
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the agent who sent it.
+    /// - `to`: The identifier of the agent who should have received it.
+    /// - `msg`: The identifier of the message stated, or of the message enacted.
+    /// - `enacted`: Whether it is the action enacting the message rather than the message itself.
+    /// - `reason`: The [`DropReason`] why it never arrived.
+    #[inline]
+    pub fn log_drop(&mut self, id: &str, to: &str, msg: &str, enacted: bool, reason: DropReason) {
+        self.emit(|round| Event::Dropped { round, agent: id.into(), to: to.into(), id: msg.into(), enacted, reason });
+    }
 
+    /// Logs that an agent advanced the time.
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the agent who advanced it.
+    /// - `time`: The [`Timestamp`] that was advanced to.
+    #[inline]
+    pub fn log_advance(&mut self, id: &str, time: Timestamp) { self.emit(|round| Event::TimeAdvanced { round, agent: id.into(), time }); }
 
-    /// Logs that an agent started synchronizing a new time.
+    /// Logs that an agent created a new agreement over [`Message`]s.
     ///
     /// # Arguments
-    /// - `id`: The identifier of the agent who is logging.
-    /// - `time`: The [`Timestamp`] that will be advanced if synchronized.
-    pub fn log_advance_start(&self, id: &str, time: Timestamp) {
-        // Write the main log-line
-        println!(
-            "{}{}{} Initiated synchronization to advance time to {}",
-            style("[INFO] [").bold(),
-            self.styles.get(id).unwrap().apply_to(id),
-            style("]").bold(),
-            style(time).bold()
-        );
-        println!();
+    /// - `id`: The identifier of the agent who created it.
+    /// - `agrmnt`: The [`Agreement`] (over [`Message`]s) that was added to the pool of agreements.
+    #[inline]
+    pub fn log_agree(&mut self, id: &str, agrmnt: &Agreement<Message>) {
+        self.emit(|round| Event::Agreed { round, agent: id.into(), agr: agrmnt.clone() });
     }
 
-    /// Logs that all agents agreed to synchronize time.
+    /// Logs that an agent revoked an existing agreement.
     ///
     /// # Arguments
-    /// - `time`: The [`Timestamp`] that will be advanced if synchronized.
-    pub fn log_advance(&self, time: Timestamp) {
-        // Write the main log-line
-        println!(
-            "{}{}{} Time advanced to {}",
-            style("[INFO] [").bold(),
-            self.styles.get("<system>").unwrap().apply_to("<system>"),
-            style("]").bold(),
-            style(time).bold()
-        );
-        println!();
+    /// - `id`: The identifier of the agent who revoked it.
+    /// - `agrmnt`: The [`Agreement`] (over [`Message`]s) as it will be known after the revocation, i.e., with its shortened range.
+    #[inline]
+    pub fn log_revoke(&mut self, id: &str, agrmnt: &Agreement<Message>) {
+        self.emit(|round| Event::Revoked { round, agent: id.into(), agr: agrmnt.clone() });
     }
 
-    /// Logs that an agent started synchronizing a new agreement over [`Message`]s.
+    /// Logs that an agent proposed an agreement to vote on.
     ///
     /// # Arguments
-    /// - `id`: The identifier of the agent who is logging.
-    /// - `agrmnt`: The [`Agreement`] that will be added to the pool of agreements when synchronized.
-    pub fn log_agree_start(&self, id: &str, agrmnt: &Agreement<Message>) {
-        // Write the main log-line
-        println!(
-            "{}{}{} Initiated synchronization to agree on message '{}'",
-            style("[INFO] [").bold(),
-            self.styles.get(id).unwrap().apply_to(id),
-            style("]").bold(),
-            style(agrmnt.id()).bold()
-        );
-
-        // Write the set
-        println!(" └> {}", agrmnt.display("Agreement", "    "));
-        println!();
+    /// - `id`: The identifier of the agent who proposed it.
+    /// - `agrmnt`: The proposed [`Agreement`] (over [`Message`]s).
+    #[inline]
+    pub fn log_propose(&mut self, id: &str, agrmnt: &Agreement<Message>) {
+        self.emit(|round| Event::Proposed { round, agent: id.into(), agr: agrmnt.clone() });
     }
 
-    /// Logs that all agents agreed to synchronize time.
+    /// Logs that an agent voted on a proposed agreement.
     ///
     /// # Arguments
-    /// - `agrmnt`: The [`Agreement`] (over [`Message`]s) that will be added to the pool of agreements when synchronized.
-    pub fn log_agree(&self, agrmnt: &Agreement<Message>) {
-        // Write the main log-line
-        println!(
-            "{}{}{} New agreement '{}' created",
-            style("[INFO] [").bold(),
-            self.styles.get("<system>").unwrap().apply_to("<system>"),
-            style("]").bold(),
-            style(agrmnt.id()).bold()
-        );
-
-        // Write the set
-        println!(" └> {}", agrmnt.display("Agreement", "    "));
-        println!();
+    /// - `id`: The identifier of the agent who voted.
+    /// - `agrmnt`: The identifier of the proposed agreement.
+    /// - `approve`: Whether the agent voted in favour (true) or against (false).
+    #[inline]
+    pub fn log_vote(&mut self, id: &str, agrmnt: &str, approve: bool) {
+        self.emit(|round| Event::Voted { round, agent: id.into(), id: agrmnt.into(), approve });
     }
 
+    /// Logs that the vote on a proposed agreement was decided.
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the agent whose vote decided it.
+    /// - `agrmnt`: The identifier of the proposed agreement.
+    /// - `outcome`: The [`Outcome`] of the vote.
+    #[inline]
+    pub fn log_decide(&mut self, id: &str, agrmnt: &str, outcome: Outcome) {
+        self.emit(|round| Event::Decided { round, agent: id.into(), id: agrmnt.into(), outcome });
+    }
 
+    /// Logs that an agent proposed to advance the time.
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the agent who proposed it.
+    /// - `time`: The proposed [`Timestamp`].
+    #[inline]
+    pub fn log_propose_time(&mut self, id: &str, time: Timestamp) { self.emit(|round| Event::TimeProposed { round, agent: id.into(), time }); }
 
-    /// Logs an error message to stdout.
+    /// Logs that an agent voted in favour of a proposed time.
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the agent who voted.
+    /// - `time`: The proposed [`Timestamp`].
+    #[inline]
+    pub fn log_vote_time(&mut self, id: &str, time: Timestamp) { self.emit(|round| Event::TimeVoted { round, agent: id.into(), time }); }
+
+    /// Logs that the vote on a proposed time was decided.
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the agent whose vote decided it.
+    /// - `time`: The proposed [`Timestamp`].
+    /// - `outcome`: The [`Outcome`] of the vote.
+    #[inline]
+    pub fn log_decide_time(&mut self, id: &str, time: Timestamp, outcome: Outcome) {
+        self.emit(|round| Event::TimeDecided { round, agent: id.into(), time, outcome });
+    }
+
+
+
+    /// Logs an error message.
     ///
     /// # Arguments
     /// - `id`: The identifier of the agent who is logging.
     /// - `msg`: Some message (retrieved as [`Display`]) to show.
-    pub fn error(&self, id: &str, msg: impl Display) {
-        println!(
-            "{}{}{}{}{} {}\n",
-            style("[").bold(),
-            style("ERROR").bold().red(),
-            style("] [").bold(),
-            self.styles.get(id).unwrap().apply_to(id),
-            style("]").bold(),
-            msg
-        );
+    #[inline]
+    pub fn error(&mut self, id: &str, msg: impl Display) { self.emit(|round| Event::Errored { round, agent: id.into(), msg: msg.to_string() }); }
+
+    /// Logs that an agent failed to poll.
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the agent who failed.
+    /// - `err`: The error the agent failed with.
+    /// - `policy`: The [`FailurePolicy`] that is applied to the agent.
+    #[inline]
+    pub fn error_agent(&mut self, id: &str, err: &dyn Error, policy: FailurePolicy) {
+        self.emit(|round| Event::AgentFailed { round, agent: id.into(), err: err.to_string(), policy });
     }
 
-    /// Logs a the result of a failed audit to stdout.
+    /// Logs that different versions of a message were stated under the same identifier.
     ///
     /// # Arguments
     /// - `id`: The identifier of the agent who is logging.
-    /// - `act`: The [`Action`] (over [`Message`]s) that failed the audit.
-    /// - `expl`: The [`Explanation`] of why the audit of that action failed.
-    pub fn error_audit<E1, E2>(&self, id: &str, act: &Action<Message>, expl: AuditExplanation<&str, E1, E2>) {
-        // Write for that agent
-        println!(
-            "{}{}{}{}{} Action that enacts '{}' did not succeed audit",
-            style("[").bold(),
-            style("ERROR").bold().red(),
-            style("] [").bold(),
-            self.styles.get(id).unwrap().apply_to(id),
-            style("]").bold(),
-            act.enacts().id(),
-        );
-
-        // Retrieve the message IDs for the justication
-        let just: LocalSet<&Message> = act.justification();
-        let mut just_ids: String = String::new();
-        for msg in just.iter() {
-            if !just_ids.is_empty() {
-                just_ids.push_str(", ");
-            }
-            just_ids.push_str(&msg.id().to_string());
-        }
+    /// - `versions`: All the different versions of the [`Message`] that are stated.
+    #[inline]
+    pub fn error_equivocation(&mut self, id: &str, versions: &[Message]) {
+        self.emit(|round| Event::Equivocated { round, agent: id.into(), versions: versions.to_vec() });
+    }
 
-        // Generate serialized explanation
-        let sexpl: String = match expl {
-            AuditExplanation::Stated { stmt } => format!("Message '{}' is not stated", style(stmt).bold()),
-            AuditExplanation::Extract { err: _ } => format!("Cannot extract policy"),
-            AuditExplanation::Valid { expl: _ } => format!("Extracted policy is not valid"),
-            AuditExplanation::Based { stmt } => format!("Message '{}' is not in the set of agreements", style(stmt).bold()),
-            AuditExplanation::Timely { stmt, applies_at, taken_at } => format!(
-                "Message '{}' is an agreement valid for time {}, but the action was taken at time {}",
-                style(stmt).bold(),
-                applies_at,
-                taken_at
-            ),
-        };
-        let sexpl: &str = sexpl.trim_end();
-
-        // Write the sets
-        print!(" ├> {}", act.basis().display("Basis", " |  "));
-        print!(" ├> {}", just.display("Justification", " |  "));
-        print!(" ├> {}", act.enacts().display("Enacts", " |  "));
-        println!(" └> {sexpl}");
-        println!();
+    /// Logs that an agent stated more messages in a single round than allowed.
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the agent who stated them.
+    /// - `stated`: The number of messages the agent stated.
+    /// - `limit`: The number of messages the agent was allowed to state.
+    #[inline]
+    pub fn error_flood(&mut self, id: &str, stated: usize, limit: usize) {
+        self.emit(|round| Event::Flooded { round, agent: id.into(), stated, limit });
+    }
+
+    /// Logs a warning that an [`Action`]'s justification contains messages that play no part in its validity.
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the agent who is logging.
+    /// - `act`: The [`Action`] (over [`Message`]s) that has superfluous messages.
+    /// - `superfluous`: The messages in the `act`ion's justification that are superfluous.
+    pub fn warn_superfluous(&mut self, id: &str, act: &Action<Message>, superfluous: &LocalSet<&Message>) {
+        self.emit(|round| {
+            // Retrieve the message IDs that are superfluous, sorted for predictability
+            let mut ids: Vec<String> = superfluous.iter().map(|msg| msg.id().into()).collect();
+            ids.sort();
+            Event::Superfluous { round, agent: id.into(), act: act.clone(), superfluous: ids }
+        });
+    }
+
+    /// Logs a the result of a failed audit.
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the agent who is logging.
+    /// - `act`: The [`Action`] (over [`Message`]s) that failed the audit.
+    /// - `report`: The [`AuditReport`] listing all the reasons why the audit of that action failed.
+    pub fn error_audit<E1: Display, E2: Display>(&mut self, id: &str, act: &Action<Message>, report: AuditReport<&str, E1, E2>) {
+        self.emit(|round| Event::AuditFailed { round, agent: id.into(), act: act.clone(), violations: report.into_iter().map(Violation::from).collect() });
+    }
+}
+impl Debug for Interface {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        f.debug_struct("Interface")
+            .field("console", &self.console)
+            .field("sinks", &format!("<{} sinks>", self.sinks.len()))
+            .field("round", &self.round)
+            .field("quiet", &self.quiet)
+            .field("muted", &self.muted)
+            .field("held", &self.held.as_ref().map(|held| format!("<{} events>", held.len())))
+            .finish()
     }
 }
@@ -4,7 +4,7 @@
 //  Created:
 //    15 Apr 2024, 16:13:37
 //  Last edited:
-//    04 Jun 2024, 11:29:50
+//    16 Oct 2026, 20:33:49
 //  Auto updated?
 //    Yes
 //
@@ -14,10 +14,17 @@
 
 // Declare modules
 pub mod agreements;
+pub mod byzantine;
+pub mod events;
+pub mod explorer;
 pub mod interface;
+#[cfg(test)]
+mod mock;
+pub mod network;
 pub mod simulation;
 pub mod statements;
 pub mod times;
+pub mod trace;
 
 // Use some of it in the global namespace
 pub use simulation::*;
@@ -0,0 +1,238 @@
+//  MOCK.rs
+//    by Lut99
+//
+//  Created:
+//    16 Oct 2026, 20:33:19
+//  Last edited:
+//    16 Oct 2026, 21:16:13
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Defines a scripted agent such that the simulation can be tested
+//!   without writing a new agent for every scenario.
+//
+
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FResult};
+use std::str::Utf8Error;
+
+use justact_core::agents::{Agent, AgentPoll, RationalAgent};
+use justact_core::agreements::{Agreement, Agreements};
+use justact_core::auxillary::{Authored, Identifiable};
+use justact_core::policy::{Extractor, Policy};
+use justact_core::set::LocalSet;
+use justact_core::statements::{Action, Message as JAMessage, Statements};
+use justact_core::times::{TimeRange, Times, Timestamp};
+
+use crate::statements::{Message, Target};
+
+
+/***** ERRORS *****/
+/// The [`Policy::SemanticError`] of the [`MockPolicy`].
+#[derive(Debug)]
+pub struct MockInvalid;
+impl Display for MockInvalid {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult { write!(f, "\"error\" occurs in the policy") }
+}
+impl Error for MockInvalid {}
+
+/// The [`RationalAgent::Error`] of the [`MockAgent`].
+#[derive(Debug)]
+pub struct MockFailure {
+    /// The agent that failed.
+    pub agent: String,
+}
+impl Display for MockFailure {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult { write!(f, "Agent '{}' failed as scripted", self.agent) }
+}
+impl Error for MockFailure {}
+
+
+
+
+
+/***** LIBRARY *****/
+/// A policy that is simply the lines of all messages in a set.
+///
+/// It is valid if and only if none of these lines is `error`.
+#[derive(Clone, Debug)]
+pub struct MockPolicy<'v> {
+    /// The lines in the policy.
+    pub lines: Vec<&'v str>,
+}
+impl<'v> Policy for MockPolicy<'v> {
+    type SemanticError = MockInvalid;
+
+    #[inline]
+    fn assert_validity(&self) -> Result<(), Self::SemanticError> { if self.lines.contains(&"error") { Err(MockInvalid) } else { Ok(()) } }
+}
+
+/// The [`Extractor`] for the [`MockPolicy`].
+#[derive(Clone, Copy, Debug)]
+pub struct MockExtractor;
+impl<M> Extractor<M> for MockExtractor {
+    type Policy<'v> = MockPolicy<'v> where Self: 'v;
+    type SyntaxError<'v> = Utf8Error where Self: 'v;
+
+    #[inline]
+    fn extract<'v, R>(set: &LocalSet<M, R>) -> Result<Self::Policy<'v>, Self::SyntaxError<'v>>
+    where
+        Self: Sized,
+        M: Authored + Identifiable + JAMessage<'v>,
+    {
+        let mut lines: Vec<&'v str> = Vec::new();
+        for msg in set {
+            lines.extend(std::str::from_utf8(msg.payload())?.lines());
+        }
+        Ok(MockPolicy { lines })
+    }
+}
+
+
+
+/// A single step in the script of a [`MockAgent`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum MockStep {
+    /// States messages with the given identifiers to all agents. Their payload is their
+    /// identifier.
+    State(Vec<String>),
+    /// States a message with the given identifier to all agents, unless a message with the
+    /// identifier `unless` is stated to it or agreed upon.
+    StateUnless { id: String, unless: String },
+    /// Agrees on (or votes in favour of) the message with the given identifier, applying from the
+    /// current time. If it is already proposed, the proposed version is used instead. Fails to
+    /// poll if that is refused.
+    Agree(String),
+    /// States a message with the given identifier and enacts it, justified by the given messages
+    /// and based on the agreement with the lowest identifier. Until that agreement and those
+    /// messages are known, the agent waits (but is polled) without taking the next step.
+    Enact { id: String, just: Vec<String> },
+    /// Advances the time (or proposes to) to the given timestamp. Fails to poll if that is refused.
+    Advance(Timestamp),
+    /// Fails to poll.
+    Fail,
+    /// Fails to poll if a message with the given identifier is stated to it or agreed upon.
+    /// Otherwise, it does nothing.
+    FailOn(String),
+    /// Sleeps until the given time.
+    Sleep(Timestamp),
+    /// Waits until a message with the given identifier is stated to it or agreed upon.
+    WaitFor(String),
+    /// Spawns the given agents.
+    Spawn(Vec<MockAgent>),
+}
+
+/// An agent that carries out a script, one [`MockStep`] every time it is polled.
+///
+/// Once its script is done, the agent dies the next time it is polled.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct MockAgent {
+    /// The identifier of the agent.
+    pub id:     String,
+    /// The steps it has yet to take.
+    pub script: VecDeque<MockStep>,
+}
+impl MockAgent {
+    /// Constructor for the MockAgent.
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the agent.
+    /// - `script`: The [`MockStep`]s it takes, in order.
+    ///
+    /// # Returns
+    /// A new MockAgent.
+    #[inline]
+    pub fn new(id: impl Into<String>, script: impl IntoIterator<Item = MockStep>) -> Self { Self { id: id.into(), script: script.into_iter().collect() } }
+
+    /// Creates a message authored by this agent.
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the message, which is also its payload.
+    ///
+    /// # Returns
+    /// A new [`Message`].
+    #[inline]
+    pub fn message(&self, id: &str) -> Message { Message { id: id.into(), author: self.id.clone(), payload: id.as_bytes().to_vec(), lang: None } }
+}
+impl Identifiable for MockAgent {
+    type Id = str;
+
+    #[inline]
+    fn id(&self) -> &Self::Id { &self.id }
+}
+impl Agent for MockAgent {}
+impl RationalAgent for MockAgent {
+    type Message = Message;
+    type Target = Target;
+    type Error = MockFailure;
+
+    fn poll(
+        &mut self,
+        mut agrs: impl Agreements<Message = Self::Message>,
+        mut times: impl Times,
+        mut stmts: impl Statements<Message = Self::Message, Target = Self::Target>,
+    ) -> Result<AgentPoll<Self, Self::Message>, Self::Error> {
+        let Some(step) = self.script.pop_front() else {
+            return Ok(AgentPoll::Dead);
+        };
+        match step {
+            MockStep::State(ids) => {
+                for id in ids {
+                    stmts.state(Target::All, self.message(&id));
+                }
+                Ok(AgentPoll::Alive)
+            },
+            MockStep::StateUnless { id, unless } => {
+                if !agrs.agreed().contains(unless.as_str()) && !stmts.stated().contains(unless.as_str()) {
+                    stmts.state(Target::All, self.message(&id));
+                }
+                Ok(AgentPoll::Alive)
+            },
+            MockStep::Agree(id) => {
+                let agr: Agreement<Message> = match agrs.proposed().get(id.as_str()) {
+                    Some(agr) => (*agr).clone(),
+                    None => Agreement { msg: self.message(&id), applies: TimeRange::starting(times.current()) },
+                };
+                agrs.agree(agr).map_err(|_| MockFailure { agent: self.id.clone() })?;
+                Ok(AgentPoll::Alive)
+            },
+            MockStep::Enact { id, just } => {
+                // Find everything we need
+                let basis: Option<Agreement<Message>> = agrs.agreed().into_iter().min_by(|lhs, rhs| lhs.id().cmp(rhs.id())).cloned();
+                let known: LocalSet<&Message> = stmts.stated();
+                let msgs: Option<Vec<Message>> = just.iter().map(|id| known.get(id.as_str()).map(|msg| (*msg).clone())).collect();
+                let (Some(basis), Some(msgs)) = (basis, msgs) else {
+                    self.script.push_front(MockStep::Enact { id, just });
+                    return Ok(AgentPoll::Alive);
+                };
+
+                // Enact it
+                let enacts: Message = self.message(&id);
+                let mut just: LocalSet<Message> = msgs.into_iter().collect();
+                just.add(basis.msg.clone());
+                just.add(enacts.clone());
+                stmts.state(Target::All, enacts.clone());
+                stmts.enact(Target::All, Action { basis, just, enacts, timestamp: times.current() });
+                Ok(AgentPoll::Alive)
+            },
+            MockStep::Advance(time) => {
+                times.advance_to(time).map_err(|_| MockFailure { agent: self.id.clone() })?;
+                Ok(AgentPoll::Alive)
+            },
+            MockStep::Fail => Err(MockFailure { agent: self.id.clone() }),
+            MockStep::FailOn(id) => {
+                if agrs.agreed().contains(id.as_str()) || stmts.stated().contains(id.as_str()) {
+                    return Err(MockFailure { agent: self.id.clone() });
+                }
+                Ok(AgentPoll::Alive)
+            },
+            MockStep::Sleep(time) => Ok(AgentPoll::Sleep(time)),
+            MockStep::WaitFor(id) => Ok(AgentPoll::WaitFor(Box::new(move |msg: &Message| msg.id == id))),
+            MockStep::Spawn(agents) => Ok(AgentPoll::Spawn(agents)),
+        }
+    }
+}
@@ -0,0 +1,485 @@
+//  NETWORK.rs
+//    by Lut99
+//
+//  Created:
+//    16 Oct 2026, 20:14:51
+//  Last edited:
+//    16 Oct 2026, 22:21:53
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements a simulated network between the [`Statements`](crate::statements::Statements)
+//!   of agents and the [`GlobalStatements`](crate::statements::GlobalStatements), which
+//!   delays, drops and reorders whatever agents send.
+//
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter, Result as FResult};
+use std::hash::{Hash as _, Hasher};
+
+use justact_core::auxillary::Identifiable as _;
+use justact_core::statements::Action;
+use serde::{Deserialize, Serialize};
+
+use crate::explorer::{hash_action, hash_unordered};
+use crate::simulation::Shuffler;
+use crate::statements::Message;
+
+
+/***** TESTS *****/
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+
+    /// Creates a parcel with a stated message.
+    ///
+    /// # Arguments
+    /// - `id`: The identifier of the message, which is also its payload.
+    ///
+    /// # Returns
+    /// A new [`Parcel::Stmt`].
+    fn parcel(id: &str) -> Parcel { Parcel::Stmt(Message { id: id.into(), author: "a".into(), payload: id.as_bytes().to_vec(), lang: None }) }
+
+    /// Sends ten parcels from `a` to `b` over a link with jitter and collects them as they arrive.
+    ///
+    /// # Arguments
+    /// - `seed`: The seed of the [`Network`].
+    ///
+    /// # Returns
+    /// The identifiers of the parcels, in the order in which they arrived.
+    fn arrivals(seed: u64) -> Vec<String> {
+        let mut network: Network = Network::new(seed);
+        network.set_default_link(Link { latency: 1, jitter: 3, loss: 0.0 });
+        for i in 0..10 {
+            assert!(matches!(network.transmit("a", "b", parcel(&i.to_string())), Transmission::InFlight));
+        }
+        let arrived: Vec<String> = (1..=4).flat_map(|round| network.arrive(round)).map(|packet| packet.parcel.id().to_string()).collect();
+        assert_eq!(network.in_flight(), 0);
+        arrived
+    }
+
+
+    #[test]
+    fn test_network_seed() {
+        let arrived: Vec<String> = arrivals(42);
+        assert_eq!(arrived.len(), 10);
+        assert_eq!(arrivals(42), arrived);
+    }
+
+    #[test]
+    fn test_network_loss() {
+        let mut network: Network = Network::new(42);
+        network.set_default_link(Link { latency: 0, jitter: 0, loss: 1.0 });
+        network.set_link("a", "c", Link::with_latency(1));
+        for i in 0..10 {
+            assert!(matches!(network.transmit("a", "b", parcel(&i.to_string())), Transmission::Dropped(_, DropReason::Lost)));
+        }
+        assert!(matches!(network.transmit("a", "c", parcel("c")), Transmission::InFlight));
+        assert_eq!(network.in_flight(), 1);
+    }
+
+    #[test]
+    fn test_network_partition() {
+        let mut network: Network = Network::new(42);
+        network.partition([vec!["a"], vec!["b"]]);
+        assert!(!network.is_connected("a", "b"));
+        assert!(network.is_connected("a", "c"));
+        assert!(matches!(network.transmit("a", "b", parcel("x")), Transmission::Dropped(_, DropReason::Partitioned)));
+        assert!(matches!(network.transmit("b", "a", parcel("y")), Transmission::Dropped(_, DropReason::Partitioned)));
+        assert!(matches!(network.transmit("a", "c", parcel("z")), Transmission::Arrived(_)));
+    }
+
+    #[test]
+    fn test_network_heal() {
+        let mut network: Network = Network::new(42);
+        network.set_default_link(Link::with_latency(2));
+        assert!(matches!(network.transmit("a", "b", parcel("x")), Transmission::InFlight));
+
+        // Partitioning does not stop what is on its way...
+        network.partition([vec!["a"], vec!["b"]]);
+        assert!(matches!(network.transmit("a", "b", parcel("y")), Transmission::Dropped(_, DropReason::Partitioned)));
+        assert!(network.arrive(1).is_empty());
+
+        // ...and neither does healing
+        network.heal();
+        assert!(network.is_connected("a", "b"));
+        assert!(matches!(network.transmit("a", "b", parcel("z")), Transmission::InFlight));
+        let arrived: Vec<String> = network.arrive(2).into_iter().map(|packet| packet.parcel.id().to_string()).collect();
+        assert_eq!(arrived, vec!["x".to_string()]);
+        let arrived: Vec<String> = network.arrive(3).into_iter().map(|packet| packet.parcel.id().to_string()).collect();
+        assert_eq!(arrived, vec!["z".to_string()]);
+    }
+
+    #[test]
+    fn test_network_extreme_link() {
+        let mut network: Network = Network::new(42);
+        network.set_default_link(Link { latency: usize::MAX, jitter: usize::MAX, loss: 0.0 });
+        network.arrive(1);
+        for i in 0..10 {
+            assert!(matches!(network.transmit("a", "b", parcel(&i.to_string())), Transmission::InFlight));
+        }
+        assert!(network.arrive(usize::MAX - 1).is_empty());
+        assert_eq!(network.in_flight(), 10);
+    }
+}
+
+
+
+
+
+/***** AUXILLARY *****/
+/// Describes how elements travel from one agent to another.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Link {
+    /// The number of rounds it takes for an element to arrive. If this is 0, then elements
+    /// arrive as soon as they are sent.
+    pub latency: usize,
+    /// The maximum number of rounds an element may arrive after the `latency`. The actual number
+    /// is drawn for every element, such that elements may overtake each other.
+    pub jitter:  usize,
+    /// The probability (between 0 and 1) that an element is lost along the way.
+    pub loss:    f64,
+}
+impl Link {
+    /// Constructor for a Link that takes a fixed number of rounds and never loses anything.
+    ///
+    /// # Arguments
+    /// - `latency`: The number of rounds it takes for an element to arrive.
+    ///
+    /// # Returns
+    /// A new Link.
+    #[inline]
+    pub fn with_latency(latency: usize) -> Self { Self { latency, jitter: 0, loss: 0.0 } }
+}
+
+/// The reason why an element sent over a [`Network`] never arrived.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DropReason {
+    /// The element was lost along the way (see [`Link::loss`]).
+    Lost,
+    /// The sender and the recipient were in different partitions.
+    Partitioned,
+}
+impl Display for DropReason {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        match self {
+            Self::Lost => write!(f, "lost"),
+            Self::Partitioned => write!(f, "partitioned"),
+        }
+    }
+}
+
+/// How a [`Network`] is set up, i.e., everything about it except what is in flight.
+///
+/// This is what [`Event::Started`](crate::events::Event::Started) records of a network.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct NetworkConfig {
+    /// The seed that determines all randomness.
+    pub seed:       u64,
+    /// The link used between agents without a specific one.
+    pub default:    Link,
+    /// Specific links from one agent (the first) to another (the second), sorted.
+    pub links:      Vec<(String, String, Link)>,
+    /// Groups of agents that can only reach each other, each sorted.
+    pub partitions: Vec<Vec<String>>,
+}
+
+/// Something sent over a [`Network`].
+#[derive(Clone, Debug)]
+pub(crate) enum Parcel {
+    /// A stated message.
+    Stmt(Message),
+    /// An enacted action, boxed to keep parcels small.
+    Enct(Box<Action<Message>>),
+}
+impl Parcel {
+    /// Returns the identifier of the message stated, or of the message enacted by the action.
+    ///
+    /// # Returns
+    /// The identifier of the parcel's message.
+    #[inline]
+    pub(crate) fn id(&self) -> &str {
+        match self {
+            Self::Stmt(msg) => msg.id(),
+            Self::Enct(act) => act.enacts.id(),
+        }
+    }
+
+    /// Returns whether this is an enacted action rather than a stated message.
+    ///
+    /// # Returns
+    /// True if this is a [`Parcel::Enct`], or false otherwise.
+    #[inline]
+    pub(crate) fn is_enacted(&self) -> bool { matches!(self, Self::Enct(_)) }
+
+    /// Hashes the parcel.
+    ///
+    /// # Arguments
+    /// - `state`: The [`Hasher`] to hash into.
+    #[inline]
+    fn fingerprint(&self, state: &mut impl Hasher) {
+        match self {
+            Self::Stmt(msg) => {
+                state.write_u8(0);
+                msg.hash(state);
+            },
+            Self::Enct(act) => {
+                state.write_u8(1);
+                hash_action(act, state);
+            },
+        }
+    }
+}
+
+/// A [`Parcel`] that is travelling over a [`Network`].
+#[derive(Clone, Debug)]
+pub(crate) struct Packet {
+    /// The round in which it arrives.
+    due:               usize,
+    /// Decides the order of packets arriving in the same round.
+    key:               u64,
+    /// The agent who sent it.
+    pub(crate) from:   String,
+    /// The agent who receives it.
+    pub(crate) to:     String,
+    /// Whatever is sent.
+    pub(crate) parcel: Parcel,
+}
+
+/// What happened to a [`Parcel`] sent over a [`Network`].
+#[derive(Debug)]
+pub(crate) enum Transmission {
+    /// It arrived immediately.
+    Arrived(Parcel),
+    /// It arrives in some later round.
+    InFlight,
+    /// It never arrives.
+    Dropped(Parcel, DropReason),
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// A simulated network that delays, drops and reorders the statements and enactments agents send
+/// to each other.
+///
+/// Every pair of agents is connected by a [`Link`] in either direction, which determines how
+/// many rounds it takes for elements to arrive and how likely they are lost. Agents can also be
+/// partitioned into groups that cannot reach each other. All randomness is drawn from a
+/// generator determined by the seed, such that running the same agents with the same seed
+/// delivers the same elements in the same order.
+///
+/// Agents always learn of what they send themselves immediately, and elements sent to all agents
+/// are sent to every agent individually.
+#[derive(Clone, Debug)]
+pub struct Network {
+    /// The seed that determines all randomness.
+    seed:       u64,
+    /// The link used between agents without a specific one.
+    default:    Link,
+    /// Specific links from one agent to another.
+    links:      HashMap<(String, String), Link>,
+    /// Groups of agents that can only reach each other.
+    partitions: Vec<HashSet<String>>,
+    /// The generator drawing all randomness.
+    rng:        Shuffler,
+    /// The current round.
+    now:        usize,
+    /// The elements that have been sent but not yet arrived.
+    in_flight:  Vec<Packet>,
+}
+impl Network {
+    /// Constructor for a Network in which elements arrive immediately and are never lost.
+    ///
+    /// # Arguments
+    /// - `seed`: The seed that determines which elements are delayed, lost and reordered.
+    ///
+    /// # Returns
+    /// A new Network.
+    #[inline]
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            default: Link::default(),
+            links: HashMap::new(),
+            partitions: Vec::new(),
+            rng: Shuffler::new(seed),
+            now: 0,
+            in_flight: Vec::new(),
+        }
+    }
+
+    /// Returns the seed that determines which elements are delayed, lost and reordered.
+    ///
+    /// # Returns
+    /// The seed of this network.
+    #[inline]
+    pub fn seed(&self) -> u64 { self.seed }
+
+    /// Returns how this network is set up.
+    ///
+    /// # Returns
+    /// A [`NetworkConfig`] with the seed, links and partitions of this network.
+    pub fn config(&self) -> NetworkConfig {
+        let mut links: Vec<(String, String, Link)> = self.links.iter().map(|((from, to), link)| (from.clone(), to.clone(), *link)).collect();
+        links.sort_by(|lhs, rhs| (&lhs.0, &lhs.1).cmp(&(&rhs.0, &rhs.1)));
+        let partitions: Vec<Vec<String>> = self
+            .partitions
+            .iter()
+            .map(|group| {
+                let mut group: Vec<String> = group.iter().cloned().collect();
+                group.sort_unstable();
+                group
+            })
+            .collect();
+        NetworkConfig { seed: self.seed, default: self.default, links, partitions }
+    }
+
+    /// Sets the link used between agents without a specific one.
+    ///
+    /// # Arguments
+    /// - `link`: The [`Link`] to use.
+    #[inline]
+    pub fn set_default_link(&mut self, link: Link) { self.default = link; }
+
+    /// Sets the link from one agent to another.
+    ///
+    /// Note that this only applies in one direction.
+    ///
+    /// # Arguments
+    /// - `from`: The identifier of the agent sending over the link.
+    /// - `to`: The identifier of the agent receiving over the link.
+    /// - `link`: The [`Link`] to use.
+    #[inline]
+    pub fn set_link(&mut self, from: impl Into<String>, to: impl Into<String>, link: Link) { self.links.insert((from.into(), to.into()), link); }
+
+    /// Returns the link from one agent to another.
+    ///
+    /// # Arguments
+    /// - `from`: The identifier of the agent sending over the link.
+    /// - `to`: The identifier of the agent receiving over the link.
+    ///
+    /// # Returns
+    /// The specific [`Link`] between the agents, or the default one if there is none.
+    #[inline]
+    pub fn link(&self, from: &str, to: &str) -> Link { self.links.get(&(from.into(), to.into())).copied().unwrap_or(self.default) }
+
+    /// Partitions the agents into groups that cannot reach each other.
+    ///
+    /// Agents that are not in any group can still reach everybody. Partitions only affect elements
+    /// sent while they are in place; elements that are already on their way still arrive.
+    ///
+    /// # Arguments
+    /// - `groups`: The groups of agent identifiers. This replaces any previous partitions.
+    pub fn partition<I>(&mut self, groups: impl IntoIterator<Item = I>)
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.partitions = groups.into_iter().map(|group| group.into_iter().map(Into::into).collect()).collect();
+    }
+
+    /// Removes all partitions, such that every agent can reach everybody again.
+    #[inline]
+    pub fn heal(&mut self) { self.partitions.clear(); }
+
+    /// Checks whether one agent can reach another.
+    ///
+    /// # Arguments
+    /// - `from`: The identifier of the agent sending something.
+    /// - `to`: The identifier of the agent receiving it.
+    ///
+    /// # Returns
+    /// True if both agents are in the same partition, or if either is in none, or false otherwise.
+    #[inline]
+    pub fn is_connected(&self, from: &str, to: &str) -> bool {
+        let from: Option<usize> = self.partitions.iter().position(|group| group.contains(from));
+        let to: Option<usize> = self.partitions.iter().position(|group| group.contains(to));
+        from.is_none() || to.is_none() || from == to
+    }
+
+    /// Returns the number of elements that have been sent but not yet arrived.
+    ///
+    /// # Returns
+    /// The number of elements in flight.
+    #[inline]
+    pub fn in_flight(&self) -> usize { self.in_flight.len() }
+
+    /// Sends something from one agent to another.
+    ///
+    /// # Arguments
+    /// - `from`: The identifier of the agent sending it.
+    /// - `to`: The identifier of the agent receiving it.
+    /// - `parcel`: The [`Parcel`] to send.
+    ///
+    /// # Returns
+    /// A [`Transmission`] describing whether it arrived immediately, later or never.
+    pub(crate) fn transmit(&mut self, from: &str, to: &str, parcel: Parcel) -> Transmission {
+        if !self.is_connected(from, to) {
+            return Transmission::Dropped(parcel, DropReason::Partitioned);
+        }
+        let link: Link = self.link(from, to);
+        if link.loss > 0.0 && self.chance() < link.loss {
+            return Transmission::Dropped(parcel, DropReason::Lost);
+        }
+
+        // Decide when it arrives, saturating since parcels due after the last round never arrive anyway
+        let jitter: usize = if link.jitter > 0 { (u128::from(self.rng.next()) % (link.jitter as u128 + 1)) as usize } else { 0 };
+        let delay: usize = link.latency.saturating_add(jitter);
+        if delay == 0 {
+            return Transmission::Arrived(parcel);
+        }
+        let key: u64 = self.rng.next();
+        self.in_flight.push(Packet { due: self.now.saturating_add(delay), key, from: from.into(), to: to.into(), parcel });
+        Transmission::InFlight
+    }
+
+    /// Moves to the next round, returning everything that arrives in it.
+    ///
+    /// # Arguments
+    /// - `round`: The round that starts.
+    ///
+    /// # Returns
+    /// The [`Packet`]s that arrive, in the order in which they arrive. Packets that arrive in the
+    /// same round are ordered randomly.
+    pub(crate) fn arrive(&mut self, round: usize) -> Vec<Packet> {
+        self.now = round;
+        let (mut arrived, in_flight): (Vec<Packet>, Vec<Packet>) = std::mem::take(&mut self.in_flight).into_iter().partition(|packet| packet.due <= round);
+        self.in_flight = in_flight;
+        arrived.sort_by_key(|packet| (packet.due, packet.key));
+        arrived
+    }
+
+    /// Draws a random number between 0 (inclusive) and 1 (exclusive).
+    ///
+    /// # Returns
+    /// A pseudo-random [`f64`].
+    #[inline]
+    fn chance(&mut self) -> f64 { (self.rng.next() >> 11) as f64 / (1u64 << 53) as f64 }
+
+    /// Hashes the elements in flight and the state of the generator.
+    ///
+    /// # Arguments
+    /// - `state`: The [`Hasher`] to hash into.
+    pub(crate) fn fingerprint(&self, state: &mut impl Hasher) {
+        self.rng.fingerprint(state);
+        self.now.hash(state);
+        hash_unordered(
+            &self.in_flight,
+            |packet, state| {
+                packet.due.hash(state);
+                packet.key.hash(state);
+                packet.from.hash(state);
+                packet.to.hash(state);
+                packet.parcel.fingerprint(state);
+            },
+            state,
+        );
+    }
+}
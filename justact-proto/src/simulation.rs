@@ -4,7 +4,7 @@
 //  Created:
 //    16 Apr 2024, 11:06:51
 //  Last edited:
-//    29 May 2024, 14:04:25
+//    16 Oct 2026, 22:19:57
 //  Auto updated?
 //    Yes
 //
@@ -14,24 +14,333 @@
 
 use std::any::type_name;
 use std::cell::RefCell;
-use std::collections::HashSet;
-use std::convert::Infallible;
+use std::collections::hash_map::RandomState;
+use std::collections::{HashMap, HashSet};
 use std::error;
-use std::fmt::{Display, Formatter, Result as FResult};
+use std::fmt::{Debug, Display, Formatter, Result as FResult};
+use std::hash::{BuildHasher as _, Hash, Hasher};
+use std::io;
+use std::num::NonZeroUsize;
 use std::rc::Rc;
+use std::thread;
 
 use console::Style;
 use justact_core::agents::{AgentPoll, RationalAgent};
+use justact_core::agreements::{Agreement, Agreements as _};
+use justact_core::auditors::Auditor;
 use justact_core::auxillary::Identifiable;
 use justact_core::policy::Extractor;
+use justact_core::search::analyze_justification;
 use justact_core::set::LocalSet;
-use log::{debug, info};
-use stackvec::StackVec;
+use justact_core::statements::{Action, Statements as _};
+use justact_core::times::{Times as _, Timestamp};
+use log::{debug, info, warn};
+use serde::{Deserialize, Serialize};
 
-use crate::agreements::GlobalAgreementsDictator;
+use crate::agreements::{Agreements, AgreementsQueue, GlobalAgreements, GlobalAgreementsConsensus, GlobalAgreementsDictator, Quorum};
+use crate::events::{Decision, Event, EventSink};
+use crate::explorer::hash_unordered;
 use crate::interface::Interface;
-use crate::statements::{GlobalStatements, Message, Target};
-use crate::times::GlobalTimesDictator;
+use crate::network::Network;
+use crate::statements::{GlobalStatements, Message, Statements, Target};
+use crate::times::{GlobalTimes, GlobalTimesConsensus, GlobalTimesDictator, Times};
+
+
+/***** TESTS *****/
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+
+    use justact_core::auditors::Paper;
+    use serde_json::Value;
+
+    use super::*;
+    use crate::events::MemorySink;
+    use crate::mock::{MockAgent, MockExtractor, MockStep};
+
+
+    /// Creates a quiet [`Simulation`] with the given agents registered.
+    ///
+    /// # Arguments
+    /// - `dictator`: The agent that gets to update the agreements and the time.
+    /// - `agents`: The [`MockAgent`]s to register.
+    ///
+    /// # Returns
+    /// A new [`Simulation`].
+    fn simulation(dictator: &str, agents: impl IntoIterator<Item = MockAgent>) -> Simulation<MockAgent> {
+        let mut sim: Simulation<MockAgent> = Simulation::new(dictator);
+        sim.set_quiet(true);
+        for agent in agents {
+            sim.register(agent, Style::new());
+        }
+        sim
+    }
+
+    /// Polls a [`Simulation`] until no agent is awake anymore.
+    ///
+    /// # Returns
+    /// The failures of agents, as pairs of the agent and the [`FailurePolicy`] applied, per round.
+    fn poll_all(sim: &mut Simulation<MockAgent>) -> Vec<Vec<(String, FailurePolicy)>> {
+        let mut rounds: Vec<Vec<(String, FailurePolicy)>> = Vec::new();
+        loop {
+            let report: PollReport<_> = sim.poll().unwrap();
+            rounds.push(report.failures.into_iter().map(|failure| (failure.agent, failure.policy)).collect());
+            if !report.alive {
+                return rounds;
+            }
+        }
+    }
+
+
+    /// Serializes an [`Event`] such that it can be compared.
+    ///
+    /// Justifications are sets, so their messages are sorted by identifier.
+    ///
+    /// # Arguments
+    /// - `event`: The [`Event`] to serialize.
+    ///
+    /// # Returns
+    /// The event as JSON.
+    fn serialize(event: &Event) -> String {
+        fn sort_just(value: &mut Value) {
+            match value {
+                Value::Object(fields) => {
+                    for (key, field) in fields.iter_mut() {
+                        if let (true, Value::Array(msgs)) = (key == "just", &mut *field) {
+                            msgs.sort_by(|lhs, rhs| lhs["id"].as_str().cmp(&rhs["id"].as_str()));
+                        }
+                        sort_just(field);
+                    }
+                },
+                Value::Array(elems) => elems.iter_mut().for_each(sort_just),
+                _ => {},
+            }
+        }
+        let mut value: Value = serde_json::to_value(event).unwrap();
+        sort_just(&mut value);
+        value.to_string()
+    }
+
+    /// Runs a scenario in which agents only depend on what others did in earlier rounds.
+    ///
+    /// # Arguments
+    /// - `run`: Some function that runs the [`Simulation`] to completion.
+    ///
+    /// # Returns
+    /// The fingerprint of the simulation afterwards, and its events serialized as JSON.
+    fn scenario(run: impl FnOnce(&mut Simulation<MockAgent>)) -> (u64, Vec<String>) {
+        let state = |ids: &[&str]| -> MockStep { MockStep::State(ids.iter().map(|id| id.to_string()).collect()) };
+        let mut sim: Simulation<MockAgent> = simulation("e", [
+            MockAgent::new("a", [MockStep::Enact { id: "a-act".into(), just: vec!["c1".into(), "c2".into()] }, state(&["a1"])]),
+            MockAgent::new("b", [MockStep::Enact { id: "b-act".into(), just: vec!["error".into()] }]),
+            MockAgent::new("c", [state(&["c1", "c2"]), state(&["c3"]), MockStep::Sleep(Timestamp(1)), state(&["c4"])]),
+            MockAgent::new("d", [state(&["error"])]),
+            MockAgent::new("e", [MockStep::Agree("rules".into()), MockStep::Advance(Timestamp(1))]),
+        ]);
+        let sink: MemorySink = MemorySink::new();
+        sim.add_sink(sink.clone());
+        run(&mut sim);
+
+        let mut state: DefaultHasher = DefaultHasher::new();
+        sim.fingerprint(&mut state);
+        (state.finish(), sink.take().iter().map(serialize).collect())
+    }
+
+
+    #[test]
+    fn test_simulation_parallel() {
+        let (fingerprint, events): (u64, Vec<String>) = scenario(|sim| {
+            sim.run::<MockExtractor>(Paper).unwrap();
+        });
+        assert!(!events.iter().any(|event| event.contains("audit_failed") && event.contains("a-act")));
+        assert!(events.iter().any(|event| event.contains("audit_failed") && event.contains("b-act")));
+
+        // The parallel backend ends up in the same state with the same events, however many threads
+        // it uses and however often it runs
+        for threads in [1, 2, 4, 1, 2, 4] {
+            let parallel: (u64, Vec<String>) = scenario(|sim| {
+                sim.set_threads(threads);
+                sim.run_parallel::<MockExtractor>(Paper).unwrap();
+            });
+            assert_eq!(parallel, (fingerprint, events.clone()), "with {threads} threads");
+        }
+    }
+
+    #[test]
+    fn test_simulation_shuffled() {
+        // Returns the order in which agents were polled in every round
+        let order = |schedule: Schedule| -> Vec<Vec<String>> {
+            let agents = ["a", "b", "c", "d", "e"].map(|id| {
+                MockAgent::new(id, (1..=3).map(|round| MockStep::State(vec![format!("{id}{round}")])))
+            });
+            let mut sim: Simulation<MockAgent> = simulation("a", agents);
+            sim.set_schedule(schedule);
+            let sink: MemorySink = MemorySink::new();
+            sim.add_sink(sink.clone());
+            poll_all(&mut sim);
+
+            let mut order: Vec<Vec<String>> = vec![Vec::new(); 3];
+            for event in sink.take() {
+                if let Event::Stated { round, agent, .. } = event {
+                    order[round - 1].push(agent);
+                }
+            }
+            order
+        };
+
+        // The same seed polls the agents the same way, and a different seed does not
+        let in_order: Vec<Vec<String>> = order(Schedule::InOrder);
+        assert_eq!(in_order, vec![vec!["a", "b", "c", "d", "e"]; 3]);
+        let shuffled: Vec<Vec<String>> = order(Schedule::Shuffled { seed: 42 });
+        assert_ne!(shuffled, in_order);
+        assert_eq!(order(Schedule::Shuffled { seed: 42 }), shuffled);
+        assert_ne!(order(Schedule::Shuffled { seed: 43 }), shuffled);
+
+        // The seed of a random schedule can be used to reproduce it
+        let schedule: Schedule = Schedule::shuffled();
+        assert_eq!(order(Schedule::Shuffled { seed: schedule.seed().unwrap() }), order(schedule));
+    }
+
+    #[test]
+    fn test_simulation_failure_policies() {
+        let mut sim: Simulation<MockAgent> = simulation("a", [
+            MockAgent::new("a", [MockStep::Fail, MockStep::Fail, MockStep::State(vec!["a1".into()])]),
+            MockAgent::new("b", [MockStep::Fail, MockStep::Fail, MockStep::State(vec!["b1".into()])]),
+            MockAgent::new("c", [MockStep::Fail, MockStep::State(vec!["c1".into()])]),
+        ]);
+        sim.set_failure_policy("a", FailurePolicy::Retry { max: 2 });
+        sim.set_failure_policy("b", FailurePolicy::Retry { max: 1 });
+        sim.set_failure_policy("c", FailurePolicy::Quarantine);
+        let retry = |max: usize| FailurePolicy::Retry { max };
+        assert_eq!(poll_all(&mut sim), vec![
+            vec![("a".into(), retry(2)), ("b".into(), retry(1)), ("c".into(), FailurePolicy::Quarantine)],
+            // `b` has run out of retries...
+            vec![("a".into(), retry(2)), ("b".into(), FailurePolicy::Kill)],
+            // ...while `a` makes it
+            vec![],
+            vec![],
+        ]);
+
+        // Only `a` got to state anything, and `c` is kept around without being polled
+        let stated: Vec<&str> = sim.stmts.stated().into_iter().map(|msg| msg.id.as_str()).collect();
+        assert_eq!(stated, vec!["a1"]);
+        assert_eq!(sim.quarantined(), &[MockAgent::new("c", [MockStep::State(vec!["c1".into()])])]);
+    }
+
+    #[test]
+    fn test_simulation_sleep_wait_spawn() {
+        let child: MockAgent = MockAgent::new("child", [MockStep::State(vec!["y".into()])]);
+        let mut sim: Simulation<MockAgent> = simulation("a", [
+            MockAgent::new("a", [MockStep::State(vec!["x".into()]), MockStep::Advance(Timestamp(2))]),
+            MockAgent::new("sleeper", [MockStep::Sleep(Timestamp(2)), MockStep::State(vec!["woke".into()])]),
+            MockAgent::new("waiter", [MockStep::WaitFor("y".into()), MockStep::State(vec!["got-y".into()])]),
+            MockAgent::new("parent", [MockStep::Spawn(vec![child])]),
+        ]);
+        let sink: MemorySink = MemorySink::new();
+        sim.add_sink(sink.clone());
+        poll_all(&mut sim);
+
+        // The child is polled the round after it is spawned, and the others wake up the round
+        // after the time or the message they wait for is there
+        let mut stated: Vec<(usize, String, String)> = sink
+            .take()
+            .into_iter()
+            .filter_map(|event| if let Event::Stated { round, agent, msg, .. } = event { Some((round, agent, msg.id)) } else { None })
+            .collect();
+        stated.sort_unstable();
+        assert_eq!(stated, vec![
+            (1, "a".into(), "x".into()),
+            (2, "child".into(), "y".into()),
+            (3, "sleeper".into(), "woke".into()),
+            (3, "waiter".into(), "got-y".into()),
+        ]);
+    }
+
+    #[test]
+    fn test_simulation_many_agents() {
+        // Well over the 64 agents we used to be limited to
+        const AGENTS: usize = 200;
+        for parallel in [false, true] {
+            let agents = (0..AGENTS).map(|i| MockAgent::new(format!("agent{i}"), [MockStep::State(vec![format!("msg{i}")])]));
+            let mut sim: Simulation<MockAgent> = simulation("agent0", agents);
+            if parallel {
+                sim.poll_parallel().unwrap();
+            } else {
+                sim.poll().unwrap();
+            }
+
+            // Every agent was polled, and learned of what every other agent stated
+            assert_eq!(sim.stmts.stated().len(), AGENTS, "parallel: {parallel}");
+            for i in 0..AGENTS {
+                let id: String = format!("agent{i}");
+                assert_eq!(sim.stmts.known_by(&id).count(), AGENTS, "for agent '{id}' (parallel: {parallel})");
+            }
+        }
+    }
+
+    #[test]
+    fn test_simulation_with_agents() {
+        let mut sim: Simulation<MockAgent> =
+            Simulation::with_agents("a", [MockAgent::new("a", [MockStep::State(vec!["x".into()])]), MockAgent::new("b", [])]);
+        sim.set_quiet(true);
+        sim.poll().unwrap();
+
+        // The agents are registered as usual, so broadcasts reach them
+        for id in ["a", "b"] {
+            let known: Vec<&str> = sim.stmts.known_by(id).map(|msg| msg.id.as_str()).collect();
+            assert_eq!(known, vec!["x"], "for agent '{id}'");
+        }
+    }
+
+    #[test]
+    fn test_simulation_failure_abort() {
+        // Runs a round in which `a` aborts before `b` is polled, returning the simulation and its events
+        let abort = |parallel: bool| -> (Simulation<MockAgent>, Vec<Event>) {
+            let mut sim: Simulation<MockAgent> =
+                simulation("a", [MockAgent::new("a", [MockStep::Fail]), MockAgent::new("b", [MockStep::State(vec!["b1".into()])])]);
+            let sink: MemorySink = MemorySink::new();
+            sim.add_sink(sink.clone());
+            let res = if parallel { sim.poll_parallel() } else { sim.poll() };
+            assert!(matches!(res, Err(Error::AgentPoll { agent, .. }) if agent == "a"));
+
+            // Both agents are still there, and the sinks heard of the failure and the end of the round
+            let mut ids: Vec<&str> = sim.agents.iter().map(|agent| agent.id()).collect();
+            ids.sort_unstable();
+            assert_eq!(ids, vec!["a", "b"]);
+            let events: Vec<Event> = sink.take();
+            assert!(
+                events.iter().any(|event| matches!(event, Event::AgentFailed { agent, policy: FailurePolicy::Abort, .. } if agent == "a"))
+            );
+            assert!(events.iter().any(|event| matches!(event, Event::Finished { round: 1 })));
+            (sim, events)
+        };
+        let stated = |events: &[Event]| -> Vec<String> {
+            events.iter().filter_map(|event| if let Event::Stated { msg, .. } = event { Some(msg.id.clone()) } else { None }).collect()
+        };
+
+        // Sequentially, `b` is only polled the next round...
+        let (mut sim, events): (Simulation<MockAgent>, Vec<Event>) = abort(false);
+        assert!(stated(&events).is_empty());
+        sim.poll().unwrap();
+        let ids: Vec<&str> = sim.stmts.stated().into_iter().map(|msg| msg.id.as_str()).collect();
+        assert_eq!(ids, vec!["b1"]);
+
+        // ...while in parallel, it was already polled and its changes are kept
+        let (_, events): (Simulation<MockAgent>, Vec<Event>) = abort(true);
+        assert_eq!(stated(&events), vec!["b1".to_string()]);
+    }
+}
+
+
+
+
+
+/***** CONSTANTS *****/
+/// The maximum number of candidate justifications tried when analyzing an action's justification for superfluous messages.
+pub const ANALYSIS_BUDGET: usize = 1 << 12;
+
+
+
 
 
 /***** ERROR *****/
@@ -40,12 +349,15 @@ use crate::times::GlobalTimesDictator;
 pub enum Error<E> {
     /// Some agent errored.
     AgentPoll { agent: String, err: E },
+    /// Some [`EventSink`] failed to handle the events of a round.
+    Sink { err: io::Error },
 }
 impl<E: Display> Display for Error<E> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
         use Error::*;
         match self {
             AgentPoll { agent, .. } => write!(f, "Failed to poll agent {agent}"),
+            Sink { .. } => write!(f, "Failed to flush event sink"),
         }
     }
 }
@@ -54,10 +366,292 @@ impl<E: 'static + error::Error> error::Error for Error<E> {
         use Error::*;
         match self {
             AgentPoll { err, .. } => Some(err),
+            Sink { err } => Some(err),
+        }
+    }
+}
+
+
+
+
+
+/***** AUXILLARY *****/
+/// Determines what the [`Simulation`] does when an agent fails to poll.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub enum FailurePolicy {
+    /// Stop the whole simulation with an [`Error::AgentPoll`].
+    #[default]
+    Abort,
+    /// Remove the agent from the simulation, as if it died.
+    Kill,
+    /// Poll the agent again the next round, unless it has failed more than `max` times in a row.
+    /// In that case, it is killed.
+    Retry { max: usize },
+    /// Stop polling the agent, but keep it around for inspection (see
+    /// [`Simulation::quarantined()`]).
+    Quarantine,
+}
+impl Display for FailurePolicy {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use FailurePolicy::*;
+        match self {
+            Abort => write!(f, "aborting simulation"),
+            Kill => write!(f, "killing agent"),
+            Retry { max } => write!(f, "retrying agent next round (at most {max} times)"),
+            Quarantine => write!(f, "quarantining agent"),
+        }
+    }
+}
+
+/// Determines the order in which the [`Simulation`] polls its agents every round.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Schedule {
+    /// Poll agents in the order in which they were registered.
+    #[default]
+    InOrder,
+    /// Poll agents in a different random order every round. The orders are fully determined by
+    /// the `seed`, such that running the same agents with the same seed polls them the same way.
+    Shuffled { seed: u64 },
+}
+impl Schedule {
+    /// Constructor for a [`Schedule::Shuffled`] with a seed that is different every time.
+    ///
+    /// # Returns
+    /// A new Schedule that shuffles the agents. Use [`Schedule::seed()`] to find out which order
+    /// it will produce.
+    #[inline]
+    pub fn shuffled() -> Self { Self::Shuffled { seed: RandomState::new().build_hasher().finish() } }
+
+    /// Returns the seed that determines the order of agents, if any.
+    ///
+    /// # Returns
+    /// The seed of a [`Schedule::Shuffled`], or [`None`] if the agents are polled in order.
+    #[inline]
+    pub fn seed(&self) -> Option<u64> {
+        match self {
+            Self::InOrder => None,
+            Self::Shuffled { seed } => Some(*seed),
+        }
+    }
+}
+impl Display for Schedule {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use Schedule::*;
+        match self {
+            InOrder => write!(f, "in order"),
+            Shuffled { seed } => write!(f, "in random order (seed {seed})"),
+        }
+    }
+}
+
+/// Shuffles the agents of a [`Simulation`] with a [`Schedule::Shuffled`].
+///
+/// This implements the SplitMix64 generator instead of relying on some crate, such that the same
+/// seed keeps producing the same orders across versions of dependencies. It is also used to
+/// draw the randomness of a [`Network`].
+#[derive(Clone, Debug)]
+pub(crate) struct Shuffler {
+    /// The current state of the generator.
+    state: u64,
+}
+impl Shuffler {
+    /// Constructor for the Shuffler.
+    ///
+    /// # Arguments
+    /// - `seed`: The seed that determines all orders produced.
+    ///
+    /// # Returns
+    /// A new Shuffler.
+    #[inline]
+    pub(crate) fn new(seed: u64) -> Self { Self { state: seed } }
+
+    /// Generates the next random number.
+    ///
+    /// # Returns
+    /// A pseudo-random [`u64`].
+    #[inline]
+    pub(crate) fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z: u64 = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Hashes the current state of the generator.
+    ///
+    /// # Arguments
+    /// - `state`: The [`Hasher`] to hash into.
+    #[inline]
+    pub(crate) fn fingerprint(&self, state: &mut impl Hasher) { state.write_u64(self.state); }
+
+    /// Shuffles the given elements (using Fisher-Yates).
+    ///
+    /// # Arguments
+    /// - `elems`: The elements to shuffle in-place.
+    fn shuffle<T>(&mut self, elems: &mut [T]) {
+        for i in (1..elems.len()).rev() {
+            let j: usize = (self.next() % (i as u64 + 1)) as usize;
+            elems.swap(i, j);
         }
     }
 }
 
+/// Determines when a parked agent in the [`Simulation`] is polled again.
+#[derive(Clone)]
+enum Wake {
+    /// When the current time is at least the given one.
+    At(Timestamp),
+    /// When a message matching the predicate is stated to the agent or agreed upon.
+    On(Rc<dyn Fn(&Message) -> bool>),
+}
+impl Debug for Wake {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        match self {
+            Self::At(time) => f.debug_tuple("At").field(time).finish(),
+            Self::On(_) => f.debug_tuple("On").field(&"<predicate>").finish(),
+        }
+    }
+}
+
+/// The result of polling an agent `A`.
+pub(crate) type PollResult<A> = Result<AgentPoll<A, Message>, <A as RationalAgent>::Error>;
+
+/// Collects what happens to agents during a single round of the [`Simulation`].
+pub(crate) struct Round<A: RationalAgent> {
+    /// The agents to poll again next round.
+    pub(crate) next:     Vec<A>,
+    /// The agents spawned this round, together with the agent that spawned them.
+    pub(crate) spawned:  Vec<(String, A)>,
+    /// The failures of agents this round.
+    pub(crate) failures: Vec<AgentFailure<A::Error>>,
+}
+impl<A: RationalAgent> Round<A> {
+    /// Constructor for a Round in which nothing happened yet.
+    ///
+    /// # Arguments
+    /// - `capacity`: The number of agents polled this round.
+    ///
+    /// # Returns
+    /// A new Round.
+    #[inline]
+    pub(crate) fn with_capacity(capacity: usize) -> Self { Self { next: Vec::with_capacity(capacity), spawned: Vec::new(), failures: Vec::new() } }
+
+    /// Copies this Round, except for the failures that happened so far.
+    ///
+    /// # Returns
+    /// A new Round with the same agents to poll next round and spawned agents.
+    #[inline]
+    pub(crate) fn fork(&self) -> Self
+    where
+        A: Clone,
+    {
+        Self { next: self.next.clone(), spawned: self.spawned.clone(), failures: Vec::new() }
+    }
+}
+
+/// The changes an agent wants to make to the [`Simulation`] after it has been polled.
+#[derive(Clone, Debug)]
+pub(crate) struct Changes {
+    /// The agreements (or votes on them) it queued.
+    pub(crate) agrs:  AgreementsQueue,
+    /// The times it queued.
+    pub(crate) times: Vec<Timestamp>,
+    /// The statements it queued.
+    pub(crate) stmts: Vec<(Target, Message)>,
+    /// The enactments it queued.
+    pub(crate) encts: Vec<(Target, Action<Message>)>,
+}
+
+/// An agent to poll on some thread, together with its views on the [`Simulation`].
+struct Job<'v, A: RationalAgent> {
+    /// The agent to poll.
+    agent: A,
+    /// The agent's view on the agreements.
+    agrs:  Agreements<'v>,
+    /// The agent's view on the times.
+    times: Times<'v>,
+    /// The agent's view on the statements.
+    stmts: Statements<'v>,
+    /// The result of polling the agent, once it is polled.
+    res:   Option<PollResult<A>>,
+}
+impl<'v, A: RationalAgent> Job<'v, A> {
+    /// Releases the views of a polled job, keeping only the changes the agent made.
+    ///
+    /// # Returns
+    /// A [`Polled`] with the agent, its result and its changes.
+    ///
+    /// # Panics
+    /// This function panics if the job's agent was not polled.
+    #[inline]
+    fn into_polled(self) -> Polled<A> {
+        Polled {
+            agent:   self.agent,
+            res:     self.res.unwrap_or_else(|| panic!("Agent was not polled")),
+            changes: Changes { agrs: self.agrs.into_queue(), times: self.times.into_queue(), stmts: self.stmts.stmts_queue, encts: self.stmts.encts_queue },
+        }
+    }
+}
+
+/// An agent that was polled without synchronizing its changes yet.
+pub(crate) struct Polled<A: RationalAgent> {
+    /// The agent that was polled.
+    pub(crate) agent:   A,
+    /// The result of polling it.
+    pub(crate) res:     PollResult<A>,
+    /// The changes it wants to make.
+    pub(crate) changes: Changes,
+}
+
+/// Records a single failure of an agent during a [`Simulation`].
+///
+/// # Generics
+/// - `E`: The type of error the agent failed with.
+#[derive(Debug)]
+pub struct AgentFailure<E> {
+    /// The agent that failed.
+    pub agent:  String,
+    /// The round in which it failed, starting at 1.
+    pub round:  usize,
+    /// The error it failed with.
+    pub err:    E,
+    /// The [`FailurePolicy`] that was applied. If the agent ran out of retries, this is
+    /// [`FailurePolicy::Kill`].
+    pub policy: FailurePolicy,
+}
+
+/// Describes the outcome of a single [`Simulation::poll()`].
+///
+/// # Generics
+/// - `E`: The type of error agents may fail with.
+#[derive(Debug)]
+pub struct PollReport<E> {
+    /// Whether at least one agent is still awake. If not, any sleeping or waiting agents will
+    /// never wake up either, as nobody is left to change anything.
+    pub alive:    bool,
+    /// The failures of agents during this round.
+    pub failures: Vec<AgentFailure<E>>,
+}
+
+/// Describes the outcome of a [`Simulation::run()`].
+///
+/// # Generics
+/// - `E`: The type of error agents may fail with.
+#[derive(Debug)]
+pub struct RunReport<E> {
+    /// The number of rounds that were run.
+    pub rounds:   usize,
+    /// The schedule with which agents were polled. Run the simulation again with the same
+    /// schedule to reproduce the run.
+    pub schedule: Schedule,
+    /// The failures of agents during the run, in the order they occurred.
+    pub failures: Vec<AgentFailure<E>>,
+}
+
 
 
 
@@ -73,15 +667,35 @@ impl<E: 'static + error::Error> error::Error for Error<E> {
 pub struct Simulation<A> {
     /// The (alive!) agents in the simulation.
     agents:    Vec<A>,
+    /// The agents that are sleeping or waiting, together with when they should be polled again.
+    parked: Vec<(A, Wake)>,
+    /// The agents that were quarantined after failing.
+    quarantined: Vec<A>,
+    /// The number of rounds polled so far.
+    round: usize,
+    /// The failure policies for specific agents.
+    policies: HashMap<String, FailurePolicy>,
+    /// The failure policy for agents without a specific one.
+    default_policy: FailurePolicy,
+    /// The number of times agents with a [`FailurePolicy::Retry`] have failed in a row.
+    retries: HashMap<String, usize>,
+    /// The number of threads used to poll agents in parallel.
+    threads: usize,
+    /// The order in which agents are polled.
+    schedule: Schedule,
+    /// Shuffles the agents if the `schedule` tells us to.
+    shuffler: Option<Shuffler>,
     /// A set of action (identifiers) of the ones we've already audited
     audited:   HashSet<String>,
     /// An interface we use to log whatever happens in pretty ways.
     interface: Rc<RefCell<Interface>>,
+    /// The agent that gets to update the time (and the agreements, unless they are decided by consensus).
+    dictator:  String,
 
     /// The globally synchronized agreements.
-    agrs:  GlobalAgreementsDictator,
+    agrs:  GlobalAgreements,
     /// The globally synchronized timestamps.
-    times: GlobalTimesDictator,
+    times: GlobalTimes,
     /// The local statements.
     stmts: GlobalStatements,
 }
@@ -95,23 +709,8 @@ impl<A> Simulation<A> {
     /// An empty simulation that wouldn't run anything.
     #[inline]
     pub fn new(dictator: impl AsRef<str>) -> Self {
-        let dictator: &str = dictator.as_ref();
         info!("Creating demo Simulation<{}>", type_name::<A>());
-
-        // Build an interface with ourselves registered
-        let mut interface: Interface = Interface::new();
-        interface.register("<system>", Style::new().bold());
-
-        // Create ourselves with that
-        let interface: Rc<RefCell<Interface>> = Rc::new(RefCell::new(interface));
-        Self {
-            agrs: GlobalAgreementsDictator::new(dictator, interface.clone()),
-            times: GlobalTimesDictator::new(dictator, interface.clone()),
-            stmts: GlobalStatements::new(interface.clone()),
-            agents: Vec::new(),
-            audited: HashSet::new(),
-            interface,
-        }
+        Self::build(dictator.as_ref(), Vec::new())
     }
 
     /// Creates a new Simulation with no agents registered yet, but space to do so before re-allocation is triggered.
@@ -124,65 +723,287 @@ impl<A> Simulation<A> {
     /// An empty simulation that wouldn't run anything but that has space for at least `capacity` agents.
     #[inline]
     pub fn with_capacity(dictator: impl AsRef<str>, capacity: usize) -> Self {
-        let dictator: &str = dictator.as_ref();
         info!("Creating demo Simulation<{}> (with capacity '{}')", type_name::<A>(), capacity);
+        Self::build(dictator.as_ref(), Vec::with_capacity(capacity))
+    }
 
+    /// Creates a new Simulation without registering anything.
+    ///
+    /// # Arguments
+    /// - `dictator`: The agent that gets to update all globally synchronized agreements.
+    /// - `agents`: Some (empty) list with space for the agents to register.
+    ///
+    /// # Returns
+    /// A simulation that wouldn't run anything.
+    fn build(dictator: &str, agents: Vec<A>) -> Self {
         // Build an interface with ourselves registered
         let mut interface: Interface = Interface::new();
         interface.register("<system>", Style::new().bold());
+        interface.hold();
 
         // Create ourselves with that
         let interface: Rc<RefCell<Interface>> = Rc::new(RefCell::new(interface));
         Self {
-            agrs: GlobalAgreementsDictator::new(dictator, interface.clone()),
-            times: GlobalTimesDictator::new(dictator, interface.clone()),
+            agrs: GlobalAgreementsDictator::new(dictator, interface.clone()).into(),
+            times: GlobalTimesDictator::new(dictator, interface.clone()).into(),
             stmts: GlobalStatements::new(interface.clone()),
-            agents: Vec::with_capacity(capacity),
+            agents,
+            parked: Vec::new(),
+            quarantined: Vec::new(),
+            round: 0,
+            policies: HashMap::new(),
+            default_policy: FailurePolicy::default(),
+            retries: HashMap::new(),
+            threads: thread::available_parallelism().map(NonZeroUsize::get).unwrap_or(1),
+            schedule: Schedule::default(),
+            shuffler: None,
             audited: HashSet::new(),
             interface,
+            dictator: dictator.into(),
         }
     }
 
-    /// Builds a new Simulation with the given set of agents registered to it from the get-go.
+    /// Sets the [`FailurePolicy`] for a particular agent.
     ///
     /// # Arguments
-    /// - `dictator`: The agent that gets to update all globally synchronized agreements.
-    /// - `agents`: Some list of `A`gents that should be registered right away.
+    /// - `agent`: The identifier of the agent to set the policy for.
+    /// - `policy`: The [`FailurePolicy`] to apply when that agent fails.
+    #[inline]
+    pub fn set_failure_policy(&mut self, agent: impl Into<String>, policy: FailurePolicy) { self.policies.insert(agent.into(), policy); }
+
+    /// Sets the [`FailurePolicy`] for all agents without a specific one.
+    ///
+    /// By default, this is [`FailurePolicy::Abort`].
+    ///
+    /// # Arguments
+    /// - `policy`: The [`FailurePolicy`] to apply when those agents fail.
+    #[inline]
+    pub fn set_default_failure_policy(&mut self, policy: FailurePolicy) { self.default_policy = policy; }
+
+    /// Sets the order in which agents are polled every round.
+    ///
+    /// Setting a [`Schedule::Shuffled`] (again) restarts the orders produced by its seed.
+    ///
+    /// # Arguments
+    /// - `schedule`: The [`Schedule`] to poll agents with.
+    #[inline]
+    pub fn set_schedule(&mut self, schedule: Schedule) {
+        self.schedule = schedule;
+        self.shuffler = schedule.seed().map(Shuffler::new);
+    }
+
+    /// Returns the order in which agents are polled every round.
+    ///
+    /// This can be used to find the seed of a run that failed with an error.
     ///
     /// # Returns
-    /// A Simulation with the given `agents` already registered in it.
+    /// The [`Schedule`] of this simulation.
     #[inline]
-    pub fn with_agents(dictator: impl AsRef<str>, agents: impl IntoIterator<Item = A>) -> Self {
-        let dictator: &str = dictator.as_ref();
-        info!("Creating demo Simulation<{}> with agents", type_name::<A>());
+    pub fn schedule(&self) -> Schedule { self.schedule }
 
-        // Build an interface with ourselves registered
-        let mut interface: Interface = Interface::new();
-        interface.register("<system>", Style::new().bold());
+    /// Sets the number of threads used by [`Simulation::poll_parallel()`].
+    ///
+    /// By default, this is the number of cores available.
+    ///
+    /// # Arguments
+    /// - `threads`: The number of threads to use. If this is 0, then 1 is used instead.
+    #[inline]
+    pub fn set_threads(&mut self, threads: usize) { self.threads = threads.max(1); }
 
-        // Create agents out of the given iterator, logging as we go
-        let agents: Vec<A> = agents
-            .into_iter()
-            .enumerate()
-            .map(|(i, a)| {
-                debug!("Registered agent {}", i);
-                a
-            })
-            .collect();
+    /// Sets a network over which agents send statements and enactments to each other.
+    ///
+    /// Agents then only learn of what others state or enact once it arrives, which may be some
+    /// rounds later or never (see [`Network`]). Elements arrive at the start of a round.
+    ///
+    /// This should be done before the first round. Sinks are not told of it afterwards (see
+    /// [`Simulation::add_sink()`]).
+    ///
+    /// # Arguments
+    /// - `network`: The [`Network`] to use, or [`None`] to deliver everything immediately.
+    #[inline]
+    pub fn set_network(&mut self, network: Option<Network>) {
+        self.warn_reconfigure("network");
+        self.stmts.set_network(network);
+    }
+
+    /// Returns the network over which agents send statements and enactments, mutably.
+    ///
+    /// This can be used to, e.g., partition the agents or heal partitions while the simulation
+    /// runs.
+    ///
+    /// # Returns
+    /// The [`Network`], or [`None`] if everything is delivered immediately.
+    #[inline]
+    pub fn network_mut(&mut self) -> Option<&mut Network> { self.stmts.network_mut() }
+
+    /// Sets the maximum number of messages an agent may state in a single round.
+    ///
+    /// Agents stating more are reported with an [`Event::Flooded`], and only the first `limit` of
+    /// their messages are stated.
+    ///
+    /// This should be done before the first round. Sinks are not told of it afterwards (see
+    /// [`Simulation::add_sink()`]).
+    ///
+    /// # Arguments
+    /// - `limit`: The maximum number of messages, or [`None`] to allow any number.
+    #[inline]
+    pub fn set_rate_limit(&mut self, limit: Option<usize>) {
+        self.warn_reconfigure("rate limit");
+        self.stmts.set_rate_limit(limit);
+    }
 
-        // Now built self
+    /// Adds a sink that receives every [`Event`] that happens in the simulation.
+    ///
+    /// The sink first receives an [`Event::Started`] that records how the simulation is set up,
+    /// followed by everything that happened since the simulation was created. Both are given to
+    /// it once the first round starts, such that the simulation may still be set up after adding
+    /// the sink. This allows rebuilding the full state of the simulation from its events (e.g.,
+    /// with a [`Replay`](crate::trace::Replay)).
+    ///
+    /// Sinks added after the first round started immediately receive an [`Event::Started`], but
+    /// miss whatever happened before.
+    ///
+    /// Sinks are flushed at the end of every round. Any failure to do so is returned by
+    /// [`Simulation::poll()`] as an [`Error::Sink`].
+    ///
+    /// # Arguments
+    /// - `sink`: The [`EventSink`] to add.
+    pub fn add_sink(&mut self, mut sink: impl 'static + EventSink) {
+        if !self.interface.borrow().is_held() {
+            sink.handle(&self.started());
+        }
+        self.interface.borrow_mut().add_sink(Box::new(sink));
+    }
+
+    /// Builds the event that records how the simulation is set up.
+    ///
+    /// # Returns
+    /// An [`Event::Started`].
+    fn started(&self) -> Event {
+        let agreements: Decision = match &self.agrs {
+            GlobalAgreements::Dictator(_) => Decision::Dictator,
+            GlobalAgreements::Consensus(agrs) => Decision::Consensus { quorum: agrs.quorum().clone(), timeout: Some(agrs.timeout()) },
+        };
+        let times: Decision = match &self.times {
+            GlobalTimes::Dictator(_) => Decision::Dictator,
+            GlobalTimes::Consensus(times) => Decision::Consensus { quorum: times.quorum().clone(), timeout: None },
+        };
+        Event::Started {
+            dictator: self.dictator.clone(),
+            agreements,
+            times,
+            network: self.stmts.network().map(Network::config),
+            rate_limit: self.stmts.rate_limit(),
+        }
+    }
+
+    /// Warns that the simulation is reconfigured after its sinks learned how it is set up.
+    ///
+    /// Their [`Event::Started`] then no longer matches the simulation, so a
+    /// [`Replay`](crate::trace::Replay) of their events may diverge from it.
+    ///
+    /// # Arguments
+    /// - `what`: Whatever is reconfigured, for debugging purposes.
+    fn warn_reconfigure(&self, what: &str) {
+        if !self.interface.borrow().is_held() && self.interface.borrow().has_sinks() {
+            warn!("Reconfiguring the {what} of a simulation that already started; its sinks will not know of this");
+        }
+    }
+
+    /// Flushes all sinks.
+    ///
+    /// # Errors
+    /// This function errors if any sink failed to flush.
+    #[inline]
+    fn flush_sinks<E>(&mut self) -> Result<(), Error<E>> { self.interface.borrow_mut().flush().map_err(|err| Error::Sink { err }) }
+
+    /// Sets whether the simulation suppresses all output to the terminal about what happens.
+    ///
+    /// Events are still given to any sinks added with [`Simulation::add_sink()`].
+    ///
+    /// # Arguments
+    /// - `quiet`: If true, nothing is written anymore.
+    #[inline]
+    pub fn set_quiet(&mut self, quiet: bool) { self.interface.borrow_mut().set_quiet(quiet); }
+
+    /// Returns whether the simulation suppresses all output to the terminal about what happens.
+    ///
+    /// # Returns
+    /// True if nothing is written, or false otherwise.
+    #[inline]
+    pub fn is_quiet(&self) -> bool { self.interface.borrow().is_quiet() }
+
+    /// Copies the simulation, such that it can continue in another way than this one.
+    ///
+    /// The fork logs to an interface of its own, which is muted and has no sinks. As such,
+    /// nothing the fork does ends up next to what this simulation does.
+    ///
+    /// # Returns
+    /// A new Simulation in the same state.
+    pub(crate) fn fork(&self) -> Self
+    where
+        A: Clone,
+    {
+        let mut interface: Interface = Interface::new();
+        interface.set_muted(true);
         let interface: Rc<RefCell<Interface>> = Rc::new(RefCell::new(interface));
+
+        // Point the globally synchronized sets to the new interface
+        let mut agrs: GlobalAgreements = self.agrs.clone();
+        agrs.set_interface(interface.clone());
+        let mut times: GlobalTimes = self.times.clone();
+        times.set_interface(interface.clone());
+        let mut stmts: GlobalStatements = self.stmts.clone();
+        stmts.set_interface(interface.clone());
         Self {
-            agrs: GlobalAgreementsDictator::new(dictator, interface.clone()),
-            times: GlobalTimesDictator::new(dictator, interface.clone()),
-            stmts: GlobalStatements::new(interface.clone()),
-            agents,
-            audited: HashSet::new(),
+            agents: self.agents.clone(),
+            parked: self.parked.clone(),
+            quarantined: self.quarantined.clone(),
+            round: self.round,
+            policies: self.policies.clone(),
+            default_policy: self.default_policy,
+            retries: self.retries.clone(),
+            threads: self.threads,
+            schedule: self.schedule,
+            shuffler: self.shuffler.clone(),
+            audited: self.audited.clone(),
             interface,
+            dictator: self.dictator.clone(),
+            agrs,
+            times,
+            stmts,
         }
     }
+
+    /// Returns the agents that were quarantined after failing.
+    ///
+    /// # Returns
+    /// A slice of the `A`gents that are no longer polled because of [`FailurePolicy::Quarantine`].
+    #[inline]
+    pub fn quarantined(&self) -> &[A] { &self.quarantined }
 }
 impl<A: Identifiable<Id = str>> Simulation<A> {
+    /// Builds a new Simulation with the given set of agents registered to it from the get-go.
+    ///
+    /// The agents are registered as with [`Simulation::register()`], using a default [`Style`].
+    ///
+    /// # Arguments
+    /// - `dictator`: The agent that gets to update all globally synchronized agreements.
+    /// - `agents`: Some list of `A`gents that should be registered right away.
+    ///
+    /// # Returns
+    /// A Simulation with the given `agents` already registered in it.
+    #[inline]
+    pub fn with_agents(dictator: impl AsRef<str>, agents: impl IntoIterator<Item = A>) -> Self {
+        info!("Creating demo Simulation<{}> with agents", type_name::<A>());
+        let agents = agents.into_iter();
+        let mut sim: Self = Self::build(dictator.as_ref(), Vec::with_capacity(agents.size_hint().0));
+        for agent in agents {
+            sim.register(agent, Style::new());
+        }
+        sim
+    }
+
     /// Registers a new agent after creation.
     ///
     /// # Arguments
@@ -192,89 +1013,549 @@ impl<A: Identifiable<Id = str>> Simulation<A> {
     pub fn register(&mut self, agent: impl Into<A>, style: Style) {
         debug!("Registered agent {}", self.agents.len());
 
-        // Register the agent in the statements
+        // Register the agent in the statements and as a voter
         let agent: A = agent.into();
         self.stmts.register(&agent);
+        self.agrs.register(agent.id());
+        self.times.register(agent.id());
 
         // Register the agent in the interface
         self.interface.borrow_mut().register(agent.id(), style);
 
         // Put it in the simulation
-        self.agents.push(agent.into());
+        self.agents.push(agent);
+    }
+
+    /// Lets the agents decide on agreements by voting instead of leaving it to the dictator.
+    ///
+    /// Any agent may then propose an agreement, which is agreed upon once the given `quorum` of
+    /// the registered agents votes in favour of it (see
+    /// [`GlobalAgreementsConsensus`](crate::agreements::GlobalAgreementsConsensus)). Agreements
+    /// that were already agreed upon are kept.
+    ///
+    /// This should be done before the first round. Sinks are not told of it afterwards (see
+    /// [`Simulation::add_sink()`]).
+    ///
+    /// # Arguments
+    /// - `quorum`: The [`Quorum`] that must vote in favour of a proposal.
+    /// - `timeout`: The number of rounds after the one in which an agreement is proposed before
+    ///   it expires if it isn't decided by then.
+    pub fn set_consensus(&mut self, quorum: Quorum, timeout: usize) {
+        self.warn_reconfigure("agreements");
+        let mut agrs: GlobalAgreementsConsensus = GlobalAgreementsConsensus::new(quorum, timeout, self.interface.clone());
+        for agr in self.agrs.agreed() {
+            // NOTE: Cannot fail, as the system needs no vote
+            let _ = agrs.agree(agr.clone());
+        }
+        for agent in self.voters() {
+            agrs.register(agent);
+        }
+        self.agrs = agrs.into();
+    }
+
+    /// Lets the agents advance the time by voting instead of leaving it to the dictator.
+    ///
+    /// Any agent may then propose a new time, which becomes the current time once the given
+    /// `quorum` of the registered agents votes in favour of it (see
+    /// [`GlobalTimesConsensus`](crate::times::GlobalTimesConsensus)). The current time is kept.
+    ///
+    /// This should be done before the first round. Sinks are not told of it afterwards (see
+    /// [`Simulation::add_sink()`]).
+    ///
+    /// # Arguments
+    /// - `quorum`: The [`Quorum`] that must vote in favour of a new time.
+    pub fn set_time_consensus(&mut self, quorum: Quorum) {
+        self.warn_reconfigure("times");
+        let mut times: GlobalTimesConsensus = GlobalTimesConsensus::new(quorum, self.interface.clone());
+        let now: Timestamp = self.times.current();
+        if now > Timestamp(0) {
+            // NOTE: Cannot fail, as the system needs no vote and the time moves forward
+            let _ = times.advance_to(now);
+        }
+        for agent in self.voters() {
+            times.register(agent);
+        }
+        self.times = times.into();
+    }
+
+    /// Returns the identifiers of all agents registered so far, which may vote when agreements or
+    /// times are decided by consensus.
+    ///
+    /// # Returns
+    /// A list of agent identifiers, in no particular order.
+    fn voters(&self) -> HashSet<&str> {
+        self.agents.iter().chain(self.parked.iter().map(|(agent, _)| agent)).map(|agent| agent.id()).chain(self.stmts.agents()).collect()
+    }
+
+    /// Moves any parked agents that should be polled again back among the awake agents.
+    fn wake(&mut self) {
+        let Self { agents, parked, agrs, times, stmts, .. } = self;
+        if parked.is_empty() {
+            return;
+        }
+        let now: Timestamp = times.current();
+        let agreed: LocalSet<&Agreement<Message>> = agrs.agreed();
+        for (agent, wake) in std::mem::take(parked) {
+            let awake: bool = match &wake {
+                Wake::At(time) => now >= *time,
+                Wake::On(pred) => agreed.iter().any(|agr| pred(&agr.msg)) || stmts.known_by(agent.id()).any(|msg| pred(msg)),
+            };
+            if awake {
+                debug!("Waking agent '{}'", agent.id());
+                agents.push(agent);
+            } else {
+                parked.push((agent, wake));
+            }
+        }
+    }
+}
+impl<A: Hash> Simulation<A> {
+    /// Hashes the state of the simulation, regardless of the order of its agents and of the
+    /// order in which statements, enactments and agreements were sent.
+    ///
+    /// Sleeping agents are hashed with the time they wake up, but agents waiting for a message
+    /// are hashed without their predicate.
+    ///
+    /// # Arguments
+    /// - `state`: The [`Hasher`] to hash into.
+    pub(crate) fn fingerprint(&self, state: &mut impl Hasher) {
+        hash_unordered(&self.agents, |agent, state| agent.hash(state), state);
+        hash_unordered(
+            &self.parked,
+            |(agent, wake), state| {
+                agent.hash(state);
+                if let Wake::At(time) = wake {
+                    time.hash(state);
+                }
+            },
+            state,
+        );
+        hash_unordered(&self.quarantined, |agent, state| agent.hash(state), state);
+        hash_unordered(&self.retries, |retries, state| retries.hash(state), state);
+        hash_unordered(&self.audited, |id, state| id.hash(state), state);
+        self.agrs.fingerprint(state);
+        self.times.fingerprint(state);
+        self.stmts.fingerprint(state);
+    }
+
+    /// Hashes only the agreements, the times and what every agent knows, i.e., the part of the
+    /// state that a [`Replay`](crate::trace::Replay) rebuilds from the simulation's events.
+    ///
+    /// # Arguments
+    /// - `state`: The [`Hasher`] to hash into.
+    #[cfg(test)]
+    pub(crate) fn fingerprint_known(&self, state: &mut impl Hasher) {
+        self.agrs.fingerprint(state);
+        self.times.fingerprint(state);
+        self.stmts.fingerprint_known(state);
     }
 }
 impl<A> Simulation<A>
 where
     A: Identifiable<Id = str>,
-    A: RationalAgent<Message = Message, Target = Target, Error = Infallible>,
+    A: RationalAgent<Message = Message, Target = Target>,
 {
     /// Polls all the agents in the simulation once.
     ///
+    /// Only agents that are awake are polled. Agents that sleep or wait for a message are woken up
+    /// once the time or message they are waiting for is there, and spawned agents are polled from
+    /// the next round onwards. Agents that fail are handled according to their [`FailurePolicy`].
+    ///
+    /// Agents are polled one after another, and see the changes made by agents polled before them
+    /// in the same round.
+    ///
     /// # Returns
-    /// True if at least one agent is alive, or false otherwise.
+    /// A [`PollReport`] describing whether at least one agent is awake and which agents failed.
     ///
     /// # Errors
-    /// This function errors if any of the agents fails to communicate with the end-user or other agents.
-    pub fn poll(&mut self) -> Result<bool, Error<<A as RationalAgent>::Error>> {
-        let Self { agents, agrs, times, stmts, .. } = self;
+    /// This function errors if any of the agents with [`FailurePolicy::Abort`] fails. The agents
+    /// that were not polled yet are then not polled this round, but the round is still finished
+    /// and all agents (including the failed one) are kept in the simulation.
+    ///
+    /// It also errors if any of the sinks fails to flush.
+    pub fn poll(&mut self) -> Result<PollReport<<A as RationalAgent>::Error>, Error<<A as RationalAgent>::Error>> {
         info!("Starting new agent iteration");
 
         // Iterate over the agents and only keep those that report they wanna be kept
-        let mut agent_next: StackVec<64, A> = StackVec::new();
-        for (i, mut agent) in agents.drain(..).enumerate() {
+        let agents: Vec<A> = self.begin_round();
+        let mut round: Round<A> = Round::with_capacity(agents.len());
+        let mut agents = agents.into_iter().enumerate();
+        let mut aborted: Option<Error<<A as RationalAgent>::Error>> = None;
+        for (i, agent) in agents.by_ref() {
             debug!("Polling agent {}...", i);
 
-            // Prepare calling the agent's poll method
+            // Call the agent's poll method, and make its changes visible to the next
             let id: String = agent.id().into();
-            agrs.scope(&id, |agrs| {
-                times.scope(&id, |times| {
-                    stmts.scope(&id, |stmts| {
-                        // Do the call then
-                        match agent.poll(agrs, times, stmts) {
-                            Ok(AgentPoll::Alive) => Ok(agent_next.push(agent)),
-                            Ok(AgentPoll::Dead) => Ok(()),
-                            Err(err) => Err(Error::AgentPoll { agent: id.clone(), err }),
-                        }
-                    })
-                })
-            })?;
+            let polled: Polled<A> = self.poll_detached(agent);
+            self.deliver(&id, polled.changes);
+
+            // Decide what to do with the agent
+            if let Err(err) = self.settle(&mut round, polled.agent, polled.res) {
+                aborted = Some(err);
+                break;
+            }
         }
 
-        // Now re-instante those kept and return whether we're done
-        self.agents.extend(agent_next);
-        Ok(!self.agents.is_empty())
+        // Agents that weren't polled because another aborted simply live on
+        round.next.extend(agents.map(|(_, agent)| agent));
+        self.end_round(round, aborted)
     }
 
     /// Runs the simulation until no more agents are alive.
     ///
+    /// # Generics
+    /// - `E`: The [`Extractor`] of the policy language used to audit actions.
+    ///
+    /// # Arguments
+    /// - `auditor`: The [`Auditor`] (stack) used to audit the actions enacted by agents. Use
+    ///   [`Paper`](justact_core::auditors::Paper) to check the properties from the paper.
+    ///
+    /// Actions that pass the audit are additionally analyzed for messages in their justification
+    /// that play no part in its validity, which are reported as warnings.
+    ///
+    /// # Returns
+    /// A [`RunReport`] with the number of rounds run and all failures of agents along the way.
+    ///
     /// # Errors
-    /// This function errors if any of the agents fails to communicate with the end-user or other agents.
+    /// This function errors if any of the agents with [`FailurePolicy::Abort`] fails.
     #[inline]
-    pub fn run<E>(&mut self) -> Result<(), Error<<A as RationalAgent>::Error>>
+    pub fn run<E>(&mut self, auditor: impl Auditor<Message>) -> Result<RunReport<<A as RationalAgent>::Error>, Error<<A as RationalAgent>::Error>>
     where
         E: for<'e> Extractor<&'e Message>,
     {
+        self.run_with::<E>(auditor, Self::poll)
+    }
+
+
+
+    /// Runs the simulation until no more agents are alive, using the given function to poll them.
+    ///
+    /// # Generics
+    /// - `E`: The [`Extractor`] of the policy language used to audit actions.
+    ///
+    /// # Arguments
+    /// - `auditor`: The [`Auditor`] (stack) used to audit the actions enacted by agents.
+    /// - `poll`: The function that polls all agents once, i.e., [`Self::poll()`] or [`Self::poll_parallel()`].
+    ///
+    /// # Returns
+    /// A [`RunReport`] with the number of rounds run and all failures of agents along the way.
+    ///
+    /// # Errors
+    /// This function errors if any of the agents with [`FailurePolicy::Abort`] fails.
+    fn run_with<E>(
+        &mut self,
+        auditor: impl Auditor<Message>,
+        mut poll: impl FnMut(&mut Self) -> Result<PollReport<<A as RationalAgent>::Error>, Error<<A as RationalAgent>::Error>>,
+    ) -> Result<RunReport<<A as RationalAgent>::Error>, Error<<A as RationalAgent>::Error>>
+    where
+        E: for<'e> Extractor<&'e Message>,
+    {
+        info!("Polling agents {}", self.schedule);
+        self.interface.borrow_mut().log("<system>", format!("Polling agents {}", self.schedule));
+        let mut report: RunReport<<A as RationalAgent>::Error> = RunReport { rounds: 0, schedule: self.schedule, failures: Vec::new() };
         loop {
             // Run the next iteration
-            let reiterate: bool = self.poll()?;
+            let poll: PollReport<<A as RationalAgent>::Error> = poll(self)?;
+            report.rounds += 1;
+            report.failures.extend(poll.failures);
 
             // Run an audit
-            debug!("Running audit on {} actions...", self.stmts.encts.values().map(LocalSet::len).sum::<usize>());
-            for enct in self.stmts.encts.values().flat_map(LocalSet::iter) {
-                // Audit if we haven't yet
-                if !self.audited.contains(enct.id()) {
-                    if let Err(expl) = enct.audit::<E, GlobalStatements, GlobalAgreementsDictator>(&self.stmts, &self.agrs) {
-                        // Write the problem
-                        self.interface.borrow().error_audit("<system>", enct, expl);
+            self.audit::<E>(&auditor);
+
+            // Stop if no agents are alive
+            if !poll.alive {
+                return Ok(report);
+            }
+        }
+    }
+
+    /// Audits all actions that have not been audited yet, ordered by identifier.
+    ///
+    /// Failed audits are reported as errors, and actions that pass are analyzed for superfluous
+    /// messages in their justification.
+    ///
+    /// # Generics
+    /// - `E`: The [`Extractor`] of the policy language used to audit actions.
+    ///
+    /// # Arguments
+    /// - `auditor`: The [`Auditor`] (stack) used to audit the actions.
+    ///
+    /// # Returns
+    /// The actions that failed their audit.
+    pub(crate) fn audit<E>(&mut self, auditor: &impl Auditor<Message>) -> Vec<Action<Message>>
+    where
+        E: for<'e> Extractor<&'e Message>,
+    {
+        // Audit in a fixed order, such that the same run is always reported the same way
+        let mut encts: Vec<&Action<Message>> = self.stmts.enacted().into_iter().collect();
+        encts.sort_unstable_by(|lhs, rhs| lhs.id().cmp(rhs.id()));
+        debug!("Running audit on {} actions...", encts.len());
+        let mut failed: Vec<Action<Message>> = Vec::new();
+        for enct in encts {
+            // Audit if we haven't yet
+            if !self.audited.contains(enct.id()) {
+                let report = enct.audit_with::<E, GlobalStatements, GlobalAgreements, _>(auditor, &self.stmts, &self.agrs);
+                if !report.is_ok() {
+                    // Write the problem(s)
+                    self.interface.borrow_mut().error_audit("<system>", enct, report);
+                    failed.push(enct.clone());
+                } else {
+                    // Flag any messages in the justification that don't matter
+                    match analyze_justification::<E, Message>(enct, ANALYSIS_BUDGET) {
+                        Ok(analysis) => {
+                            if !analysis.superfluous.is_empty() {
+                                self.interface.borrow_mut().warn_superfluous("<system>", enct, &analysis.superfluous);
+                            }
+                        },
+                        Err(err) => debug!("Skipping analysis of action that enacts '{}': {}", enct.enacts().id(), err),
                     }
-                    self.audited.insert(enct.id().into());
                 }
+                self.audited.insert(enct.id().into());
             }
+        }
+        failed
+    }
 
-            // Stop if no agents are alive
-            if !reiterate {
+    /// Starts a new round by taking the agents to poll out of the simulation, in the order of its
+    /// [`Schedule`].
+    ///
+    /// # Returns
+    /// The awake agents, ordered.
+    pub(crate) fn begin_round(&mut self) -> Vec<A> {
+        self.interface.borrow_mut().start(|| self.started());
+        self.wake();
+        self.round += 1;
+        self.interface.borrow_mut().set_round(self.round);
+        self.stmts.arrive(self.round);
+        let mut agents: Vec<A> = std::mem::take(&mut self.agents);
+        if let Some(shuffler) = &mut self.shuffler {
+            shuffler.shuffle(&mut agents);
+        }
+        agents
+    }
+
+    /// Polls an agent without synchronizing its changes.
+    ///
+    /// # Arguments
+    /// - `agent`: The `A`gent to poll.
+    ///
+    /// # Returns
+    /// A [`Polled`] with the agent, the result of polling it and the changes it wants to make (see
+    /// [`Self::deliver()`]).
+    pub(crate) fn poll_detached(&self, mut agent: A) -> Polled<A> {
+        let id: String = agent.id().into();
+        let mut agrs: Agreements = self.agrs.view(&id);
+        let mut times: Times = self.times.view(&id);
+        let mut stmts: Statements = self.stmts.view(&id);
+        let res: PollResult<A> = agent.poll(&mut agrs, &mut times, &mut stmts);
+        let changes: Changes = Changes { agrs: agrs.into_queue(), times: times.into_queue(), stmts: stmts.stmts_queue, encts: stmts.encts_queue };
+        Polled { agent, res, changes }
+    }
+
+    /// Synchronizes the changes an agent made.
+    ///
+    /// # Arguments
+    /// - `agent`: The identifier of the agent who made the changes.
+    /// - `changes`: The [`Changes`] to synchronize.
+    #[inline]
+    pub(crate) fn deliver(&mut self, agent: &str, changes: Changes) {
+        self.stmts.sync(agent, changes.stmts, changes.encts);
+        self.times.sync(agent, changes.times);
+        self.agrs.sync(agent, changes.agrs);
+    }
+
+    /// Decides what happens to an agent after it has been polled.
+    ///
+    /// # Arguments
+    /// - `round`: The [`Round`] in which to collect what happens to the agent.
+    /// - `agent`: The `A`gent that was polled.
+    /// - `res`: The result of polling the `agent`.
+    ///
+    /// # Errors
+    /// This function errors if the agent failed and has [`FailurePolicy::Abort`]. The failure is
+    /// still logged, and the agent is kept to be polled next round.
+    pub(crate) fn settle(&mut self, round: &mut Round<A>, agent: A, res: PollResult<A>) -> Result<(), Error<<A as RationalAgent>::Error>> {
+        let id: String = agent.id().into();
+        let err: <A as RationalAgent>::Error = match res {
+            Ok(poll) => {
+                self.retries.remove(&id);
+                match poll {
+                    AgentPoll::Alive => round.next.push(agent),
+                    AgentPoll::Dead => {},
+                    AgentPoll::Sleep(time) => self.parked.push((agent, Wake::At(time))),
+                    AgentPoll::WaitFor(pred) => {
+                        // The predicate never leaves this thread, so it no longer needs to be `Send`
+                        let pred: Box<dyn Fn(&Message) -> bool> = pred;
+                        self.parked.push((agent, Wake::On(pred.into())));
+                    },
+                    AgentPoll::Spawn(new) => {
+                        round.next.push(agent);
+                        round.spawned.extend(new.into_iter().map(|new| (id.clone(), new)));
+                    },
+                }
                 return Ok(());
+            },
+            Err(err) => err,
+        };
+
+        // Apply the agent's failure policy
+        let mut policy: FailurePolicy = self.policies.get(&id).copied().unwrap_or(self.default_policy);
+        if let FailurePolicy::Retry { max } = policy {
+            let tries: &mut usize = self.retries.entry(id.clone()).or_default();
+            *tries += 1;
+            if *tries > max {
+                self.retries.remove(&id);
+                policy = FailurePolicy::Kill;
+            }
+        }
+        match policy {
+            FailurePolicy::Kill => {},
+            FailurePolicy::Abort | FailurePolicy::Retry { .. } => round.next.push(agent),
+            FailurePolicy::Quarantine => self.quarantined.push(agent),
+        }
+        self.interface.borrow_mut().error_agent(&id, &err, policy);
+        if policy == FailurePolicy::Abort {
+            return Err(Error::AgentPoll { agent: id, err });
+        }
+        round.failures.push(AgentFailure { agent: id, round: self.round, err, policy });
+        Ok(())
+    }
+
+    /// Finishes a round by re-instating the agents that live on and adding any new ones.
+    ///
+    /// # Arguments
+    /// - `round`: The [`Round`] with what happened to the agents.
+    ///
+    /// # Returns
+    /// A [`PollReport`] describing whether at least one agent is awake and which agents failed.
+    pub(crate) fn finish(&mut self, round: Round<A>) -> PollReport<<A as RationalAgent>::Error> {
+        self.agents.extend(round.next);
+        for (parent, agent) in round.spawned {
+            self.interface.borrow_mut().log(&parent, format!("Spawned agent '{}'", agent.id()));
+            self.register(agent, Style::new().bold());
+        }
+        self.agrs.finish_round();
+        self.interface.borrow_mut().log_finish();
+        self.wake();
+        PollReport { alive: !self.agents.is_empty(), failures: round.failures }
+    }
+
+    /// Finishes a round (see [`Self::finish()`]) and flushes all sinks, even if an agent aborted
+    /// it.
+    ///
+    /// # Arguments
+    /// - `round`: The [`Round`] with what happened to the agents.
+    /// - `aborted`: The error of the agent that aborted the round, if any.
+    ///
+    /// # Returns
+    /// A [`PollReport`] describing whether at least one agent is awake and which agents failed.
+    ///
+    /// # Errors
+    /// This function errors with `aborted` if it is given, or else if any sink failed to flush.
+    fn end_round(
+        &mut self,
+        round: Round<A>,
+        aborted: Option<Error<<A as RationalAgent>::Error>>,
+    ) -> Result<PollReport<<A as RationalAgent>::Error>, Error<<A as RationalAgent>::Error>> {
+        let report: PollReport<<A as RationalAgent>::Error> = self.finish(round);
+        let flushed: Result<(), Error<<A as RationalAgent>::Error>> = self.flush_sinks();
+        if let Some(err) = aborted {
+            return Err(err);
+        }
+        flushed.map(|_| report)
+    }
+}
+impl<A> Simulation<A>
+where
+    A: Send + Identifiable<Id = str>,
+    A: RationalAgent<Message = Message, Target = Target>,
+    <A as RationalAgent>::Error: Send,
+{
+    /// Polls all the agents in the simulation once, using multiple threads.
+    ///
+    /// This works like [`Simulation::poll()`], except that all agents see the simulation as it
+    /// was at the start of the round. Their changes are synchronized afterwards, in the same
+    /// order in which they would have been polled by [`Simulation::poll()`]. As such, the result
+    /// does not depend on how the agents are divided over threads (see
+    /// [`Simulation::set_threads()`]).
+    ///
+    /// # Returns
+    /// A [`PollReport`] describing whether at least one agent is awake and which agents failed.
+    ///
+    /// # Errors
+    /// This function errors if any of the agents with [`FailurePolicy::Abort`] fails. As all
+    /// agents have been polled by then, the changes of every agent are still synchronized, the
+    /// round is finished and all agents (including the failed one) are kept in the simulation.
+    ///
+    /// It also errors if any of the sinks fails to flush.
+    ///
+    /// # Panics
+    /// This function panics if any of the agents panics.
+    pub fn poll_parallel(&mut self) -> Result<PollReport<<A as RationalAgent>::Error>, Error<<A as RationalAgent>::Error>> {
+        info!("Starting new parallel agent iteration");
+
+        // Give every agent a view on the simulation as it is now, and poll them on the threads
+        let agents: Vec<A> = self.begin_round();
+        let ids: Vec<String> = agents.iter().map(|agent| agent.id().into()).collect();
+        let polled: Vec<Polled<A>> = {
+            let mut jobs: Vec<Job<A>> = agents
+                .into_iter()
+                .zip(&ids)
+                .map(|(agent, id)| Job { agent, agrs: self.agrs.view(id), times: self.times.view(id), stmts: self.stmts.view(id), res: None })
+                .collect();
+            let size: usize = jobs.len().div_ceil(self.threads).max(1);
+            debug!("Polling {} agents in chunks of {}...", jobs.len(), size);
+            thread::scope(|scope| {
+                for chunk in jobs.chunks_mut(size) {
+                    scope.spawn(move || {
+                        for job in chunk {
+                            job.res = Some(job.agent.poll(&mut job.agrs, &mut job.times, &mut job.stmts));
+                        }
+                    });
+                }
+            });
+            jobs.into_iter().map(Job::into_polled).collect()
+        };
+
+        // Synchronize their changes in order, and decide what to do with them
+        let mut round: Round<A> = Round::with_capacity(polled.len());
+        let mut aborted: Option<Error<<A as RationalAgent>::Error>> = None;
+        for (id, polled) in ids.iter().zip(polled) {
+            self.deliver(id, polled.changes);
+            if let Err(err) = self.settle(&mut round, polled.agent, polled.res) {
+                aborted.get_or_insert(err);
             }
         }
+        self.end_round(round, aborted)
+    }
+
+    /// Runs the simulation until no more agents are alive, using multiple threads.
+    ///
+    /// This works like [`Simulation::run()`], except that agents are polled using
+    /// [`Simulation::poll_parallel()`].
+    ///
+    /// # Generics
+    /// - `E`: The [`Extractor`] of the policy language used to audit actions.
+    ///
+    /// # Arguments
+    /// - `auditor`: The [`Auditor`] (stack) used to audit the actions enacted by agents.
+    ///
+    /// # Returns
+    /// A [`RunReport`] with the number of rounds run and all failures of agents along the way.
+    ///
+    /// # Errors
+    /// This function errors if any of the agents with [`FailurePolicy::Abort`] fails.
+    ///
+    /// # Panics
+    /// This function panics if any of the agents panics.
+    #[inline]
+    pub fn run_parallel<E>(
+        &mut self,
+        auditor: impl Auditor<Message>,
+    ) -> Result<RunReport<<A as RationalAgent>::Error>, Error<<A as RationalAgent>::Error>>
+    where
+        E: for<'e> Extractor<&'e Message>,
+    {
+        self.run_with::<E>(auditor, Self::poll_parallel)
     }
 }
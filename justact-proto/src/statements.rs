@@ -4,7 +4,7 @@
 //  Created:
 //    23 May 2024, 13:54:33
 //  Last edited:
-//    29 May 2024, 13:35:39
+//    16 Oct 2026, 22:13:15
 //  Auto updated?
 //    Yes
 //
@@ -15,15 +15,123 @@
 use std::borrow::Cow;
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::error::Error;
 use std::fmt::{Display, Formatter, Result as FResult};
+use std::hash::{Hash as _, Hasher};
 use std::rc::Rc;
 
 use console::style;
 use justact_core::auxillary::{Authored, Identifiable};
 use justact_core::set::LocalSet;
 use justact_core::statements::{Action, Message as JAMessage, Statements as JAStatements};
+use serde::{Deserialize, Serialize};
 
+use crate::explorer::{hash_action, hash_unordered};
 use crate::interface::{Displayable, Interface};
+use crate::network::{Network, Packet, Parcel, Transmission};
+
+
+/***** TESTS *****/
+#[cfg(test)]
+mod tests {
+    use justact_core::agreements::Agreement;
+    use justact_core::times::{TimeRange, Timestamp};
+
+    use super::*;
+
+
+    /// Creates a quiet [`GlobalStatements`] with agents `a`, `b` and `c` registered.
+    fn stmts() -> GlobalStatements {
+        let interface: Rc<RefCell<Interface>> = Rc::new(RefCell::new(Interface::new()));
+        interface.borrow_mut().set_quiet(true);
+        let mut stmts = GlobalStatements::new(interface);
+        for agent in ["a", "b", "c"] {
+            stmts.register_id(agent);
+        }
+        stmts
+    }
+
+    /// Creates a [`Message`] with the given identifier, author and payload.
+    fn msg(id: &str, author: &str, payload: &[u8]) -> Message {
+        Message { id: id.into(), author: author.into(), payload: payload.to_vec(), lang: None }
+    }
+
+
+    #[test]
+    fn test_statements_equivocated() {
+        let mut stmts: GlobalStatements = stmts();
+        stmts.scope("b", |view| view.state(Target::Agent("c".into()), msg("s1", "b", b"foo"))).unwrap();
+        stmts.scope("a", |view| view.state(Target::All, msg("s1", "a", b"bar"))).unwrap();
+
+        // Everybody knows the second version, but only `c` learned of the first
+        assert!(stmts.view("a").equivocated().is_empty());
+        assert!(stmts.view("b").equivocated().is_empty());
+        assert_eq!(stmts.view("c").equivocated().len(), 2);
+        assert_eq!(stmts.equivocated().len(), 2);
+
+        // Queued versions count as well
+        stmts.scope("a", |view| {
+            view.state(Target::Agent("a".into()), msg("s1", "a", b"baz")).unwrap();
+            assert_eq!(view.equivocated().len(), 2);
+        });
+    }
+
+    #[test]
+    fn test_statements_not_the_author() {
+        let mut stmts: GlobalStatements = stmts();
+        stmts.scope("a", |view| {
+            // Stating somebody else's message is refused...
+            let err: StatementsError = view.state(Target::All, msg("s1", "b", b"foo")).unwrap_err();
+            assert!(matches!(err, StatementsError::NotTheAuthor { ref id, ref author, ref agent } if id == "s1" && author == "b" && agent == "a"));
+            assert!(view.stated().is_empty());
+
+            // ...and so is enacting it
+            let act = Action {
+                basis:     Agreement { msg: msg("s2", "a", b"bar"), applies: TimeRange::starting(Timestamp(0)) },
+                just:      LocalSet::new(),
+                enacts:    msg("s1", "b", b"foo"),
+                timestamp: Timestamp(0),
+            };
+            let err: StatementsError = view.enact(Target::All, act).unwrap_err();
+            assert!(matches!(err, StatementsError::NotTheActor { ref id, ref author, ref agent } if id == "s1" && author == "b" && agent == "a"));
+            assert!(view.enacted().is_empty());
+        });
+        assert!(stmts.stated().is_empty());
+        assert!(stmts.enacted().is_empty());
+    }
+}
+
+
+
+
+
+/***** ERRORS *****/
+/// Determines the reasons why an agent's [`Statements`] rejected a message or an action.
+#[derive(Debug)]
+pub enum StatementsError {
+    /// The agent attempted to state a message authored by somebody else.
+    NotTheAuthor { id: String, author: String, agent: String },
+    /// The agent attempted to enact a message authored by somebody else.
+    NotTheActor { id: String, author: String, agent: String },
+}
+impl Display for StatementsError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use StatementsError::*;
+        match self {
+            NotTheAuthor { id, author, agent } => {
+                write!(f, "Agent '{agent}' failed to state message '{id}' because they are not its author ('{author}' is)")
+            },
+            NotTheActor { id, author, agent } => {
+                write!(f, "Agent '{agent}' failed to enact message '{id}' because they are not its author ('{author}' is)")
+            },
+        }
+    }
+}
+impl Error for StatementsError {}
+
+
+
 
 
 /***** FORMATTERS *****/
@@ -43,7 +151,11 @@ impl<'m, P: Display, I: Display> Display for MessageFormatter<'m, P, I> {
         let spayload: Cow<str> = String::from_utf8_lossy(self.msg.payload());
 
         // Write the message
-        writeln!(f, "{} '{}' by '{}' {{", self.prefix, style(self.msg.id()).bold(), style(self.msg.author()).bold())?;
+        write!(f, "{} '{}' by '{}' ", self.prefix, style(self.msg.id()).bold(), style(self.msg.author()).bold())?;
+        if let Some(lang) = &self.msg.lang {
+            write!(f, "in {} ", style(lang).bold())?;
+        }
+        writeln!(f, "{{")?;
         writeln!(f, "{}    {}", self.indent, spayload.replace('\n', &format!("\n{}    ", self.indent)).trim_end())?;
         writeln!(f, "{}}}", self.indent)
     }
@@ -55,7 +167,7 @@ impl<'m, P: Display, I: Display> Display for MessageFormatter<'m, P, I> {
 
 /***** AUXILLARY *****/
 /// Determines the possible targets that agents can send messages to for this [`Statements`].
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub enum Target {
     /// Send it to everybody.
     All,
@@ -86,6 +198,181 @@ impl Target {
     }
 }
 
+/// A queue of elements (e.g., messages) that an agent sent, together with their [`Target`]s.
+pub(crate) type Queue<T> = Vec<(Target, T)>;
+
+
+
+/// Stores the elements (e.g., messages) sent to agents.
+///
+/// Elements sent to all agents are stored only once, in the order they were sent. Agents only
+/// learn of those sent after they were registered, and keep the first version of every element
+/// they receive.
+///
+/// # Generics
+/// - `T`: The type of elements stored.
+#[derive(Clone, Debug)]
+pub(crate) struct Mailboxes<T> {
+    /// The elements sent to all agents.
+    all:    Vec<T>,
+    /// The position in `all` where every identifier was last sent.
+    index:  HashMap<String, usize>,
+    /// For every agent, the number of elements in `all` sent before it was registered, and the
+    /// elements sent to it in particular.
+    agents: HashMap<String, (usize, LocalSet<T>)>,
+}
+impl<T: Identifiable<Id = str>> Mailboxes<T> {
+    /// Constructor for Mailboxes that have no agents yet.
+    ///
+    /// # Returns
+    /// New, empty Mailboxes.
+    #[inline]
+    pub(crate) fn new() -> Self { Self { all: Vec::new(), index: HashMap::new(), agents: HashMap::new() } }
+
+    /// Adds a mailbox for a new agent.
+    ///
+    /// # Arguments
+    /// - `agent`: The identifier of the agent to add.
+    #[inline]
+    pub(crate) fn register(&mut self, agent: &str) { self.agents.insert(agent.into(), (self.all.len(), LocalSet::new())); }
+
+    /// Sends a new element to some agents.
+    ///
+    /// # Arguments
+    /// - `target`: The [`Target`] determining who receives the element.
+    /// - `elem`: The element to send.
+    ///
+    /// # Panics
+    /// This function panics if the `target` is an unknown agent.
+    #[inline]
+    #[track_caller]
+    pub(crate) fn send(&mut self, target: Target, elem: T) {
+        match target {
+            Target::All => {
+                self.index.insert(elem.id().into(), self.all.len());
+                self.all.push(elem);
+            },
+            Target::Agent(agent) => {
+                let (joined, own): &mut (usize, LocalSet<T>) =
+                    self.agents.get_mut(&agent).unwrap_or_else(|| panic!("Unknown synchronize agent '{agent}'"));
+                let known: bool = own.contains(elem.id()) || self.index.get(elem.id()).map(|pos| *pos >= *joined).unwrap_or(false);
+                if !known {
+                    own.add(elem);
+                }
+            },
+        }
+    }
+
+    /// Returns the elements known to a particular agent.
+    ///
+    /// # Arguments
+    /// - `agent`: The identifier of the agent to return the [`Mailbox`] of.
+    ///
+    /// # Returns
+    /// A [`Mailbox`] with the elements known to the `agent`, or [`None`] if it is unknown.
+    #[inline]
+    pub(crate) fn view(&self, agent: &str) -> Option<Mailbox<'_, T>> {
+        self.agents.get(agent).map(|(joined, own)| Mailbox { all: &self.all[*joined..], own })
+    }
+
+    /// Returns the agents that have a mailbox.
+    ///
+    /// # Returns
+    /// An iterator over the identifiers of the registered agents.
+    #[inline]
+    pub(crate) fn agents(&self) -> impl Iterator<Item = &str> { self.agents.keys().map(String::as_str) }
+
+    /// Returns all elements sent to anyone.
+    ///
+    /// # Returns
+    /// An iterator over all elements, which may yield multiple versions of the same element.
+    #[inline]
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &T> { self.all.iter().chain(self.agents.values().flat_map(|(_, own)| own.iter())) }
+}
+
+/// The elements in [`Mailboxes`] that are known to a particular agent.
+///
+/// # Generics
+/// - `T`: The type of elements stored.
+#[derive(Debug)]
+pub(crate) struct Mailbox<'v, T> {
+    /// The elements sent to all agents since this agent was registered.
+    all: &'v [T],
+    /// The elements sent to this agent in particular.
+    own: &'v LocalSet<T>,
+}
+impl<'v, T: Identifiable<Id = str>> Mailbox<'v, T> {
+    /// Collects the elements in this mailbox, keeping the first version of every element.
+    ///
+    /// # Returns
+    /// A [`LocalSet`] with references to the known elements.
+    #[inline]
+    pub(crate) fn collect(&self) -> LocalSet<&'v T> {
+        let mut set: LocalSet<&'v T> = LocalSet::with_capacity(self.own.len() + self.all.len());
+        for elem in self.own.iter().chain(self.all) {
+            if !set.contains(elem.id()) {
+                set.add(elem);
+            }
+        }
+        set
+    }
+
+    /// Returns the elements in this mailbox.
+    ///
+    /// # Returns
+    /// An iterator over the known elements, which may yield multiple versions of the same element.
+    #[inline]
+    pub(crate) fn iter(&self) -> impl 'v + Iterator<Item = &'v T> { self.own.iter().chain(self.all) }
+}
+
+
+
+
+
+/***** HELPER FUNCTIONS *****/
+/// Delivers something an agent stated or enacted to its target(s) immediately.
+///
+/// # Arguments
+/// - `stmts`: The [`Mailboxes`] with stated messages.
+/// - `encts`: The [`Mailboxes`] with enacted actions.
+/// - `target`: The [`Target`] determining who receives it.
+/// - `parcel`: The [`Parcel`] to deliver.
+///
+/// # Panics
+/// This function panics if the `target` is an unknown agent.
+#[inline]
+#[track_caller]
+fn deliver(stmts: &mut Mailboxes<Message>, encts: &mut Mailboxes<Action<Message>>, target: Target, parcel: Parcel) {
+    match parcel {
+        Parcel::Stmt(stmt) => stmts.send(target, stmt),
+        Parcel::Enct(enct) => encts.send(target, *enct),
+    }
+}
+
+/// Lists the agents who receive something sent to a target individually.
+///
+/// # Arguments
+/// - `stmts`: The [`Mailboxes`] with stated messages, which know all registered agents.
+/// - `target`: The [`Target`] determining who receives it.
+///
+/// # Returns
+/// The identifiers of the recipients, sorted.
+///
+/// # Panics
+/// This function panics if the `target` is an unknown agent.
+#[track_caller]
+fn recipients(stmts: &Mailboxes<Message>, target: Target) -> Vec<String> {
+    match target {
+        Target::All => {
+            let mut recipients: Vec<String> = stmts.agents().map(String::from).collect();
+            recipients.sort_unstable();
+            recipients
+        },
+        Target::Agent(to) if stmts.view(&to).is_some() => vec![to],
+        Target::Agent(to) => panic!("Unknown synchronize agent '{to}'"),
+    }
+}
+
 
 
 
@@ -94,7 +381,7 @@ impl Target {
 /// Defines the prototype's notion of a message.
 ///
 /// This means that it is assumed agents _cannot_ lie about their authorship of a message.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct Message {
     /// The identifier of the message.
     pub id:      String,
@@ -102,6 +389,21 @@ pub struct Message {
     pub author:  String,
     /// The payload of the message.
     pub payload: Vec<u8>,
+    /// The language in which the payload is written, if any.
+    pub lang:    Option<String>,
+}
+impl Message {
+    /// Checks whether this message conflicts with another one.
+    ///
+    /// # Arguments
+    /// - `other`: The other [`Message`] to compare with.
+    ///
+    /// # Returns
+    /// True if both messages have the same identifier but a different author, payload or language, or false otherwise.
+    #[inline]
+    pub fn conflicts_with(&self, other: &Message) -> bool {
+        self.id == other.id && (self.author != other.author || self.payload != other.payload || self.lang != other.lang)
+    }
 }
 impl Displayable for Message {
     type Formatter<'s, P: Display, I: Display> = MessageFormatter<'s, P, I> where Self: 's;
@@ -133,6 +435,9 @@ impl<'v> JAMessage<'v> for &'v Message {
 
     #[inline]
     fn payload(&self) -> &'v [u8] { &self.payload }
+
+    #[inline]
+    fn lang(&self) -> Option<&'v str> { self.lang.as_deref() }
 }
 
 
@@ -140,12 +445,32 @@ impl<'v> JAMessage<'v> for &'v Message {
 /// An owned version of the statements.
 ///
 /// Agents will see the agent-scoped variation [`Statements`].
-#[derive(Debug)]
+///
+/// Statements and enactments sent to all agents are stored only once, such that scoping and
+/// synchronizing does not scale with the number of agents.
+///
+/// If a message is stated with the same identifier as a different message, then agents that
+/// already know a version keep it. However, all versions are recorded and reported through
+/// [`JAStatements::equivocated()`]. Agents' [`Statements`] only report the versions they received.
+///
+/// Whatever agents state or enact is delivered to its targets immediately, unless a [`Network`]
+/// is set (see [`GlobalStatements::set_network()`]). In that case, it is stated as soon as it is
+/// sent, but its targets only learn of it once it arrives.
+///
+/// If a rate limit is set (see [`GlobalStatements::set_rate_limit()`]), then agents flooding the
+/// system with more statements than allowed are reported and only the first ones are stated.
+#[derive(Clone, Debug)]
 pub struct GlobalStatements {
     /// The current statements, scoped by agent.
-    stmts: HashMap<String, LocalSet<Message>>,
+    stmts: Mailboxes<Message>,
+    /// All distinct versions of the messages stated under every identifier.
+    versions: HashMap<String, Vec<Message>>,
     /// The current actions, scoped by agent.
-    pub(crate) encts: HashMap<String, LocalSet<Action<Message>>>,
+    encts: Mailboxes<Action<Message>>,
+    /// The network over which agents send statements and enactments, if any.
+    network: Option<Network>,
+    /// The maximum number of messages an agent may state at once, if any.
+    rate_limit: Option<usize>,
     /// An interface we use to log whatever happens in pretty ways.
     interface: Rc<RefCell<Interface>>,
 }
@@ -158,7 +483,59 @@ impl GlobalStatements {
     /// # Returns
     /// A new GlobalStatements.
     #[inline]
-    pub fn new(interface: Rc<RefCell<Interface>>) -> Self { Self { stmts: HashMap::new(), encts: HashMap::new(), interface } }
+    pub fn new(interface: Rc<RefCell<Interface>>) -> Self {
+        Self { stmts: Mailboxes::new(), versions: HashMap::new(), encts: Mailboxes::new(), network: None, rate_limit: None, interface }
+    }
+
+    /// Changes the interface to which whatever happens is logged.
+    ///
+    /// # Arguments
+    /// - `interface`: The new interface to log to.
+    #[inline]
+    pub(crate) fn set_interface(&mut self, interface: Rc<RefCell<Interface>>) { self.interface = interface; }
+
+    /// Sets the network over which agents send statements and enactments.
+    ///
+    /// Any elements still in flight on a previous network are lost.
+    ///
+    /// # Arguments
+    /// - `network`: The [`Network`] to use, or [`None`] to deliver everything immediately.
+    #[inline]
+    pub fn set_network(&mut self, network: Option<Network>) { self.network = network; }
+
+    /// Returns the network over which agents send statements and enactments.
+    ///
+    /// # Returns
+    /// The [`Network`], or [`None`] if everything is delivered immediately.
+    #[inline]
+    pub fn network(&self) -> Option<&Network> { self.network.as_ref() }
+
+    /// Returns the network over which agents send statements and enactments, mutably.
+    ///
+    /// This can be used to change its links or partitions while the simulation runs.
+    ///
+    /// # Returns
+    /// The [`Network`], or [`None`] if everything is delivered immediately.
+    #[inline]
+    pub fn network_mut(&mut self) -> Option<&mut Network> { self.network.as_mut() }
+
+    /// Sets the maximum number of messages an agent may state at once, i.e., in a single
+    /// [`Self::scope()`] or round of the simulation.
+    ///
+    /// Agents stating more are reported as flooding the system, and only the first `limit` of
+    /// their messages are stated. Enactments are not limited.
+    ///
+    /// # Arguments
+    /// - `limit`: The maximum number of messages, or [`None`] to allow any number.
+    #[inline]
+    pub fn set_rate_limit(&mut self, limit: Option<usize>) { self.rate_limit = limit; }
+
+    /// Returns the maximum number of messages an agent may state at once.
+    ///
+    /// # Returns
+    /// The maximum number of messages, or [`None`] if any number is allowed.
+    #[inline]
+    pub fn rate_limit(&self) -> Option<usize> { self.rate_limit }
 
     /// Registers a new agent for target in the statements.
     ///
@@ -167,12 +544,35 @@ impl GlobalStatements {
     /// # Arguments
     /// - `agent`: The new `A`gent to register.
     #[inline]
-    pub fn register(&mut self, agent: impl Identifiable<Id = str>) {
-        let id: &str = agent.id();
-        self.stmts.insert(id.into(), LocalSet::new());
-        self.encts.insert(id.into(), LocalSet::new());
+    pub fn register(&mut self, agent: impl Identifiable<Id = str>) { self.register_id(agent.id()) }
+
+    /// Registers a new agent for target in the statements by its identifier.
+    ///
+    /// # Arguments
+    /// - `agent`: The identifier of the new agent to register.
+    #[inline]
+    pub(crate) fn register_id(&mut self, agent: &str) {
+        self.stmts.register(agent);
+        self.encts.register(agent);
     }
 
+    /// Checks whether an agent is registered.
+    ///
+    /// # Arguments
+    /// - `agent`: The identifier of the agent to check.
+    ///
+    /// # Returns
+    /// True if the agent can be targeted, or false otherwise.
+    #[inline]
+    pub(crate) fn is_registered(&self, agent: &str) -> bool { self.stmts.view(agent).is_some() }
+
+    /// Returns the registered agents.
+    ///
+    /// # Returns
+    /// An iterator over the identifiers of the registered agents, in no particular order.
+    #[inline]
+    pub(crate) fn agents(&self) -> impl Iterator<Item = &str> { self.stmts.agents() }
+
     /// Allows an agent scoped access to the Times-set.
     ///
     /// # Arguments
@@ -185,44 +585,223 @@ impl GlobalStatements {
     #[track_caller]
     pub fn scope<R>(&mut self, agent: &str, func: impl FnOnce(&mut Statements) -> R) -> R {
         // Call the closure
-        let (res, mut stmts_queue, mut encts_queue): (R, Vec<(Target, Message)>, Vec<(Target, Action<Message>)>) = {
-            let mut view = Statements {
-                agent,
-                stmts: self.stmts.get(agent).unwrap_or_else(|| panic!("Unknown given agent '{agent}'")),
-                stmts_queue: vec![],
-                encts: self.encts.get(agent).unwrap_or_else(|| panic!("Unknown given agent '{agent}'")),
-                encts_queue: vec![],
-            };
+        let (res, stmts_queue, encts_queue): (R, Queue<Message>, Queue<Action<Message>>) = {
+            let mut view: Statements = self.view(agent);
             let res: R = func(&mut view);
             (res, view.stmts_queue, view.encts_queue)
         };
 
         // Sync the changes back
-        self.stmts.reserve(stmts_queue.len());
-        self.encts.reserve(encts_queue.len());
-        for (target, stmt) in stmts_queue.drain(..) {
-            self.interface.borrow().log_state(agent, &stmt);
-            if let Target::Agent(target) = target {
-                self.stmts.get_mut(&target).unwrap_or_else(|| panic!("Unknown synchronize agent '{target}'")).add(stmt);
-            } else {
-                for stmts in self.stmts.values_mut() {
-                    stmts.add(stmt.clone());
-                }
+        self.sync(agent, stmts_queue, encts_queue);
+        res
+    }
+
+    /// Returns a [`Statements`] for a particular agent without synchronizing it back.
+    ///
+    /// # Arguments
+    /// - `agent`: The agent to scope this [`GlobalStatements`] for.
+    ///
+    /// # Returns
+    /// A new [`Statements`] of which the queues should be given to [`Self::sync()`].
+    #[inline]
+    #[track_caller]
+    pub(crate) fn view<'s>(&'s self, agent: &'s str) -> Statements<'s> {
+        Statements {
+            agent,
+            stmts: self.stmts.view(agent).unwrap_or_else(|| panic!("Unknown given agent '{agent}'")),
+            stmts_queue: vec![],
+            encts: self.encts.view(agent).unwrap_or_else(|| panic!("Unknown given agent '{agent}'")),
+            encts_queue: vec![],
+        }
+    }
+
+    /// Synchronizes the statements and enactments queued by an agent.
+    ///
+    /// # Arguments
+    /// - `agent`: The agent who queued them.
+    /// - `stmts_queue`: The queue of stated messages of a [`Statements`] returned by [`Self::view()`].
+    /// - `encts_queue`: The queue of enacted actions of that same [`Statements`].
+    #[inline]
+    #[track_caller]
+    pub(crate) fn sync(&mut self, agent: &str, mut stmts_queue: Queue<Message>, encts_queue: Queue<Action<Message>>) {
+        if let Some(limit) = self.rate_limit {
+            if stmts_queue.len() > limit {
+                self.interface.borrow_mut().error_flood(agent, stmts_queue.len(), limit);
+                stmts_queue.truncate(limit);
             }
         }
-        for (target, enct) in encts_queue.drain(..) {
-            self.interface.borrow().log_enact(agent, &enct);
-            if let Target::Agent(target) = target {
-                self.encts.get_mut(&target).unwrap_or_else(|| panic!("Unknown synchronize agent '{target}'")).add(enct);
-            } else {
-                for encts in self.encts.values_mut() {
-                    encts.add(enct.clone());
-                }
+        for (target, stmt) in stmts_queue {
+            self.interface.borrow_mut().log_state(agent, &target, &stmt);
+            self.record_versions(agent, &stmt);
+            self.transmit(agent, target, Parcel::Stmt(stmt));
+        }
+        for (target, enct) in encts_queue {
+            self.interface.borrow_mut().log_enact(agent, &target, &enct);
+            self.transmit(agent, target, Parcel::Enct(Box::new(enct)));
+        }
+    }
+
+    /// Delivers everything that arrives over the network in the given round.
+    ///
+    /// Does nothing if there is no network.
+    ///
+    /// # Arguments
+    /// - `round`: The round that starts.
+    pub(crate) fn arrive(&mut self, round: usize) {
+        let Self { stmts, encts, network, interface, .. } = self;
+        let Some(network) = network else { return };
+        for Packet { from, to, parcel, .. } in network.arrive(round) {
+            interface.borrow_mut().log_deliver(&from, &to, parcel.id(), parcel.is_enacted());
+            deliver(stmts, encts, Target::Agent(to), parcel);
+        }
+    }
+
+    /// Sends something an agent stated or enacted to its target(s), over the network if there is
+    /// one.
+    ///
+    /// # Arguments
+    /// - `agent`: The agent who sent it.
+    /// - `target`: The [`Target`] determining who receives it.
+    /// - `parcel`: The [`Parcel`] to send.
+    ///
+    /// # Panics
+    /// This function panics if the `target` is an unknown agent.
+    #[track_caller]
+    fn transmit(&mut self, agent: &str, target: Target, parcel: Parcel) {
+        let Self { stmts, encts, network, interface, .. } = self;
+        let Some(network) = network else {
+            deliver(stmts, encts, target, parcel);
+            return;
+        };
+
+        // Send it to every recipient individually, in a predictable order
+        for to in recipients(stmts, target) {
+            // Agents always know what they send themselves
+            if to == agent {
+                deliver(stmts, encts, Target::Agent(to), parcel.clone());
+                continue;
+            }
+            match network.transmit(agent, &to, parcel.clone()) {
+                Transmission::Arrived(parcel) => {
+                    interface.borrow_mut().log_deliver(agent, &to, parcel.id(), parcel.is_enacted());
+                    deliver(stmts, encts, Target::Agent(to), parcel);
+                },
+                Transmission::InFlight => {},
+                Transmission::Dropped(parcel, reason) => interface.borrow_mut().log_drop(agent, &to, parcel.id(), parcel.is_enacted(), reason),
             }
         }
+    }
 
-        // OK, done
-        res
+    /// Synchronizes something an agent stated or enacted without sending it to anybody but the
+    /// agent itself.
+    ///
+    /// This is used to replay traces of simulations with a [`Network`], in which the trace tells
+    /// when it arrives at the others (see [`Self::deliver_to()`]).
+    ///
+    /// # Arguments
+    /// - `agent`: The agent who sent it.
+    /// - `target`: The [`Target`] determining who receives it.
+    /// - `parcel`: The [`Parcel`] that was sent.
+    ///
+    /// # Returns
+    /// The identifiers of the other agents who should receive it, sorted.
+    ///
+    /// # Panics
+    /// This function panics if the `target` is an unknown agent.
+    #[track_caller]
+    pub(crate) fn sync_unsent(&mut self, agent: &str, target: Target, parcel: Parcel) -> Vec<String> {
+        match &parcel {
+            Parcel::Stmt(stmt) => {
+                self.interface.borrow_mut().log_state(agent, &target, stmt);
+                self.record_versions(agent, stmt);
+            },
+            Parcel::Enct(enct) => self.interface.borrow_mut().log_enact(agent, &target, enct),
+        }
+        let mut recipients: Vec<String> = recipients(&self.stmts, target);
+        if let Some(i) = recipients.iter().position(|to| to == agent) {
+            recipients.remove(i);
+            deliver(&mut self.stmts, &mut self.encts, Target::Agent(agent.into()), parcel);
+        }
+        recipients
+    }
+
+    /// Delivers something an agent stated or enacted to a particular agent immediately.
+    ///
+    /// # Arguments
+    /// - `to`: The identifier of the agent who receives it.
+    /// - `parcel`: The [`Parcel`] to deliver.
+    ///
+    /// # Panics
+    /// This function panics if `to` is an unknown agent.
+    #[inline]
+    #[track_caller]
+    pub(crate) fn deliver_to(&mut self, to: &str, parcel: Parcel) { deliver(&mut self.stmts, &mut self.encts, Target::Agent(to.into()), parcel); }
+
+    /// Returns the messages known to a particular agent.
+    ///
+    /// # Arguments
+    /// - `agent`: The agent to return the messages of.
+    ///
+    /// # Returns
+    /// An iterator over the [`Message`]s stated to that agent, which may yield multiple versions
+    /// of the same message. It is empty if the agent is unknown.
+    #[inline]
+    pub(crate) fn known_by<'s>(&'s self, agent: &str) -> impl 's + Iterator<Item = &'s Message> {
+        self.stmts.view(agent).into_iter().flat_map(|view| view.iter())
+    }
+
+    /// Hashes the messages and actions known to every agent and whatever is still in flight on
+    /// the network, regardless of the order in which they were sent.
+    ///
+    /// # Arguments
+    /// - `state`: The [`Hasher`] to hash into.
+    pub(crate) fn fingerprint(&self, state: &mut impl Hasher) {
+        self.fingerprint_known(state);
+        if let Some(network) = &self.network {
+            network.fingerprint(state);
+        }
+    }
+
+    /// Hashes the messages and actions known to every agent, regardless of the order in which
+    /// they were sent.
+    ///
+    /// # Arguments
+    /// - `state`: The [`Hasher`] to hash into.
+    pub(crate) fn fingerprint_known(&self, state: &mut impl Hasher) {
+        hash_unordered(
+            self.stmts.agents(),
+            |agent, state| {
+                agent.hash(state);
+                hash_unordered(self.stmts.view(agent).into_iter().flat_map(|view| view.collect()), |msg, state| msg.hash(state), state);
+                hash_unordered(self.encts.view(agent).into_iter().flat_map(|view| view.collect()), hash_action, state);
+            },
+            state,
+        );
+        hash_unordered(
+            &self.versions,
+            |(id, versions), state| {
+                id.hash(state);
+                versions.hash(state);
+            },
+            state,
+        );
+    }
+
+    /// Records the version of a message that is about to be stated, reporting it if it conflicts
+    /// with any known version.
+    ///
+    /// # Arguments
+    /// - `agent`: The agent who is stating the message.
+    /// - `stmt`: The [`Message`] that is about to be stated.
+    fn record_versions(&mut self, agent: &str, stmt: &Message) {
+        let versions: &mut Vec<Message> = self.versions.entry(stmt.id.clone()).or_default();
+        if versions.iter().any(|version| !version.conflicts_with(stmt)) {
+            return;
+        }
+        versions.push(stmt.clone());
+        if versions.len() > 1 {
+            self.interface.borrow_mut().error_equivocation(agent, versions);
+        }
     }
 }
 impl JAStatements for GlobalStatements {
@@ -235,114 +814,119 @@ impl JAStatements for GlobalStatements {
     #[track_caller]
     fn state(&mut self, target: Self::Target, msg: Self::Message) -> Self::Status {
         // Simply add directly
-        match target {
-            Target::All => {
-                for msgs in self.stmts.values_mut() {
-                    msgs.add(msg.clone());
-                }
-            },
-            Target::Agent(agent) => {
-                self.stmts.get_mut(&agent).map(|msgs| msgs.add(msg)).unwrap_or_else(|| panic!("Unknown agent '{agent}'"));
-            },
-        }
+        self.record_versions("<system>", &msg);
+        self.stmts.send(target, msg);
     }
 
     #[inline]
-    fn stated<'s>(&'s self) -> LocalSet<&'s Self::Message> {
-        // Build a set spanning all
-        let mut set: LocalSet<&'s Message> = LocalSet::with_capacity(self.stmts.values().map(LocalSet::len).sum());
-        for msgs in self.stmts.values() {
-            set.extend(msgs);
-        }
-        set
+    fn stated(&self) -> LocalSet<&Self::Message> {
+        // The first version of every message is the one known to whoever first received it
+        self.versions.values().filter_map(|versions| versions.first()).collect()
     }
 
+    #[inline]
+    fn equivocated(&self) -> Vec<&Self::Message> { self.versions.values().filter(|versions| versions.len() > 1).flatten().collect() }
+
 
 
     #[inline]
-    fn enact<'s>(&'s mut self, target: Self::Target, act: Action<Self::Message>) -> Self::Status {
-        // Simply push to the queue
-        match target {
-            Target::All => {
-                for acts in self.encts.values_mut() {
-                    acts.add(act.clone());
-                }
-            },
-            Target::Agent(agent) => {
-                self.encts.get_mut(&agent).map(|acts| acts.add(act)).unwrap_or_else(|| panic!("Unknown agent '{agent}'"));
-            },
-        }
+    fn enact(&mut self, target: Self::Target, act: Action<Self::Message>) -> Self::Status {
+        // Simply add directly
+        self.encts.send(target, act);
     }
 
     #[inline]
-    fn enacted<'s>(&'s self) -> LocalSet<&'s Action<Self::Message>> {
-        // Build a set spanning all
-        let mut set: LocalSet<&'s Action<Message>> = LocalSet::with_capacity(self.encts.values().map(LocalSet::len).sum());
-        for acts in self.encts.values() {
-            set.extend(acts);
-        }
-        set
-    }
+    fn enacted(&self) -> LocalSet<&Action<Self::Message>> { self.encts.iter().collect() }
 }
 
 /// Provides agents with a local view on the stated- and enacted messages.
+///
+/// Agents can only state messages they authored themselves, and only enact actions of which they
+/// authored the enacted message.
 #[derive(Debug)]
 pub struct Statements<'v> {
     /// This agent
     agent: &'v str,
 
     /// The statements that this agent knows of.
-    stmts: &'v LocalSet<Message>,
+    stmts: Mailbox<'v, Message>,
     /// A queue of statements that this agent pushed.
-    pub(crate) stmts_queue: Vec<(Target, Message)>,
+    pub(crate) stmts_queue: Queue<Message>,
 
     /// The enactments that this agent knows of.
-    encts: &'v LocalSet<Action<Message>>,
+    encts: Mailbox<'v, Action<Message>>,
     /// A queue of enactments that this agent pushed.
-    pub(crate) encts_queue: Vec<(Target, Action<Message>)>,
+    pub(crate) encts_queue: Queue<Action<Message>>,
 }
 impl<'v> JAStatements for Statements<'v> {
     type Message = Message;
     type Target = Target;
-    type Status = ();
+    type Status = Result<(), StatementsError>;
 
 
     #[inline]
     #[track_caller]
     fn state(&mut self, target: Self::Target, msg: Self::Message) -> Self::Status {
-        // Simply push to the queue
+        // Only accept messages by this agent
+        if msg.author != self.agent {
+            return Err(StatementsError::NotTheAuthor { id: msg.id, author: msg.author, agent: self.agent.into() });
+        }
+
+        // Push to the queue
         self.stmts_queue.push((target, msg));
+        Ok(())
     }
 
     #[inline]
-    fn stated<'s>(&'s self) -> LocalSet<&'s Self::Message> {
+    fn stated(&self) -> LocalSet<&Self::Message> {
         // Start with what we know...
-        let mut set: LocalSet<&'s Message> = self.stmts.iter().collect();
+        let mut set: LocalSet<&Message> = self.stmts.collect();
         // ...and push any queued items for us
         for (target, msg) in &self.stmts_queue {
-            if target.matches(&self.agent) {
-                set.add(msg.into());
+            if target.matches(self.agent) {
+                set.add(msg);
             }
         }
         // OK
         set
     }
 
+    #[inline]
+    fn equivocated(&self) -> Vec<&Self::Message> {
+        // Collect the distinct versions of everything we know of, including any queued items for us...
+        let mut versions: HashMap<&str, Vec<&Message>> = HashMap::new();
+        let queued = self.stmts_queue.iter().filter(|(target, _)| target.matches(self.agent)).map(|(_, msg)| msg);
+        for msg in self.stmts.iter().chain(queued) {
+            let known: &mut Vec<&Message> = versions.entry(msg.id.as_str()).or_default();
+            if known.iter().all(|version| version.conflicts_with(msg)) {
+                known.push(msg);
+            }
+        }
+        // ...and report those with more than one
+        versions.into_values().filter(|versions| versions.len() > 1).flatten().collect()
+    }
+
 
 
     #[inline]
-    fn enact<'s>(&'s mut self, target: Self::Target, act: Action<Self::Message>) -> Self::Status {
-        // Simply push to the queue
+    fn enact(&mut self, target: Self::Target, act: Action<Self::Message>) -> Self::Status {
+        // Only accept actions by this agent
+        if act.enacts.author != self.agent {
+            return Err(StatementsError::NotTheActor { id: act.enacts.id, author: act.enacts.author, agent: self.agent.into() });
+        }
+
+        // Push to the queue
         self.encts_queue.push((target, act));
+        Ok(())
     }
 
     #[inline]
-    fn enacted<'s>(&'s self) -> LocalSet<&'s Action<Self::Message>> {
+    fn enacted(&self) -> LocalSet<&Action<Self::Message>> {
         // Start with what we know...
-        let mut set: LocalSet<&'s Action<Message>> = self.encts.iter().collect();
+        let mut set: LocalSet<&Action<Message>> = self.encts.collect();
         // ...and push any queued items for us
         for (target, act) in &self.encts_queue {
-            if target.matches(&self.agent) {
+            if target.matches(self.agent) {
                 set.add(act);
             }
         }
@@ -350,7 +934,7 @@ impl<'v> JAStatements for Statements<'v> {
         set
     }
 }
-impl<'s, 'v> JAStatements for &'s mut Statements<'v> {
+impl<'v> JAStatements for &mut Statements<'v> {
     type Message = <Statements<'v> as JAStatements>::Message;
     type Target = <Statements<'v> as JAStatements>::Target;
     type Status = <Statements<'v> as JAStatements>::Status;
@@ -361,7 +945,10 @@ impl<'s, 'v> JAStatements for &'s mut Statements<'v> {
 
     #[inline]
     #[track_caller]
-    fn stated<'s2>(&'s2 self) -> LocalSet<&'s2 Self::Message> { Statements::stated(self) }
+    fn stated(&self) -> LocalSet<&Self::Message> { Statements::stated(self) }
+
+    #[inline]
+    fn equivocated(&self) -> Vec<&Self::Message> { Statements::equivocated(self) }
 
     #[inline]
     #[track_caller]
@@ -369,5 +956,5 @@ impl<'s, 'v> JAStatements for &'s mut Statements<'v> {
 
     #[inline]
     #[track_caller]
-    fn enacted<'s2>(&'s2 self) -> LocalSet<&'s2 Action<Self::Message>> { Statements::enacted(self) }
+    fn enacted(&self) -> LocalSet<&Action<Self::Message>> { Statements::enacted(self) }
 }
@@ -4,7 +4,7 @@
 //  Created:
 //    23 May 2024, 17:36:27
 //  Last edited:
-//    29 May 2024, 13:40:28
+//    16 Oct 2026, 22:13:15
 //  Auto updated?
 //    Yes
 //
@@ -13,15 +13,102 @@
 //
 
 use std::cell::RefCell;
+use std::collections::{BTreeMap, HashSet};
 use std::error::Error;
 use std::fmt::{Display, Formatter, Result as FResult};
+use std::hash::{Hash as _, Hasher};
 use std::rc::Rc;
 
 use justact_core::times::{Times as JATimes, Timestamp};
 
+use crate::agreements::{Outcome, Quorum};
+use crate::explorer::hash_unordered;
 use crate::interface::Interface;
 
 
+/***** TESTS *****/
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{Event, MemorySink};
+
+
+    /// Creates a GlobalTimesConsensus with agents `a`, `b` and `c` as voters, who need a majority.
+    ///
+    /// # Returns
+    /// A new [`GlobalTimesConsensus`], and a [`MemorySink`] that receives its events.
+    fn consensus() -> (GlobalTimesConsensus, MemorySink) {
+        let sink: MemorySink = MemorySink::new();
+        let mut interface: Interface = Interface::new();
+        interface.set_quiet(true);
+        interface.add_sink(Box::new(sink.clone()));
+        let mut times: GlobalTimesConsensus = GlobalTimesConsensus::new(Quorum::Majority, Rc::new(RefCell::new(interface)));
+        for agent in ["a", "b", "c"] {
+            times.register(agent);
+        }
+        (times, sink)
+    }
+
+
+    #[test]
+    fn test_times_consensus_backwards() {
+        let (mut times, sink): (GlobalTimesConsensus, MemorySink) = consensus();
+        times.advance_to(Timestamp(2)).unwrap();
+        assert!(matches!(
+            times.scope("a", |times| times.advance_to(Timestamp(2))),
+            Err(TimesConsensusError::Backwards { agent, time: Timestamp(2), current: Timestamp(2) }) if agent == "a"
+        ));
+        assert!(matches!(times.advance_to(Timestamp(1)), Err(TimesConsensusError::Backwards { time: Timestamp(1), current: Timestamp(2), .. })));
+
+        // Proposals that fell behind before they were synchronized are ignored
+        sink.take();
+        times.sync("a", vec![Timestamp(1)]);
+        assert!(times.proposed().is_empty());
+        assert!(matches!(&sink.take()[..], [Event::Errored { agent, .. }] if agent == "a"));
+    }
+
+    #[test]
+    fn test_times_consensus_quorum() {
+        let (mut times, sink): (GlobalTimesConsensus, MemorySink) = consensus();
+        times.scope("a", |times| {
+            times.advance_to(Timestamp(1))?;
+            times.advance_to(Timestamp(2))?;
+            times.advance_to(Timestamp(3))
+        })
+        .unwrap();
+        assert_eq!(times.current(), Timestamp(0));
+        assert_eq!(times.proposed(), vec![Timestamp(1), Timestamp(2), Timestamp(3)]);
+
+        // A majority advances the time, rejecting anything proposed before it
+        sink.take();
+        times.scope("b", |times| times.advance_to(Timestamp(2))).unwrap();
+        assert_eq!(times.current(), Timestamp(2));
+        assert_eq!(times.proposed(), vec![Timestamp(3)]);
+        let decided: Vec<(Timestamp, Outcome)> =
+            sink.take().into_iter().filter_map(|event| if let Event::TimeDecided { time, outcome, .. } = event { Some((time, outcome)) } else { None }).collect();
+        assert_eq!(decided, vec![(Timestamp(2), Outcome::Accepted), (Timestamp(1), Outcome::Rejected)]);
+    }
+
+    #[test]
+    fn test_times_consensus_proposed() {
+        let (mut times, _): (GlobalTimesConsensus, MemorySink) = consensus();
+        times.scope("a", |times| times.advance_to(Timestamp(2))).unwrap();
+
+        // Agents see the pending proposals and their own, queued ones
+        let mut view: TimesConsensus = times.view("b");
+        view.advance_to(Timestamp(3)).unwrap();
+        view.advance_to(Timestamp(2)).unwrap();
+        view.advance_to(Timestamp(1)).unwrap();
+        assert_eq!(view.proposed(), vec![Timestamp(1), Timestamp(2), Timestamp(3)]);
+        assert_eq!(view.current(), Timestamp(0));
+        assert_eq!(times.view("c").proposed(), vec![Timestamp(2)]);
+    }
+}
+
+
+
+
+
 /***** ERRORS *****/
 /// Determines the possible errors for the [`TimesDicatator`] set.
 #[derive(Debug)]
@@ -42,17 +129,239 @@ impl Display for TimesDictatorError {
 }
 impl Error for TimesDictatorError {}
 
+/// Determines the possible errors for the [`TimesConsensus`] set.
+#[derive(Debug)]
+pub enum TimesConsensusError {
+    /// The agent attempted to propose a time that is not after the current one.
+    Backwards { agent: String, time: Timestamp, current: Timestamp },
+}
+impl Display for TimesConsensusError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use TimesConsensusError::*;
+        match self {
+            Backwards { agent, time, current } => {
+                write!(f, "Agent '{agent}' failed to propose advancing the time to {time} because it is not after the current time ({current})")
+            },
+        }
+    }
+}
+impl Error for TimesConsensusError {}
+
+/// Determines the possible errors for the [`Times`] set.
+#[derive(Debug)]
+pub enum TimesError {
+    /// The time is advanced by a dictator.
+    Dictator { err: TimesDictatorError },
+    /// The time is advanced by consensus.
+    Consensus { err: TimesConsensusError },
+}
+impl Display for TimesError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use TimesError::*;
+        match self {
+            Dictator { err } => err.fmt(f),
+            Consensus { err } => err.fmt(f),
+        }
+    }
+}
+impl Error for TimesError {}
+impl From<TimesDictatorError> for TimesError {
+    #[inline]
+    fn from(value: TimesDictatorError) -> Self { Self::Dictator { err: value } }
+}
+impl From<TimesConsensusError> for TimesError {
+    #[inline]
+    fn from(value: TimesConsensusError) -> Self { Self::Consensus { err: value } }
+}
+
 
 
 
 
 /***** LIBRARY *****/
+/// The times of a [`Simulation`](crate::Simulation), which are either advanced by a dictator or
+/// by consensus.
+///
+/// Agents will see the agent-scoped variation [`Times`].
+#[derive(Clone, Debug)]
+pub enum GlobalTimes {
+    /// A single agent advances the time.
+    Dictator(GlobalTimesDictator),
+    /// Agents vote on every new time.
+    Consensus(GlobalTimesConsensus),
+}
+impl GlobalTimes {
+    /// Registers a new agent, who may vote on new times if they are advanced by consensus.
+    ///
+    /// # Arguments
+    /// - `agent`: The identifier of the agent to register.
+    #[inline]
+    pub(crate) fn register(&mut self, agent: &str) {
+        if let Self::Consensus(times) = self {
+            times.register(agent);
+        }
+    }
+
+    /// Changes the interface to which whatever happens is logged.
+    ///
+    /// # Arguments
+    /// - `interface`: The new interface to log to.
+    #[inline]
+    pub(crate) fn set_interface(&mut self, interface: Rc<RefCell<Interface>>) {
+        match self {
+            Self::Dictator(times) => times.set_interface(interface),
+            Self::Consensus(times) => times.set_interface(interface),
+        }
+    }
+
+    /// Returns a [`Times`] for a particular agent without synchronizing it back.
+    ///
+    /// # Arguments
+    /// - `agent`: The agent to scope this [`GlobalTimes`] for.
+    ///
+    /// # Returns
+    /// A new [`Times`] of which the queue should be given to [`Self::sync()`].
+    #[inline]
+    pub(crate) fn view<'s>(&'s self, agent: &'s str) -> Times<'s> {
+        match self {
+            Self::Dictator(times) => Times::Dictator(times.view(agent)),
+            Self::Consensus(times) => Times::Consensus(times.view(agent)),
+        }
+    }
+
+    /// Synchronizes the times queued by an agent.
+    ///
+    /// # Arguments
+    /// - `agent`: The agent who queued them.
+    /// - `queue`: The queue of a [`Times`] returned by [`Self::view()`].
+    #[inline]
+    pub(crate) fn sync(&mut self, agent: &str, queue: Vec<Timestamp>) {
+        match self {
+            Self::Dictator(times) => times.sync(agent, queue),
+            Self::Consensus(times) => times.sync(agent, queue),
+        }
+    }
+
+    /// Hashes the current time, and any proposed times.
+    ///
+    /// # Arguments
+    /// - `state`: The [`Hasher`] to hash into.
+    #[inline]
+    pub(crate) fn fingerprint(&self, state: &mut impl Hasher) {
+        match self {
+            Self::Dictator(times) => times.fingerprint(state),
+            Self::Consensus(times) => times.fingerprint(state),
+        }
+    }
+}
+impl From<GlobalTimesDictator> for GlobalTimes {
+    #[inline]
+    fn from(value: GlobalTimesDictator) -> Self { Self::Dictator(value) }
+}
+impl From<GlobalTimesConsensus> for GlobalTimes {
+    #[inline]
+    fn from(value: GlobalTimesConsensus) -> Self { Self::Consensus(value) }
+}
+impl JATimes for GlobalTimes {
+    type Error = TimesError;
+
+    #[inline]
+    fn current(&self) -> Timestamp {
+        match self {
+            Self::Dictator(times) => times.current(),
+            Self::Consensus(times) => times.current(),
+        }
+    }
+
+    #[inline]
+    fn advance_to(&mut self, timestamp: Timestamp) -> Result<(), Self::Error> {
+        match self {
+            Self::Dictator(times) => Ok(times.advance_to(timestamp)?),
+            Self::Consensus(times) => Ok(times.advance_to(timestamp)?),
+        }
+    }
+
+    #[inline]
+    fn proposed(&self) -> Vec<Timestamp> {
+        match self {
+            Self::Dictator(times) => times.proposed(),
+            Self::Consensus(times) => times.proposed(),
+        }
+    }
+}
+
+/// Provides agents with a global view on the current time, which is either advanced by a
+/// dictator or by consensus.
+#[derive(Debug)]
+pub enum Times<'v> {
+    /// A single agent advances the time.
+    Dictator(TimesDictator<'v>),
+    /// Agents vote on every new time.
+    Consensus(TimesConsensus<'v>),
+}
+impl<'v> Times<'v> {
+    /// Releases this view, keeping only the times the agent queued.
+    ///
+    /// # Returns
+    /// The queued [`Timestamp`]s to give to [`GlobalTimes::sync()`].
+    #[inline]
+    pub(crate) fn into_queue(self) -> Vec<Timestamp> {
+        match self {
+            Self::Dictator(times) => times.queue,
+            Self::Consensus(times) => times.queue,
+        }
+    }
+}
+impl<'v> JATimes for Times<'v> {
+    type Error = TimesError;
+
+    #[inline]
+    fn current(&self) -> Timestamp {
+        match self {
+            Self::Dictator(times) => times.current(),
+            Self::Consensus(times) => times.current(),
+        }
+    }
+
+    #[inline]
+    fn advance_to(&mut self, timestamp: Timestamp) -> Result<(), Self::Error> {
+        match self {
+            Self::Dictator(times) => Ok(times.advance_to(timestamp)?),
+            Self::Consensus(times) => Ok(times.advance_to(timestamp)?),
+        }
+    }
+
+    #[inline]
+    fn proposed(&self) -> Vec<Timestamp> {
+        match self {
+            Self::Dictator(times) => times.proposed(),
+            Self::Consensus(times) => times.proposed(),
+        }
+    }
+}
+impl<'v> JATimes for &mut Times<'v> {
+    type Error = <Times<'v> as JATimes>::Error;
+
+    #[inline]
+    fn current(&self) -> Timestamp { Times::current(self) }
+
+    #[inline]
+    fn advance_to(&mut self, timestamp: Timestamp) -> Result<(), Self::Error> { Times::advance_to(self, timestamp) }
+
+    #[inline]
+    fn proposed(&self) -> Vec<Timestamp> { Times::proposed(self) }
+}
+
+
+
 /// An owned version of the times.
 ///
 /// This variation synchronizes new times if and only if it's a particular agent claiming it.
 ///
 /// Agents will see the agent-scoped variation [`TimesDictator`].
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct GlobalTimesDictator {
     /// The only agent allowed to make changes.
     dictator:  String,
@@ -76,6 +385,13 @@ impl GlobalTimesDictator {
         Self { dictator: dictator.clone(), interface, current: Timestamp(0) }
     }
 
+    /// Changes the interface to which whatever happens is logged.
+    ///
+    /// # Arguments
+    /// - `interface`: The new interface to log to.
+    #[inline]
+    pub(crate) fn set_interface(&mut self, interface: Rc<RefCell<Interface>>) { self.interface = interface; }
+
     /// Allows an agent scoped access to the Times-set.
     ///
     /// # Arguments
@@ -87,18 +403,48 @@ impl GlobalTimesDictator {
     #[inline]
     pub fn scope<R>(&mut self, agent: &str, func: impl FnOnce(&mut TimesDictator) -> R) -> R {
         // Call the closure
-        let mut view = TimesDictator { agent, dictator: &self.dictator, current: self.current, queue: vec![] };
-        let res: R = func(&mut view);
+        let (res, queue): (R, Vec<Timestamp>) = {
+            let mut view: TimesDictator = self.view(agent);
+            let res: R = func(&mut view);
+            (res, view.queue)
+        };
 
         // Sync the changes back
-        if let Some(current) = view.queue.pop() {
+        self.sync(agent, queue);
+        res
+    }
+
+    /// Returns a [`TimesDictator`] for a particular agent without synchronizing it back.
+    ///
+    /// # Arguments
+    /// - `agent`: The agent to scope this [`GlobalTimesDictator`] for.
+    ///
+    /// # Returns
+    /// A new [`TimesDictator`] of which the queue should be given to [`Self::sync()`].
+    #[inline]
+    pub(crate) fn view<'s>(&'s self, agent: &'s str) -> TimesDictator<'s> {
+        TimesDictator { agent, dictator: &self.dictator, current: self.current, queue: vec![] }
+    }
+
+    /// Synchronizes the times queued by an agent.
+    ///
+    /// # Arguments
+    /// - `agent`: The agent who queued them.
+    /// - `queue`: The queue of a [`TimesDictator`] returned by [`Self::view()`].
+    #[inline]
+    pub(crate) fn sync(&mut self, agent: &str, mut queue: Vec<Timestamp>) {
+        if let Some(current) = queue.pop() {
             self.current = current;
-            self.interface.borrow().log_advance(current);
+            self.interface.borrow_mut().log_advance(agent, current);
         }
-
-        // OK, done
-        res
     }
+
+    /// Hashes the current time.
+    ///
+    /// # Arguments
+    /// - `state`: The [`Hasher`] to hash into.
+    #[inline]
+    pub(crate) fn fingerprint(&self, state: &mut impl Hasher) { self.current.hash(state); }
 }
 impl JATimes for GlobalTimesDictator {
     type Error = TimesDictatorError;
@@ -153,7 +499,7 @@ impl<'v> JATimes for TimesDictator<'v> {
         }
     }
 }
-impl<'t, 'v> JATimes for &'t mut TimesDictator<'v> {
+impl<'v> JATimes for &mut TimesDictator<'v> {
     type Error = <TimesDictator<'v> as JATimes>::Error;
 
     #[inline]
@@ -161,4 +507,276 @@ impl<'t, 'v> JATimes for &'t mut TimesDictator<'v> {
 
     #[inline]
     fn advance_to(&mut self, timestamp: Timestamp) -> Result<(), Self::Error> { TimesDictator::advance_to(self, timestamp) }
+
+    #[inline]
+    fn proposed(&self) -> Vec<Timestamp> { TimesDictator::proposed(self) }
+}
+
+
+
+/// An owned version of the times.
+///
+/// This variation advances the time if and only if a [`Quorum`] of agents votes in favour of it.
+/// Any agent may propose a new time by advancing to it, which counts as a vote in favour of that
+/// time. Once a time is agreed upon, any proposed times before it are rejected.
+///
+/// Agents will see the agent-scoped variation [`TimesConsensus`].
+#[derive(Clone, Debug)]
+pub struct GlobalTimesConsensus {
+    /// Determines how many agents must vote in favour of a time.
+    quorum:    Quorum,
+    /// The agents who may vote.
+    voters:    Vec<String>,
+    /// The proposed times, together with the agents who voted in favour of them.
+    proposals: BTreeMap<Timestamp, HashSet<String>>,
+    /// An interface we use to log whatever happens in pretty ways.
+    interface: Rc<RefCell<Interface>>,
+    /// The current timestamp.
+    current:   Timestamp,
+}
+impl GlobalTimesConsensus {
+    /// Constructor for the GlobalTimesConsensus.
+    ///
+    /// # Arguments
+    /// - `quorum`: The [`Quorum`] that must vote in favour of a new time.
+    /// - `interface`: An interface we use to log whatever happens in pretty ways.
+    ///
+    /// # Returns
+    /// A new GlobalTimesConsensus without any voters.
+    #[inline]
+    pub fn new(quorum: Quorum, interface: Rc<RefCell<Interface>>) -> Self {
+        Self { quorum, voters: Vec::new(), proposals: BTreeMap::new(), interface, current: Timestamp(0) }
+    }
+
+    /// Changes the interface to which whatever happens is logged.
+    ///
+    /// # Arguments
+    /// - `interface`: The new interface to log to.
+    #[inline]
+    pub(crate) fn set_interface(&mut self, interface: Rc<RefCell<Interface>>) { self.interface = interface; }
+
+    /// Returns the quorum that must vote in favour of a new time.
+    ///
+    /// # Returns
+    /// The [`Quorum`] of this GlobalTimesConsensus.
+    #[inline]
+    pub fn quorum(&self) -> &Quorum { &self.quorum }
+
+    /// Registers a new agent who may vote.
+    ///
+    /// # Arguments
+    /// - `agent`: The identifier of the agent to register.
+    #[inline]
+    pub fn register(&mut self, agent: &str) {
+        if !self.voters.iter().any(|voter| voter == agent) {
+            self.voters.push(agent.into());
+        }
+    }
+
+    /// Allows an agent scoped access to the Times-set.
+    ///
+    /// # Arguments
+    /// - `agent`: The agent to scope this [`GlobalTimesConsensus`] for.
+    /// - `func`: Some function that is executed for this scope.
+    ///
+    /// # Returns
+    /// The result of the given closure `func`.
+    #[inline]
+    pub fn scope<R>(&mut self, agent: &str, func: impl FnOnce(&mut TimesConsensus) -> R) -> R {
+        // Call the closure
+        let (res, queue): (R, Vec<Timestamp>) = {
+            let mut view: TimesConsensus = self.view(agent);
+            let res: R = func(&mut view);
+            (res, view.queue)
+        };
+
+        // Sync the changes back
+        self.sync(agent, queue);
+        res
+    }
+
+    /// Returns a [`TimesConsensus`] for a particular agent without synchronizing it back.
+    ///
+    /// # Arguments
+    /// - `agent`: The agent to scope this [`GlobalTimesConsensus`] for.
+    ///
+    /// # Returns
+    /// A new [`TimesConsensus`] of which the queue should be given to [`Self::sync()`].
+    #[inline]
+    pub(crate) fn view<'s>(&'s self, agent: &'s str) -> TimesConsensus<'s> {
+        TimesConsensus { agent, current: self.current, proposals: &self.proposals, queue: vec![] }
+    }
+
+    /// Synchronizes the times proposed by an agent, advancing the time if any of them reaches its
+    /// quorum.
+    ///
+    /// # Arguments
+    /// - `agent`: The agent who proposed them.
+    /// - `queue`: The queue of a [`TimesConsensus`] returned by [`Self::view()`].
+    pub(crate) fn sync(&mut self, agent: &str, queue: Vec<Timestamp>) {
+        for time in queue {
+            // Another agent may have advanced the time since this one proposed it
+            if time <= self.current {
+                self.interface
+                    .borrow_mut()
+                    .error(agent, format!("Ignoring proposal to advance the time to {time} because it is not after the current time ({})", self.current));
+                continue;
+            }
+
+            // Vote in favour of it, proposing it if nobody did so before
+            let approvals: &mut HashSet<String> = self.proposals.entry(time).or_default();
+            if approvals.is_empty() {
+                self.interface.borrow_mut().log_propose_time(agent, time);
+            } else {
+                self.interface.borrow_mut().log_vote_time(agent, time);
+            }
+            approvals.insert(agent.into());
+            self.decide(agent, time);
+        }
+    }
+
+    /// Replays that an agent proposed (or voted in favour of) a new time, without deciding it.
+    ///
+    /// # Arguments
+    /// - `agent`: The agent who proposed it.
+    /// - `time`: The proposed [`Timestamp`].
+    #[inline]
+    pub(crate) fn replay_propose(&mut self, agent: &str, time: Timestamp) { self.proposals.entry(time).or_default().insert(agent.into()); }
+
+    /// Replays that a proposed time was decided, removing it. If it was accepted, the time is
+    /// advanced by [`Self::replay_advance()`].
+    ///
+    /// # Arguments
+    /// - `time`: The proposed [`Timestamp`].
+    #[inline]
+    pub(crate) fn replay_decide(&mut self, time: Timestamp) { self.proposals.remove(&time); }
+
+    /// Replays that the time was advanced.
+    ///
+    /// # Arguments
+    /// - `time`: The new current [`Timestamp`].
+    #[inline]
+    pub(crate) fn replay_advance(&mut self, time: Timestamp) { self.current = time; }
+
+    /// Decides a proposed time if it reached (or can no longer reach) its quorum.
+    ///
+    /// # Arguments
+    /// - `agent`: The agent whose vote may have decided it.
+    /// - `time`: The proposed [`Timestamp`].
+    fn decide(&mut self, agent: &str, time: Timestamp) {
+        let outcome: Option<Outcome> = match self.proposals.get(&time) {
+            Some(approvals) => self.quorum.decide(self.voters.iter().map(String::as_str), approvals, &HashSet::new()),
+            None => None,
+        };
+        match outcome {
+            Some(Outcome::Accepted) => {
+                self.proposals.remove(&time);
+                self.interface.borrow_mut().log_decide_time(agent, time, Outcome::Accepted);
+                self.interface.borrow_mut().log_advance(agent, time);
+                self.advance(time);
+            },
+            Some(outcome) => {
+                self.proposals.remove(&time);
+                self.interface.borrow_mut().log_decide_time(agent, time, outcome);
+            },
+            None => {},
+        }
+    }
+
+    /// Advances the time, rejecting any proposed times that are not after it.
+    ///
+    /// # Arguments
+    /// - `time`: The new current [`Timestamp`].
+    fn advance(&mut self, time: Timestamp) {
+        self.current = time;
+        let rest: BTreeMap<Timestamp, HashSet<String>> = self.proposals.split_off(&Timestamp(time.0.saturating_add(1)));
+        for stale in std::mem::replace(&mut self.proposals, rest).into_keys() {
+            self.interface.borrow_mut().log_decide_time("<system>", stale, Outcome::Rejected);
+        }
+    }
+
+    /// Hashes the current time and the proposed times.
+    ///
+    /// # Arguments
+    /// - `state`: The [`Hasher`] to hash into.
+    pub(crate) fn fingerprint(&self, state: &mut impl Hasher) {
+        self.current.hash(state);
+        state.write_usize(self.proposals.len());
+        for (time, approvals) in &self.proposals {
+            time.hash(state);
+            hash_unordered(approvals, |agent, state| agent.hash(state), state);
+        }
+    }
+}
+impl JATimes for GlobalTimesConsensus {
+    type Error = TimesConsensusError;
+
+    #[inline]
+    fn current(&self) -> Timestamp { self.current }
+
+    #[inline]
+    fn advance_to(&mut self, timestamp: Timestamp) -> Result<(), Self::Error> {
+        // The system itself does not need a vote, but still cannot go back
+        if timestamp <= self.current {
+            return Err(TimesConsensusError::Backwards { agent: "<system>".into(), time: timestamp, current: self.current });
+        }
+        self.advance(timestamp);
+        Ok(())
+    }
+
+    #[inline]
+    fn proposed(&self) -> Vec<Timestamp> { self.proposals.keys().copied().collect() }
+}
+
+/// Provides agents with a global view on the current time.
+///
+/// This variation advances the time if and only if a [`Quorum`] of agents votes in favour of it.
+/// As such, advancing it only proposes the new time, and the current time stays the same until
+/// it is agreed upon.
+#[derive(Debug)]
+pub struct TimesConsensus<'v> {
+    /// This agent
+    agent: &'v str,
+
+    /// The current time.
+    current:   Timestamp,
+    /// The proposed times, together with the agents who voted in favour of them.
+    proposals: &'v BTreeMap<Timestamp, HashSet<String>>,
+    /// A queue of times that this agent proposed (or voted in favour of).
+    pub(crate) queue: Vec<Timestamp>,
+}
+impl<'v> JATimes for TimesConsensus<'v> {
+    type Error = TimesConsensusError;
+
+    #[inline]
+    fn current(&self) -> Timestamp { self.current }
+
+    #[inline]
+    fn advance_to(&mut self, timestamp: Timestamp) -> Result<(), Self::Error> {
+        if timestamp <= self.current {
+            return Err(TimesConsensusError::Backwards { agent: self.agent.into(), time: timestamp, current: self.current });
+        }
+        self.queue.push(timestamp);
+        Ok(())
+    }
+
+    #[inline]
+    fn proposed(&self) -> Vec<Timestamp> {
+        let mut proposed: Vec<Timestamp> = self.proposals.keys().chain(self.queue.iter()).copied().collect();
+        proposed.sort_unstable();
+        proposed.dedup();
+        proposed
+    }
+}
+impl<'v> JATimes for &mut TimesConsensus<'v> {
+    type Error = <TimesConsensus<'v> as JATimes>::Error;
+
+    #[inline]
+    fn current(&self) -> Timestamp { TimesConsensus::current(self) }
+
+    #[inline]
+    fn advance_to(&mut self, timestamp: Timestamp) -> Result<(), Self::Error> { TimesConsensus::advance_to(self, timestamp) }
+
+    #[inline]
+    fn proposed(&self) -> Vec<Timestamp> { TimesConsensus::proposed(self) }
 }
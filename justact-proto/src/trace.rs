@@ -0,0 +1,706 @@
+//  TRACE.rs
+//    by Lut99
+//
+//  Created:
+//    16 Oct 2026, 19:56:40
+//  Last edited:
+//    16 Oct 2026, 22:20:40
+//  Auto updated?
+//    Yes
+//
+//  Description:
+//!   Implements recording the events of a [`Simulation`](crate::Simulation)
+//!   as a trace of JSON lines, and replaying such traces to rebuild the
+//!   simulation's state without running any agents.
+//
+
+use std::cell::RefCell;
+use std::error;
+use std::fmt::{Debug, Display, Formatter, Result as FResult};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use console::Style;
+use justact_core::auditors::Auditor;
+use justact_core::auxillary::Identifiable as _;
+use justact_core::policy::Extractor;
+use justact_core::statements::{Action, Statements as _};
+
+use crate::agreements::{GlobalAgreements, GlobalAgreementsConsensus, GlobalAgreementsDictator};
+use crate::events::{Decision, Event, EventSink};
+use crate::interface::Interface;
+use crate::network::{NetworkConfig, Parcel};
+use crate::statements::{GlobalStatements, Message, Target};
+use crate::times::{GlobalTimes, GlobalTimesConsensus, GlobalTimesDictator};
+
+
+/***** TESTS *****/
+#[cfg(test)]
+mod tests {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::Hasher as _;
+
+    use justact_core::agreements::Agreements as _;
+    use justact_core::auditors::Paper;
+    use justact_core::times::{Times as _, Timestamp};
+
+    use super::*;
+    use crate::agreements::Quorum;
+    use crate::events::MemorySink;
+    use crate::mock::{MockAgent, MockExtractor, MockStep};
+    use crate::network::{DropReason, Link, Network};
+    use crate::Simulation;
+
+
+    /// Runs agents for some rounds while recording their events, replays those events and asserts
+    /// that the replay rebuilt what the simulation knows.
+    ///
+    /// # Arguments
+    /// - `setup`: Some closure that sets up the simulation before its events are recorded.
+    /// - `agents`: The [`MockAgent`]s to run, in order.
+    /// - `rounds`: The number of rounds to run.
+    ///
+    /// # Returns
+    /// The [`Simulation`] afterwards, the [`Replay`] of it and the recorded [`Event`]s.
+    fn roundtrip(
+        setup: impl FnOnce(&mut Simulation<MockAgent>),
+        agents: impl IntoIterator<Item = MockAgent>,
+        rounds: usize,
+    ) -> (Simulation<MockAgent>, Replay, Vec<Event>) {
+        let mut sim: Simulation<MockAgent> = Simulation::new("e");
+        sim.set_quiet(true);
+        setup(&mut sim);
+        let sink: MemorySink = MemorySink::new();
+        sim.add_sink(sink.clone());
+        for agent in agents {
+            sim.register(agent, Style::new());
+        }
+        for _ in 0..rounds {
+            sim.poll().unwrap();
+        }
+        let (replay, events): (Replay, Vec<Event>) = replay(&sim, &sink);
+        (sim, replay, events)
+    }
+
+    /// Replays the events recorded from a simulation and asserts that the replay rebuilt what the
+    /// simulation knows.
+    ///
+    /// # Arguments
+    /// - `sim`: The [`Simulation`] that was run.
+    /// - `sink`: The [`MemorySink`] that recorded its events.
+    ///
+    /// # Returns
+    /// The [`Replay`] of the simulation and the recorded [`Event`]s.
+    fn replay(sim: &Simulation<MockAgent>, sink: &MemorySink) -> (Replay, Vec<Event>) {
+        // Replay it through JSON
+        let events: Vec<Event> = sink.events();
+        let trace: String = events.iter().map(|event| serde_json::to_string(event).unwrap() + "\n").collect();
+        let replay: Replay = Replay::read(trace.as_bytes()).unwrap();
+        let (mut expected, mut actual): (DefaultHasher, DefaultHasher) = (DefaultHasher::new(), DefaultHasher::new());
+        sim.fingerprint_known(&mut expected);
+        replay.fingerprint(&mut actual);
+        assert_eq!(actual.finish(), expected.finish(), "replay differs from simulation");
+        (replay, events)
+    }
+
+
+    #[test]
+    fn test_replay_consensus() {
+        let (_, replay, events): (_, Replay, Vec<Event>) = roundtrip(
+            |sim| {
+                sim.set_consensus(Quorum::Majority, 1);
+                sim.set_time_consensus(Quorum::Majority);
+            },
+            [
+                MockAgent::new("a", [
+                    MockStep::Agree("rules".into()),
+                    MockStep::Advance(Timestamp(1)),
+                    MockStep::Agree("stale".into()),
+                    MockStep::Enact { id: "a-act".into(), just: vec![] },
+                ]),
+                MockAgent::new("b", [MockStep::Agree("rules".into()), MockStep::Advance(Timestamp(1))]),
+                MockAgent::new("c", [
+                    MockStep::State(vec!["c1".into()]),
+                    MockStep::State(vec![]),
+                    MockStep::State(vec![]),
+                    MockStep::State(vec![]),
+                    MockStep::State(vec![]),
+                    MockStep::Advance(Timestamp(5)),
+                    MockStep::Agree("late".into()),
+                ]),
+            ],
+            7,
+        );
+        assert_eq!(replay.agrs.agreed().into_iter().map(|agr| agr.id().to_string()).collect::<Vec<String>>(), vec!["rules".to_string()]);
+        assert!(replay.agrs.proposed().contains("late"));
+        assert_eq!(replay.times.current(), Timestamp(1));
+        assert_eq!(replay.times.proposed(), vec![Timestamp(5)]);
+        assert!(replay.stmts.enacted().contains("a-act"));
+        assert!(events.iter().any(|event| matches!(event, Event::Decided { id, outcome: crate::agreements::Outcome::Expired, .. } if id == "stale")));
+    }
+
+    #[test]
+    fn test_replay_reconfigured() {
+        // Set up the simulation only after adding the sink and registering the agents
+        let mut sim: Simulation<MockAgent> = Simulation::new("e");
+        sim.set_quiet(true);
+        let sink: MemorySink = MemorySink::new();
+        sim.add_sink(sink.clone());
+        sim.register(MockAgent::new("a", [MockStep::Agree("rules".into()), MockStep::Advance(Timestamp(1))]), Style::new());
+        sim.register(MockAgent::new("b", [MockStep::State(vec!["b1".into(), "b2".into()]), MockStep::Agree("rules".into())]), Style::new());
+        sim.register(MockAgent::new("c", [MockStep::State(vec![]), MockStep::Advance(Timestamp(1))]), Style::new());
+        sim.set_consensus(Quorum::Majority, 1);
+        sim.set_time_consensus(Quorum::Majority);
+        sim.set_network(Some(Network::new(42)));
+        sim.set_rate_limit(Some(1));
+        for _ in 0..3 {
+            sim.poll().unwrap();
+        }
+
+        // The trace still starts with how the simulation was set up in the end
+        let (replay, events): (Replay, Vec<Event>) = replay(&sim, &sink);
+        assert!(matches!(&events[0], Event::Started {
+            agreements: Decision::Consensus { quorum: Quorum::Majority, timeout: Some(1) },
+            times: Decision::Consensus { quorum: Quorum::Majority, .. },
+            network: Some(_),
+            rate_limit: Some(1),
+            ..
+        }));
+        assert!(matches!(replay.agrs, GlobalAgreements::Consensus(_)));
+        assert!(replay.agrs.agreed().contains("rules"));
+        assert_eq!(replay.times.current(), Timestamp(1));
+        assert!(replay.stmts.stated().contains("b1"));
+        assert!(!replay.stmts.stated().contains("b2"));
+    }
+
+    #[test]
+    fn test_replay_network() {
+        let (mut sim, replay, events): (Simulation<MockAgent>, Replay, Vec<Event>) = roundtrip(
+            |sim| {
+                let mut network: Network = Network::new(42);
+                network.set_default_link(Link { latency: 1, jitter: 2, loss: 0.2 });
+                network.set_link("c", "e", Link::with_latency(0));
+                network.partition([vec!["a"], vec!["b"]]);
+                sim.set_network(Some(network));
+            },
+            [
+                MockAgent::new("a", [MockStep::State(vec!["a1".into(), "a2".into()]), MockStep::State(vec!["a3".into()])]),
+                MockAgent::new("b", [MockStep::State(vec!["b1".into(), "b2".into()]), MockStep::State(vec!["b3".into()])]),
+                MockAgent::new("c", [MockStep::State(vec!["c1".into(), "c2".into(), "c3".into()]), MockStep::State(vec!["c4".into()])]),
+                MockAgent::new("e", [MockStep::Agree("rules".into()), MockStep::Enact { id: "e-act".into(), just: vec!["c1".into()] }]),
+            ],
+            3,
+        );
+        let network: &mut Network = sim.network_mut().unwrap();
+        assert_eq!(replay.network, Some(network.config()));
+        assert_eq!(replay.in_flight.len(), network.in_flight());
+        assert!(!replay.in_flight.is_empty());
+        assert!(events.iter().any(|event| matches!(event, Event::Delivered { enacted: true, .. })));
+        assert!(events.iter().any(|event| matches!(event, Event::Dropped { reason: DropReason::Lost, .. })));
+        assert!(events.iter().any(|event| matches!(event, Event::Dropped { reason: DropReason::Partitioned, .. })));
+
+        // Whatever was dropped never reached its recipient
+        for event in &events {
+            if let Event::Dropped { to, id, enacted: false, .. } = event {
+                assert!(!replay.stmts.known_by(to).any(|msg| msg.id == *id), "replay delivered dropped '{id}' to '{to}'");
+            }
+        }
+    }
+
+    #[test]
+    fn test_replay_rate_limit() {
+        let (_, replay, events): (_, Replay, Vec<Event>) = roundtrip(
+            |sim| sim.set_rate_limit(Some(2)),
+            [
+                MockAgent::new("a", [MockStep::State(vec!["a1".into(), "a2".into(), "a3".into()])]),
+                MockAgent::new("e", [MockStep::Agree("rules".into()), MockStep::Enact { id: "e-act".into(), just: vec!["a2".into()] }]),
+            ],
+            3,
+        );
+        assert_eq!(replay.rate_limit, Some(2));
+        assert!(replay.stmts.stated().contains("a2"));
+        assert!(!replay.stmts.stated().contains("a3"));
+        assert!(replay.stmts.enacted().contains("e-act"));
+        assert!(events.iter().any(|event| matches!(event, Event::Flooded { agent, stated: 3, limit: 2, .. } if agent == "a")));
+    }
+
+    #[test]
+    fn test_replay_audit() {
+        // Every action is based on an invalid policy, and they are not enacted in order
+        let enact = |id: &str| -> MockStep { MockStep::Enact { id: id.into(), just: vec!["error".into()] } };
+        let (mut sim, mut replay, _): (Simulation<MockAgent>, Replay, Vec<Event>) = roundtrip(
+            |_| {},
+            [MockAgent::new("e", [MockStep::Agree("rules".into()), MockStep::State(vec!["error".into()]), enact("x3"), enact("x1"), enact("x2")])],
+            5,
+        );
+        replay.set_quiet(true);
+
+        // The replay reports the same failures as the simulation, in the same order
+        let live: Vec<String> = sim.audit::<MockExtractor>(&Paper).iter().map(|act| act.id().to_string()).collect();
+        let replayed: Vec<String> = replay.audit::<MockExtractor>(Paper).into_iter().map(|act| act.id().to_string()).collect();
+        assert_eq!(live, vec!["x1".to_string(), "x2".to_string(), "x3".to_string()]);
+        assert_eq!(replayed, live);
+    }
+}
+
+
+
+
+
+/***** ERRORS *****/
+/// Defines errors originating from creating or replaying traces.
+#[derive(Debug)]
+pub enum TraceError {
+    /// Failed to create the file to write a trace to.
+    Create { path: PathBuf, err: io::Error },
+    /// Failed to open the file to read a trace from.
+    Open { path: PathBuf, err: io::Error },
+    /// Failed to read a line of the trace.
+    Read { line: usize, err: io::Error },
+    /// Failed to parse a line of the trace as an event.
+    Parse { line: usize, err: serde_json::Error },
+    /// The trace did not start with an [`Event::Started`], or had one later on.
+    Begin { line: usize },
+    /// An event referred to an agent that was not registered (yet).
+    UnknownAgent { line: usize, agent: String },
+    /// An event delivered or dropped something that was not on its way.
+    NotInFlight { line: usize, agent: String, to: String, id: String },
+    /// An event did not fit how agreements or times are decided, e.g., a vote while a dictator
+    /// decides.
+    Decision { line: usize },
+}
+impl Display for TraceError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult {
+        use TraceError::*;
+        match self {
+            Create { path, .. } => write!(f, "Failed to create trace file '{}'", path.display()),
+            Open { path, .. } => write!(f, "Failed to open trace file '{}'", path.display()),
+            Read { line, .. } => write!(f, "Failed to read line {line} of trace"),
+            Parse { line, .. } => write!(f, "Failed to parse line {line} of trace as an event"),
+            Begin { line } => write!(f, "Expected the trace to begin exactly once, at line 1, but found line {line}"),
+            UnknownAgent { line, agent } => write!(f, "Event at line {line} of trace refers to unregistered agent '{agent}'"),
+            NotInFlight { line, agent, to, id } => {
+                write!(f, "Event at line {line} of trace delivers or drops '{id}' from '{agent}' to '{to}', but it was not on its way")
+            },
+            Decision { line } => write!(f, "Event at line {line} of trace does not fit how agreements or times are decided"),
+        }
+    }
+}
+impl error::Error for TraceError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        use TraceError::*;
+        match self {
+            Create { err, .. } => Some(err),
+            Open { err, .. } => Some(err),
+            Read { err, .. } => Some(err),
+            Parse { err, .. } => Some(err),
+            Begin { .. } => None,
+            UnknownAgent { .. } => None,
+            NotInFlight { .. } => None,
+            Decision { .. } => None,
+        }
+    }
+}
+
+
+
+
+
+/***** LIBRARY *****/
+/// An [`EventSink`] that writes [`Event`]s as JSON, one per line.
+///
+/// This is the trace that a [`Replay`] reads.
+pub struct JsonSink {
+    /// The writer to write to.
+    writer: Box<dyn Write>,
+    /// The first error that occurred while writing, if any.
+    err:    Option<io::Error>,
+}
+impl JsonSink {
+    /// Constructor for a JsonSink that writes to some [`Write`]r.
+    ///
+    /// # Arguments
+    /// - `writer`: The [`Write`]r to write the events to.
+    ///
+    /// # Returns
+    /// A new JsonSink.
+    #[inline]
+    pub fn new(writer: impl 'static + Write) -> Self { Self { writer: Box::new(writer), err: None } }
+
+    /// Constructor for a JsonSink that writes to a new file.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the file to create. If it already exists, it is truncated.
+    ///
+    /// # Returns
+    /// A new JsonSink.
+    ///
+    /// # Errors
+    /// This function errors if we failed to create the file.
+    #[inline]
+    pub fn create(path: impl AsRef<Path>) -> Result<Self, TraceError> {
+        let path: &Path = path.as_ref();
+        match File::create(path) {
+            Ok(handle) => Ok(Self::new(BufWriter::new(handle))),
+            Err(err) => Err(TraceError::Create { path: path.into(), err }),
+        }
+    }
+}
+impl EventSink for JsonSink {
+    /// Writes a single event.
+    ///
+    /// If writing fails, then nothing is written anymore and the error is returned by the next
+    /// call to [`JsonSink::flush()`].
+    fn handle(&mut self, event: &Event) {
+        if self.err.is_some() {
+            return;
+        }
+        let mut line: String = match serde_json::to_string(event) {
+            Ok(line) => line,
+            Err(err) => {
+                self.err = Some(err.into());
+                return;
+            },
+        };
+        line.push('\n');
+        if let Err(err) = self.writer.write_all(line.as_bytes()) {
+            self.err = Some(err);
+        }
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), io::Error> {
+        if let Some(err) = self.err.take() {
+            return Err(err);
+        }
+        self.writer.flush()
+    }
+}
+impl Debug for JsonSink {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FResult { f.debug_struct("JsonSink").field("writer", &"<writer>").field("err", &self.err).finish() }
+}
+
+
+
+/// The state of a [`Simulation`](crate::Simulation) rebuilt from a trace.
+///
+/// This is built without running any agents, e.g., to audit the actions taken offline. Agreements
+/// and times are decided the same way as in the simulation, but only as the trace says they
+/// were; votes are not counted again. Likewise, if the simulation had a
+/// [`Network`](crate::network::Network), whatever agents sent arrives only as the trace says it
+/// did.
+#[derive(Clone, Debug)]
+pub struct Replay {
+    /// The rebuilt statements.
+    pub stmts:      GlobalStatements,
+    /// The rebuilt agreements.
+    pub agrs:       GlobalAgreements,
+    /// The rebuilt times.
+    pub times:      GlobalTimes,
+    /// How the network over which agents sent statements and enactments was set up, if there was
+    /// one.
+    pub network:    Option<NetworkConfig>,
+    /// The maximum number of messages an agent could state at once, if any.
+    pub rate_limit: Option<usize>,
+    /// The last round in which something happened according to the trace.
+    pub rounds:     usize,
+    /// Whatever was sent over the network but did not arrive (yet), as the sender, the recipient
+    /// and the parcel, in the order in which it was sent.
+    in_flight:      Vec<(String, String, Parcel)>,
+    /// An interface we use to log whatever happens in pretty ways.
+    interface:      Rc<RefCell<Interface>>,
+}
+impl Replay {
+    /// Rebuilds the state of a simulation from the trace in the given file.
+    ///
+    /// # Arguments
+    /// - `path`: The path of the file with a trace written by a [`JsonSink`].
+    ///
+    /// # Returns
+    /// A new Replay with the state at the end of the trace.
+    ///
+    /// # Errors
+    /// This function errors if we failed to read the file or if it is not a valid trace.
+    #[inline]
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, TraceError> {
+        let path: &Path = path.as_ref();
+        match File::open(path) {
+            Ok(handle) => Self::read(BufReader::new(handle)),
+            Err(err) => Err(TraceError::Open { path: path.into(), err }),
+        }
+    }
+
+    /// Rebuilds the state of a simulation from a trace.
+    ///
+    /// Nothing is printed while replaying.
+    ///
+    /// # Arguments
+    /// - `reader`: Some [`BufRead`]er yielding the lines of a trace written by a [`JsonSink`].
+    ///
+    /// # Returns
+    /// A new Replay with the state at the end of the trace.
+    ///
+    /// # Errors
+    /// This function errors if we failed to read the trace or if it is not a valid trace.
+    pub fn read(reader: impl BufRead) -> Result<Self, TraceError> {
+        let mut interface: Interface = Interface::new();
+        interface.register("<system>", Style::new().bold());
+        interface.set_muted(true);
+        let interface: Rc<RefCell<Interface>> = Rc::new(RefCell::new(interface));
+
+        let mut replay: Option<Self> = None;
+        for (i, line) in reader.lines().enumerate() {
+            let line: String = line.map_err(|err| TraceError::Read { line: i + 1, err })?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let event: Event = serde_json::from_str(&line).map_err(|err| TraceError::Parse { line: i + 1, err })?;
+
+            // The first event creates the state, the others change it
+            match (&mut replay, event) {
+                (None, Event::Started { dictator, agreements, times, network, rate_limit }) => {
+                    let agrs: GlobalAgreements = match agreements {
+                        Decision::Dictator => GlobalAgreementsDictator::new(dictator.clone(), interface.clone()).into(),
+                        Decision::Consensus { quorum, timeout } => {
+                            GlobalAgreementsConsensus::new(quorum, timeout.unwrap_or(usize::MAX), interface.clone()).into()
+                        },
+                    };
+                    let times: GlobalTimes = match times {
+                        Decision::Dictator => GlobalTimesDictator::new(dictator, interface.clone()).into(),
+                        Decision::Consensus { quorum, .. } => GlobalTimesConsensus::new(quorum, interface.clone()).into(),
+                    };
+                    replay = Some(Self {
+                        stmts: GlobalStatements::new(interface.clone()),
+                        agrs,
+                        times,
+                        network,
+                        rate_limit,
+                        rounds: 0,
+                        in_flight: Vec::new(),
+                        interface: interface.clone(),
+                    })
+                },
+                (None, _) => return Err(TraceError::Begin { line: i + 1 }),
+                (Some(replay), event) => replay.apply(i + 1, event)?,
+            }
+        }
+
+        // Un-silence the interface for anything after replaying
+        interface.borrow_mut().set_muted(false);
+        replay.ok_or(TraceError::Begin { line: 1 })
+    }
+
+    /// Applies a single event to the state.
+    ///
+    /// Events that do not change the state (e.g., failed audits) are ignored.
+    ///
+    /// # Arguments
+    /// - `line`: The line of the event in the trace, for debugging purposes.
+    /// - `event`: The [`Event`] to apply.
+    ///
+    /// # Errors
+    /// This function errors if the event is not valid in the current state.
+    fn apply(&mut self, line: usize, event: Event) -> Result<(), TraceError> {
+        use Event::*;
+        if let Some(round) = event.round() {
+            self.rounds = self.rounds.max(round);
+        }
+        match event {
+            Started { .. } => return Err(TraceError::Begin { line }),
+            Registered { agent, .. } => {
+                self.interface.borrow_mut().register(&agent, Style::new());
+                self.stmts.register_id(&agent);
+                self.agrs.register(&agent);
+                self.times.register(&agent);
+            },
+            Stated { agent, target, msg, .. } => {
+                self.assert_registered(line, &agent, &target)?;
+                self.send(&agent, target, Parcel::Stmt(msg));
+            },
+            Enacted { agent, target, act, .. } => {
+                self.assert_registered(line, &agent, &target)?;
+                self.send(&agent, target, Parcel::Enct(Box::new(act)));
+            },
+            Delivered { agent, to, id, enacted, .. } => {
+                let parcel: Parcel = self.land(line, agent, to.clone(), id, enacted)?;
+                self.stmts.deliver_to(&to, parcel);
+            },
+            Dropped { agent, to, id, enacted, .. } => {
+                self.land(line, agent, to, id, enacted)?;
+            },
+            Agreed { agent, agr, .. } | Revoked { agent, agr, .. } => match &mut self.agrs {
+                GlobalAgreements::Dictator(agrs) => agrs.sync(&agent, vec![agr]),
+                GlobalAgreements::Consensus(agrs) => agrs.replay_agree(agr),
+            },
+            Proposed { agent, agr, .. } => self.agrs_consensus(line)?.replay_propose(&agent, agr),
+            Voted { agent, id, approve, .. } => self.agrs_consensus(line)?.replay_vote(&agent, &id, approve),
+            Decided { id, .. } => self.agrs_consensus(line)?.replay_decide(&id),
+            TimeAdvanced { agent, time, .. } => match &mut self.times {
+                GlobalTimes::Dictator(times) => times.sync(&agent, vec![time]),
+                GlobalTimes::Consensus(times) => times.replay_advance(time),
+            },
+            TimeProposed { agent, time, .. } | TimeVoted { agent, time, .. } => self.times_consensus(line)?.replay_propose(&agent, time),
+            TimeDecided { time, .. } => self.times_consensus(line)?.replay_decide(time),
+            // NOTE: Proposals that expire in this round were already decided by earlier events,
+            // so this only ages the others
+            Finished { .. } => self.agrs.finish_round(),
+            Logged { .. } | Errored { .. } | Equivocated { .. } | Flooded { .. } | AgentFailed { .. } | Superfluous { .. } | AuditFailed { .. } => {},
+        }
+        Ok(())
+    }
+
+    /// Replays that an agent stated or enacted something.
+    ///
+    /// Without a network, it is delivered to its target(s) immediately. Otherwise, only the agent
+    /// itself learns of it, and the others once the trace says it arrived.
+    ///
+    /// # Arguments
+    /// - `agent`: The agent who sent it.
+    /// - `target`: The [`Target`] it is sent to.
+    /// - `parcel`: The [`Parcel`] that is sent.
+    fn send(&mut self, agent: &str, target: Target, parcel: Parcel) {
+        if self.network.is_none() {
+            match parcel {
+                Parcel::Stmt(stmt) => self.stmts.sync(agent, vec![(target, stmt)], vec![]),
+                Parcel::Enct(enct) => self.stmts.sync(agent, vec![], vec![(target, *enct)]),
+            }
+            return;
+        }
+        for to in self.stmts.sync_unsent(agent, target, parcel.clone()) {
+            self.in_flight.push((agent.into(), to, parcel.clone()));
+        }
+    }
+
+    /// Takes something that arrived (or was dropped) off the network.
+    ///
+    /// If multiple versions of the same message are on their way, the one sent first is taken.
+    ///
+    /// # Arguments
+    /// - `line`: The line of the event in the trace, for debugging purposes.
+    /// - `agent`: The agent who sent it.
+    /// - `to`: The agent who should receive it.
+    /// - `id`: The identifier of the message stated or enacted.
+    /// - `enacted`: Whether it is the action enacting the message rather than the message itself.
+    ///
+    /// # Returns
+    /// The [`Parcel`] that was on its way.
+    ///
+    /// # Errors
+    /// This function errors if no such thing was on its way.
+    fn land(&mut self, line: usize, agent: String, to: String, id: String, enacted: bool) -> Result<Parcel, TraceError> {
+        let pos: Option<usize> = self
+            .in_flight
+            .iter()
+            .position(|(from, dest, parcel)| *from == agent && *dest == to && parcel.id() == id && parcel.is_enacted() == enacted);
+        match pos {
+            Some(pos) => Ok(self.in_flight.remove(pos).2),
+            None => Err(TraceError::NotInFlight { line, agent, to, id }),
+        }
+    }
+
+    /// Returns the agreements as decided by consensus.
+    ///
+    /// # Arguments
+    /// - `line`: The line of the event in the trace, for debugging purposes.
+    ///
+    /// # Returns
+    /// The [`GlobalAgreementsConsensus`].
+    ///
+    /// # Errors
+    /// This function errors if the agreements are decided by a dictator instead.
+    #[inline]
+    fn agrs_consensus(&mut self, line: usize) -> Result<&mut GlobalAgreementsConsensus, TraceError> {
+        match &mut self.agrs {
+            GlobalAgreements::Dictator(_) => Err(TraceError::Decision { line }),
+            GlobalAgreements::Consensus(agrs) => Ok(agrs),
+        }
+    }
+
+    /// Returns the times as decided by consensus.
+    ///
+    /// # Arguments
+    /// - `line`: The line of the event in the trace, for debugging purposes.
+    ///
+    /// # Returns
+    /// The [`GlobalTimesConsensus`].
+    ///
+    /// # Errors
+    /// This function errors if the times are decided by a dictator instead.
+    #[inline]
+    fn times_consensus(&mut self, line: usize) -> Result<&mut GlobalTimesConsensus, TraceError> {
+        match &mut self.times {
+            GlobalTimes::Dictator(_) => Err(TraceError::Decision { line }),
+            GlobalTimes::Consensus(times) => Ok(times),
+        }
+    }
+
+    /// Asserts that the agents involved in sending something are registered.
+    ///
+    /// # Arguments
+    /// - `line`: The line of the event in the trace, for debugging purposes.
+    /// - `agent`: The agent sending something.
+    /// - `target`: The [`Target`] it is sent to.
+    ///
+    /// # Errors
+    /// This function errors if either agent is not registered.
+    fn assert_registered(&self, line: usize, agent: &str, target: &Target) -> Result<(), TraceError> {
+        for agent in [Some(agent), if let Target::Agent(target) = target { Some(target.as_str()) } else { None }].into_iter().flatten() {
+            if !self.stmts.is_registered(agent) {
+                return Err(TraceError::UnknownAgent { line, agent: agent.into() });
+            }
+        }
+        Ok(())
+    }
+
+    /// Hashes the agreements, the times and what every agent knows, like
+    /// [`Simulation::fingerprint_known()`](crate::Simulation::fingerprint_known()).
+    ///
+    /// # Arguments
+    /// - `state`: The [`Hasher`] to hash into.
+    #[cfg(test)]
+    pub(crate) fn fingerprint(&self, state: &mut impl std::hash::Hasher) {
+        self.agrs.fingerprint(state);
+        self.times.fingerprint(state);
+        self.stmts.fingerprint_known(state);
+    }
+
+    /// Audits all actions in the replayed state, reporting those that fail.
+    ///
+    /// # Generics
+    /// - `E`: The [`Extractor`] of the policy language used to audit actions.
+    ///
+    /// # Arguments
+    /// - `auditor`: The [`Auditor`] (stack) used to audit the actions.
+    ///
+    /// # Returns
+    /// The actions that failed their audit, ordered by their identifiers.
+    pub fn audit<E>(&self, auditor: impl Auditor<Message>) -> Vec<&Action<Message>>
+    where
+        E: for<'e> Extractor<&'e Message>,
+    {
+        // Audit in the same order as the simulation, such that both report the same way
+        let mut encts: Vec<&Action<Message>> = self.stmts.enacted().into_iter().collect();
+        encts.sort_unstable_by(|lhs, rhs| lhs.id().cmp(rhs.id()));
+        let mut failed: Vec<&Action<Message>> = Vec::new();
+        for enct in encts {
+            let report = enct.audit_with::<E, GlobalStatements, GlobalAgreements, _>(&auditor, &self.stmts, &self.agrs);
+            if !report.is_ok() {
+                self.interface.borrow_mut().error_audit("<system>", enct, report);
+                failed.push(enct);
+            }
+        }
+        failed
+    }
+
+    /// Sets whether auditing suppresses all output.
+    ///
+    /// # Arguments
+    /// - `quiet`: If true, nothing is written anymore.
+    #[inline]
+    pub fn set_quiet(&mut self, quiet: bool) { self.interface.borrow_mut().set_quiet(quiet); }
+}
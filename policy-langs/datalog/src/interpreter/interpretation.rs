@@ -4,7 +4,7 @@
 //  Created:
 //    21 Mar 2024, 10:22:40
 //  Last edited:
-//    07 May 2024, 16:14:39
+//    16 Oct 2026, 19:27:28
 //  Auto updated?
 //    Yes
 //
@@ -418,6 +418,13 @@ impl<'f, 's, R: BuildHasher> Interpretation<'f, 's, R> {
     #[inline]
     pub fn len(&self) -> usize { self.defs.len() }
 
+    /// Returns all facts in the universe that are known to be true.
+    ///
+    /// # Returns
+    /// An iterator over the [`Atom`]s for which [`Self::closed_world_truth()`](Interpretation::closed_world_truth()) returns `Some(true)`.
+    #[inline]
+    pub fn iter_true<'i>(&'i self) -> impl 'i + Iterator<Item = &'i Atom<'f, 's>> { self.tknown.iter().filter_map(|h| self.defs.get(h)) }
+
     /// Computes some hash of this interpretation.
     ///
     /// This is computed using some general [`Hasher`] that does not rely on the internal random state of the Interpretation.
@@ -4,7 +4,7 @@
 //  Created:
 //    13 May 2024, 18:39:10
 //  Last edited:
-//    04 Jun 2024, 11:28:56
+//    16 Oct 2026, 22:22:20
 //  Auto updated?
 //    Yes
 //
@@ -13,20 +13,68 @@
 //!   $Datalog^\neg$'s [`Spec`].
 //
 
+use std::collections::HashMap;
 use std::error::Error;
 use std::fmt::{Display, Formatter, Result as FResult};
 
 use ast_toolkit_punctuated::Punctuated;
 use justact_core::auxillary::{Authored, Identifiable};
-use justact_core::policy::{Extractor, Policy};
+use justact_core::policy::{Extractor, Policy, QueryablePolicy, TaggedExtractor};
 use justact_core::set::LocalSet;
 use justact_core::statements::Message;
 
-use crate::ast::{Atom, Comma, Dot, Ident, Rule, Span, Spec};
+use crate::ast::{Atom, AtomArg, Comma, Dot, Ident, Rule, Span, Spec};
 use crate::interpreter::interpretation::Interpretation;
+use crate::interpreter::{alternating_fixpoint, Error as InterpreterError};
 use crate::parser::parse;
 
 
+/***** TESTS *****/
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+
+    /// Parses the consequent of a single fact as a query.
+    ///
+    /// # Arguments
+    /// - `fact`: The Datalog source of a single fact, e.g., `foo(bar).`.
+    ///
+    /// # Returns
+    /// The [`Atom`] that the fact states.
+    fn make_query(fact: &'static str) -> Atom<'static, 'static> {
+        let spec: Spec = parse("make_query", fact).unwrap();
+        spec.rules[0].consequences.values().next().unwrap().clone()
+    }
+
+
+    #[test]
+    fn test_spec_query() {
+        let spec: Spec = parse(
+            "test_spec_query",
+            "authorised(amy). authorised(anton). accesses(amy, x_rays) :- authorised(amy). owns(amy, amy). owns(amy, anton).",
+        )
+        .unwrap();
+
+        // Ground queries check derivability
+        assert_eq!(spec.query(&make_query("accesses(amy, x_rays).")).unwrap().len(), 1);
+        assert!(spec.query(&make_query("accesses(anton, x_rays).")).unwrap().is_empty());
+        assert!(spec.query(&make_query("denied(amy).")).unwrap().is_empty());
+
+        // Variables match any constant...
+        let answer: Vec<String> = spec.query(&make_query("authorised(X).")).unwrap().into_iter().map(|atom| atom.to_string()).collect();
+        assert_eq!(answer, vec!["authorised(amy)", "authorised(anton)"]);
+
+        // ...but consistently so
+        let answer: Vec<String> = spec.query(&make_query("owns(X, X).")).unwrap().into_iter().map(|atom| atom.to_string()).collect();
+        assert_eq!(answer, vec!["owns(amy,amy)"]);
+    }
+}
+
+
+
+
+
 /***** ERRORS *****/
 /// Defines a failure during parsing [`Spec`]s from [`MessageSet`](justact_core::wire::MessageSet)s.
 #[derive(Debug)]
@@ -87,6 +135,50 @@ pub struct SpecExtractor;
 
 
 
+/***** HELPER FUNCTIONS *****/
+/// Checks whether a (concrete) atom matches a query.
+///
+/// # Arguments
+/// - `query`: The [`Atom`] to match with. Any variables in it match any constant, as long as the same variable matches the same constant everywhere.
+/// - `atom`: The (concrete) [`Atom`] to match.
+///
+/// # Returns
+/// True if `atom` is an instance of `query`, or false otherwise.
+fn matches_query(query: &Atom, atom: &Atom) -> bool {
+    // The identifiers and arities must match first
+    if query.ident.value.value() != atom.ident.value.value() {
+        return false;
+    }
+    let qargs: Vec<&AtomArg> = query.args.iter().flat_map(|a| a.args.values()).collect();
+    let aargs: Vec<&AtomArg> = atom.args.iter().flat_map(|a| a.args.values()).collect();
+    if qargs.len() != aargs.len() {
+        return false;
+    }
+
+    // Then match the arguments, assigning variables as we go
+    let mut assign: HashMap<&str, &str> = HashMap::with_capacity(qargs.len());
+    for (qarg, aarg) in qargs.into_iter().zip(aargs) {
+        let value: &str = aarg.ident().value.value();
+        match qarg {
+            AtomArg::Atom(ident) => {
+                if ident.value.value() != value {
+                    return false;
+                }
+            },
+            AtomArg::Var(var) => {
+                if *assign.entry(var.value.value()).or_insert(value) != value {
+                    return false;
+                }
+            },
+        }
+    }
+    true
+}
+
+
+
+
+
 /***** IMPLS *****/
 // Implements `Policy` for Datalog
 impl<'v> Policy for Spec<'v, 'v> {
@@ -105,6 +197,26 @@ impl<'v> Policy for Spec<'v, 'v> {
         if error_truth == Some(false) { Ok(()) } else { Err(ValidityError::ErrorHolds { int }) }
     }
 }
+impl<'v> QueryablePolicy for Spec<'v, 'v> {
+    type Query<'q> = &'q Atom<'q, 'q>;
+    type Answer = Vec<Atom<'v, 'v>>;
+    type QueryError = InterpreterError<'v, 'v>;
+
+    /// Finds all atoms that are derived to be true and that match the given query.
+    ///
+    /// Variables in the query match any constant, as long as the same variable matches the same
+    /// constant everywhere. Ground queries thus result in at most one atom, i.e., they check if
+    /// that atom is derivable.
+    ///
+    /// The atoms are returned sorted by their serialization.
+    #[inline]
+    fn query<'q>(&self, query: Self::Query<'q>) -> Result<Self::Answer, Self::QueryError> {
+        let int: Interpretation<'v, 'v> = alternating_fixpoint(&self.rules)?;
+        let mut answer: Vec<Atom<'v, 'v>> = int.iter_true().filter(|atom| matches_query(query, atom)).cloned().collect();
+        answer.sort_by_cached_key(|atom| atom.to_string());
+        Ok(answer)
+    }
+}
 
 
 
@@ -181,3 +293,10 @@ where
         Ok(spec)
     }
 }
+impl<M> TaggedExtractor<M> for SpecExtractor
+where
+    M: Authored<AuthorId = str> + Identifiable<Id = str>,
+{
+    #[inline]
+    fn handles(lang: &str) -> bool { lang == "datalog" }
+}